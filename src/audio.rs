@@ -1,101 +1,429 @@
-use crate::world::BlockType;
+use crate::entity::{AnimalType, EntitySound, HostileMobType, CREEPER_FUSE_TIME};
+use crate::world::{Biome, BlockType};
+use cgmath::{InnerSpace, Point3, Vector3};
 use rand::Rng;
-use rodio::{OutputStream, OutputStreamHandle, Sink};
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Sounds further than this many blocks from the listener are not played
+pub const MAX_HEARING_DISTANCE: f32 = 32.0;
+/// Sounds within this distance play at full volume
+const REFERENCE_DISTANCE: f32 = 2.0;
+/// How quickly volume falls off past the reference distance
+const ROLLOFF: f32 = 0.5;
+/// Volume of the ear facing away from a sound (1.0 = no panning)
+const FAR_EAR_GAIN: f32 = 0.25;
+/// Underwater everything is quieter and loses its high end
+const UNDERWATER_GAIN: f32 = 0.6;
+const UNDERWATER_LOWPASS: f32 = 0.12;
+
+/// Where sounds are heard from - follows the camera each frame
+#[derive(Clone, Copy, Debug)]
+pub struct Listener {
+    pub position: Point3<f32>,
+    pub yaw: f32,         // Degrees, same convention as Camera (0 = looking toward +X)
+    pub underwater: bool,
+}
+
+impl Listener {
+    pub fn new(position: Point3<f32>, yaw: f32, underwater: bool) -> Self {
+        Self { position, yaw, underwater }
+    }
+
+    /// Unit vector pointing out of the listener's right ear
+    fn right(&self) -> Vector3<f32> {
+        let yaw_rad = self.yaw.to_radians();
+        Vector3::new(-yaw_rad.sin(), 0.0, yaw_rad.cos())
+    }
+
+    /// Mix for the listener's own sounds (footsteps, jumping) - centered, only muffled underwater
+    pub fn own_mix(&self) -> SpatialMix {
+        self.muffle(SpatialMix::CENTERED)
+    }
+
+    /// Stereo gains for a sound emitted at `emitter`, or None if it is out of earshot
+    pub fn spatialize(&self, emitter: Point3<f32>) -> Option<SpatialMix> {
+        let offset = emitter - self.position;
+        let distance = offset.magnitude();
+        if distance >= MAX_HEARING_DISTANCE {
+            return None;
+        }
+
+        // Inverse-distance rolloff, faded to silence at the edge of hearing
+        let gain = if distance <= REFERENCE_DISTANCE {
+            1.0
+        } else {
+            let inverse = REFERENCE_DISTANCE / (REFERENCE_DISTANCE + ROLLOFF * (distance - REFERENCE_DISTANCE));
+            let fade = 1.0 - (distance - REFERENCE_DISTANCE) / (MAX_HEARING_DISTANCE - REFERENCE_DISTANCE);
+            inverse * fade
+        };
+
+        // Pan by how far to the side the sound is (-1 = left, 1 = right); sounds overhead stay centered
+        let horizontal = Vector3::new(offset.x, 0.0, offset.z);
+        let pan = if horizontal.magnitude2() > 1e-6 {
+            horizontal.normalize().dot(self.right())
+        } else {
+            0.0
+        };
+        let left = 1.0 - (1.0 - FAR_EAR_GAIN) * pan.max(0.0);
+        let right = 1.0 - (1.0 - FAR_EAR_GAIN) * (-pan).max(0.0);
+
+        Some(self.muffle(SpatialMix { left: left * gain, right: right * gain, lowpass: None }))
+    }
+
+    fn muffle(&self, mix: SpatialMix) -> SpatialMix {
+        if self.underwater {
+            SpatialMix {
+                left: mix.left * UNDERWATER_GAIN,
+                right: mix.right * UNDERWATER_GAIN,
+                lowpass: Some(UNDERWATER_LOWPASS),
+            }
+        } else {
+            mix
+        }
+    }
+}
+
+/// Per-ear gains and optional low-pass filter for one playing sound
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpatialMix {
+    pub left: f32,
+    pub right: f32,
+    pub lowpass: Option<f32>,  // Filter factor (smaller = more muffled)
+}
+
+impl SpatialMix {
+    pub const CENTERED: SpatialMix = SpatialMix { left: 1.0, right: 1.0, lowpass: None };
+
+    /// Turn mono samples into interleaved stereo samples with this mix applied
+    pub fn apply(&self, samples: &[i16]) -> Vec<i16> {
+        let mut stereo = Vec::with_capacity(samples.len() * 2);
+        let mut prev = samples.first().copied().unwrap_or(0) as f32;
+        for &sample in samples {
+            let mut value = sample as f32;
+            if let Some(factor) = self.lowpass {
+                value = prev * (1.0 - factor) + value * factor;
+                prev = value;
+            }
+            stereo.push((value * self.left) as i16);
+            stereo.push((value * self.right) as i16);
+        }
+        stereo
+    }
+}
+
+/// Where finished sounds end up - the speakers, or nowhere on machines without them
+pub trait AudioBackend {
+    /// Play a one-shot mono sound with a spatial mix and forget about it
+    fn play_sound(&self, event: &str, samples: &[i16], sample_rate: u32, mix: SpatialMix);
+    /// Start a mono music track alongside anything already playing (crossfades overlap two)
+    fn play_music(&mut self, track: &str, samples: &[i16], sample_rate: u32, volume: f32) -> MusicVoice;
+    /// Like play_music, but repeats until stopped (ambient beds)
+    fn play_loop(&mut self, name: &str, samples: &[i16], sample_rate: u32, volume: f32) -> MusicVoice;
+    fn stop_music(&mut self, voice: MusicVoice);
+    fn set_music_volume(&mut self, voice: MusicVoice, volume: f32);
+}
+
+/// Identifies one playing music or ambient stream
+pub type MusicVoice = u32;
+
+/// Real output through rodio's default device
+pub struct RodioBackend {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    music_sinks: HashMap<MusicVoice, Sink>,
+    next_voice: MusicVoice,
+}
+
+impl RodioBackend {
+    pub fn new() -> Option<Self> {
+        match OutputStream::try_default() {
+            Ok((stream, handle)) => Some(Self { _stream: stream, handle, music_sinks: HashMap::new(), next_voice: 0 }),
+            Err(e) => {
+                eprintln!("Audio: No output device ({:?})", e);
+                None
+            }
+        }
+    }
+}
+
+impl AudioBackend for RodioBackend {
+    fn play_sound(&self, _event: &str, samples: &[i16], sample_rate: u32, mix: SpatialMix) {
+        if let Ok(sink) = Sink::try_new(&self.handle) {
+            let source = rodio::buffer::SamplesBuffer::new(2, sample_rate, mix.apply(samples));
+            sink.append(source);
+            sink.detach();
+        }
+    }
+
+    fn play_music(&mut self, track: &str, samples: &[i16], sample_rate: u32, volume: f32) -> MusicVoice {
+        let voice = self.next_voice;
+        self.next_voice += 1;
+        match Sink::try_new(&self.handle) {
+            Ok(sink) => {
+                sink.set_volume(volume);
+                sink.append(rodio::buffer::SamplesBuffer::new(1, sample_rate, samples.to_vec()));
+                self.music_sinks.insert(voice, sink);
+            }
+            Err(_) => eprintln!("Music: Failed to create sink for {}", track),
+        }
+        voice
+    }
+
+    fn play_loop(&mut self, name: &str, samples: &[i16], sample_rate: u32, volume: f32) -> MusicVoice {
+        let voice = self.next_voice;
+        self.next_voice += 1;
+        match Sink::try_new(&self.handle) {
+            Ok(sink) => {
+                sink.set_volume(volume);
+                sink.append(rodio::buffer::SamplesBuffer::new(1, sample_rate, samples.to_vec()).repeat_infinite());
+                self.music_sinks.insert(voice, sink);
+            }
+            Err(_) => eprintln!("Audio: Failed to create sink for {}", name),
+        }
+        voice
+    }
+
+    fn stop_music(&mut self, voice: MusicVoice) {
+        if let Some(sink) = self.music_sinks.remove(&voice) {
+            sink.stop();
+        }
+    }
+
+    fn set_music_volume(&mut self, voice: MusicVoice, volume: f32) {
+        if let Some(sink) = self.music_sinks.get(&voice) {
+            sink.set_volume(volume);
+        }
+    }
+}
+
+/// Silent backend for machines without an output device - plays nothing and keeps nothing
+#[derive(Default)]
+pub struct NullBackend {
+    next_voice: MusicVoice,
+}
+
+impl NullBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next_voice(&mut self) -> MusicVoice {
+        let voice = self.next_voice;
+        self.next_voice += 1;
+        voice
+    }
+}
+
+impl AudioBackend for NullBackend {
+    fn play_sound(&self, _event: &str, _samples: &[i16], _sample_rate: u32, _mix: SpatialMix) {}
+
+    fn play_music(&mut self, _track: &str, _samples: &[i16], _sample_rate: u32, _volume: f32) -> MusicVoice {
+        self.next_voice()
+    }
+
+    fn play_loop(&mut self, _name: &str, _samples: &[i16], _sample_rate: u32, _volume: f32) -> MusicVoice {
+        self.next_voice()
+    }
+
+    fn stop_music(&mut self, _voice: MusicVoice) {}
+
+    fn set_music_volume(&mut self, _voice: MusicVoice, _volume: f32) {}
+}
+
+/// A music or ambient asset from the sound directory, or the procedural version if there isn't one
+fn load_or_generate(asset: &str, generate: impl FnOnce() -> Vec<i16>) -> SoundBuffer {
+    SOUND_ASSET_EXTENSIONS.iter()
+        .map(|ext| Path::new(SOUND_ASSET_DIR).join(format!("{}.{}", asset, ext)))
+        .find(|path| path.exists())
+        .and_then(|path| load_sound_asset(&path))
+        .unwrap_or_else(|| SoundBuffer { samples: Arc::new(generate()), sample_rate: 44100 })
+}
+
+/// Rodio if there's an output device, otherwise a silent backend so the game still runs
+fn default_backend() -> Box<dyn AudioBackend> {
+    match RodioBackend::new() {
+        Some(backend) => Box::new(backend),
+        None => {
+            eprintln!("Audio: Running without sound");
+            Box::new(NullBackend::new())
+        }
+    }
+}
+
+/// Seconds spent fading from one track to the next (and from a track into its own next loop)
+const MUSIC_CROSSFADE_TIME: f32 = 4.0;
+/// Combat music keeps playing this long after the last mob gives up the chase
+const COMBAT_MUSIC_LINGER: f32 = 10.0;
 
 // Music track types
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum MusicTrack {
     CalmDay,
     CalmNight,
     Underwater,
+    Forest,
+    Desert,
+    Frost,
+    Cave,
+    Storm,
+    Combat,
+    Flight,
+}
+
+/// Everything about the player's situation that music selection looks at
+#[derive(Clone, Copy, Debug)]
+pub struct MusicContext {
+    pub time_of_day: f32,
+    pub biome: Biome,
+    pub underwater: bool,
+    pub underground: bool,
+    pub in_combat: bool,
+    pub thunderstorm: bool,
+    pub piloting: bool,
+}
+
+/// A track that is playing (or fading out) on the backend
+struct PlayingTrack {
+    track: MusicTrack,
+    voice: MusicVoice,
+    volume: f32,
+    remaining: f32,  // Seconds until the track runs out
 }
 
 // Background music manager
 pub struct MusicManager {
-    _stream: OutputStream,
-    handle: OutputStreamHandle,
-    music_sink: Option<Sink>,
-    current_track: Option<MusicTrack>,
+    backend: Box<dyn AudioBackend>,
+    current: Option<PlayingTrack>,
+    fading_out: Vec<PlayingTrack>,
     music_volume: f32,
-    track_timer: f32,
+    combat_timer: f32,
     enabled: bool,
+    track_cache: HashMap<MusicTrack, SoundBuffer>,  // Loaded or generated tracks, built once each
 }
 
 impl MusicManager {
-    pub fn new() -> Option<Self> {
-        match OutputStream::try_default() {
-            Ok((stream, handle)) => {
-                eprintln!("Music: MusicManager initialized successfully");
-                Some(Self {
-                    _stream: stream,
-                    handle,
-                    music_sink: None,
-                    current_track: None,
-                    music_volume: 0.15,  // Background music volume (subtle ambient)
-                    track_timer: 0.0,
-                    enabled: true,
-                })
-            },
-            Err(e) => {
-                eprintln!("Music: Failed to initialize MusicManager: {:?}", e);
-                None
-            }
+    pub fn new() -> Self {
+        Self::with_backend(default_backend())
+    }
+
+    pub fn with_backend(backend: Box<dyn AudioBackend>) -> Self {
+        Self {
+            backend,
+            current: None,
+            fading_out: Vec::new(),
+            music_volume: 0.15,  // Background music volume (subtle ambient)
+            combat_timer: 0.0,
+            enabled: true,
+            track_cache: HashMap::new(),
         }
     }
 
-    pub fn update(&mut self, dt: f32, time_of_day: f32, is_underwater: bool) {
+    pub fn update(&mut self, dt: f32, context: &MusicContext) {
         if !self.enabled {
             return;
         }
 
-        self.track_timer -= dt;
-
-        // Check if we need to change tracks
-        let desired_track = Self::select_track(time_of_day, is_underwater);
+        self.combat_timer = if context.in_combat { COMBAT_MUSIC_LINGER } else { (self.combat_timer - dt).max(0.0) };
+        let desired_track = Self::select_track(context, self.combat_timer > 0.0);
 
-        // Start or change music
-        if self.current_track != Some(desired_track) || self.music_sink.is_none() {
-            self.play_track(desired_track);
-        } else if self.track_timer <= 0.0 {
-            // Restart track for looping (track is about to end)
-            if let Some(sink) = &self.music_sink {
-                if sink.empty() {
-                    self.play_track(desired_track);
-                }
+        // Change tracks, or start the next loop of this one before it runs out so there's no gap
+        let start_next = match &mut self.current {
+            Some(playing) => {
+                playing.remaining -= dt;
+                playing.track != desired_track || playing.remaining <= MUSIC_CROSSFADE_TIME
             }
+            None => true,
+        };
+        if start_next {
+            self.play_track(desired_track);
         }
+
+        self.update_fades(dt);
     }
 
-    fn select_track(time_of_day: f32, is_underwater: bool) -> MusicTrack {
-        if is_underwater {
+    /// Most pressing situation wins: underwater, flying, fighting, storms, caves, then biome and time of day
+    fn select_track(context: &MusicContext, in_combat: bool) -> MusicTrack {
+        let is_day = context.time_of_day > 0.25 && context.time_of_day < 0.75;
+        if context.underwater {
             MusicTrack::Underwater
-        } else if time_of_day > 0.25 && time_of_day < 0.75 {
-            MusicTrack::CalmDay
-        } else {
+        } else if context.piloting {
+            MusicTrack::Flight
+        } else if in_combat {
+            MusicTrack::Combat
+        } else if context.thunderstorm {
+            MusicTrack::Storm
+        } else if context.underground {
+            MusicTrack::Cave
+        } else if !is_day {
             MusicTrack::CalmNight
+        } else {
+            match context.biome {
+                Biome::Forest => MusicTrack::Forest,
+                Biome::Desert => MusicTrack::Desert,
+                Biome::Mountains | Biome::Tundra => MusicTrack::Frost,
+                Biome::Plains | Biome::Ocean => MusicTrack::CalmDay,
+            }
         }
     }
 
+    /// Start a track silently; the current one (if any) fades out as this one fades in
     fn play_track(&mut self, track: MusicTrack) {
-        // Stop current music
-        if let Some(sink) = self.music_sink.take() {
-            sink.stop();
+        // Load the track's asset if there is one, otherwise generate it (only the first time)
+        let buffer = self.track_cache.entry(track)
+            .or_insert_with(|| load_or_generate(Self::track_asset(track), || Self::generate_ambient_track(track)));
+        let duration_secs = buffer.samples.len() as f32 / buffer.sample_rate as f32;
+
+        let voice = self.backend.play_music(Self::track_asset(track), &buffer.samples, buffer.sample_rate, 0.0);
+        if let Some(previous) = self.current.take() {
+            self.fading_out.push(previous);
         }
+        self.current = Some(PlayingTrack { track, voice, volume: 0.0, remaining: duration_secs });
+        eprintln!("Music: Playing {:?} track (duration: {:.1}s)", track, duration_secs);
+    }
 
-        // Generate and play new track
-        let samples = Self::generate_ambient_track(track);
-        let duration_secs = samples.len() as f32 / 44100.0;
+    fn update_fades(&mut self, dt: f32) {
+        let step = self.music_volume.max(0.01) * dt / MUSIC_CROSSFADE_TIME;
+        let backend = &mut self.backend;
 
-        if let Ok(sink) = Sink::try_new(&self.handle) {
-            let source = rodio::buffer::SamplesBuffer::new(1, 44100, samples);
-            sink.set_volume(self.music_volume);
-            sink.append(source);
-            self.music_sink = Some(sink);
-            self.current_track = Some(track);
-            self.track_timer = duration_secs - 1.0;  // Restart slightly before end
-            eprintln!("Music: Playing {:?} track (duration: {:.1}s)", track, duration_secs);
-        } else {
-            eprintln!("Music: Failed to create sink");
+        if let Some(playing) = &mut self.current {
+            if playing.volume < self.music_volume {
+                playing.volume = (playing.volume + step).min(self.music_volume);
+                backend.set_music_volume(playing.voice, playing.volume);
+            }
+        }
+
+        self.fading_out.retain_mut(|playing| {
+            playing.volume -= step;
+            if playing.volume <= 0.0 {
+                backend.stop_music(playing.voice);
+                false
+            } else {
+                backend.set_music_volume(playing.voice, playing.volume);
+                true
+            }
+        });
+    }
+
+    /// Asset path (relative to the sound directory) that replaces a generated track
+    fn track_asset(track: MusicTrack) -> &'static str {
+        match track {
+            MusicTrack::CalmDay => "music/calm_day",
+            MusicTrack::CalmNight => "music/calm_night",
+            MusicTrack::Underwater => "music/underwater",
+            MusicTrack::Forest => "music/forest",
+            MusicTrack::Desert => "music/desert",
+            MusicTrack::Frost => "music/frost",
+            MusicTrack::Cave => "music/cave",
+            MusicTrack::Storm => "music/storm",
+            MusicTrack::Combat => "music/combat",
+            MusicTrack::Flight => "music/flight",
         }
     }
 
@@ -136,6 +464,70 @@ impl MusicManager {
                 Self::apply_lowpass(&mut samples, 0.15);
                 Self::apply_lowpass(&mut samples, 0.15);
             }
+            MusicTrack::Forest => {
+                // Warm G major pentatonic, a little brighter than CalmDay
+                Self::add_ambient_pad(&mut samples, &[
+                    (196.00, 0.0),   // G3
+                    (220.00, 6.0),   // A3
+                    (293.66, 13.0),  // D4
+                    (329.63, 22.0),  // E4
+                ], 0.11, sample_rate);
+            }
+            MusicTrack::Desert => {
+                // Phrygian half-step between E and F for a dry, exotic colour
+                Self::add_ambient_pad(&mut samples, &[
+                    (164.81, 0.0),   // E3
+                    (174.61, 7.0),   // F3
+                    (246.94, 14.0),  // B3
+                    (329.63, 24.0),  // E4
+                ], 0.10, sample_rate);
+            }
+            MusicTrack::Frost => {
+                // High, sparse and cold - open fifths with lots of space
+                Self::add_ambient_pad(&mut samples, &[
+                    (493.88, 0.0),   // B4
+                    (659.25, 12.0),  // E5
+                    (739.99, 26.0),  // F#5
+                ], 0.07, sample_rate);
+            }
+            MusicTrack::Cave => {
+                // Low tritone drone, muffled like it's coming through rock
+                Self::add_ambient_pad(&mut samples, &[
+                    (65.41, 0.0),    // C2
+                    (92.50, 9.0),    // F#2
+                    (123.47, 18.0),  // B2
+                ], 0.14, sample_rate);
+                Self::apply_lowpass(&mut samples, 0.3);
+            }
+            MusicTrack::Storm => {
+                // Brooding D minor 7th under a slow heartbeat
+                Self::add_ambient_pad(&mut samples, &[
+                    (73.42, 0.0),    // D2
+                    (87.31, 5.0),    // F2
+                    (110.00, 11.0),  // A2
+                    (130.81, 18.0),  // C3
+                ], 0.13, sample_rate);
+                Self::add_pulse(&mut samples, 55.0, 50.0, 0.25, sample_rate);
+            }
+            MusicTrack::Combat => {
+                // Tense E minor with a driving pulse
+                Self::add_ambient_pad(&mut samples, &[
+                    (82.41, 0.0),    // E2
+                    (123.47, 2.0),   // B2
+                    (196.00, 4.0),   // G3
+                    (233.08, 8.0),   // A#3 (tension)
+                ], 0.12, sample_rate);
+                Self::add_pulse(&mut samples, 65.0, 132.0, 0.35, sample_rate);
+            }
+            MusicTrack::Flight => {
+                // Soaring D major add9, high and open
+                Self::add_ambient_pad(&mut samples, &[
+                    (293.66, 0.0),   // D4
+                    (440.00, 4.0),   // A4
+                    (659.25, 10.0),  // E5
+                    (739.99, 17.0),  // F#5
+                ], 0.09, sample_rate);
+            }
         }
 
         // Apply long fade in/out for seamless looping
@@ -203,6 +595,23 @@ impl MusicManager {
         }
     }
 
+    // Add a drum-like low thump on every beat
+    fn add_pulse(samples: &mut [i16], freq: f32, bpm: f32, volume: f32, sample_rate: u32) {
+        let beat_secs = 60.0 / bpm;
+        let thump_secs = 0.25_f32.min(beat_secs);
+        for (i, sample) in samples.iter_mut().enumerate() {
+            let t = i as f32 / sample_rate as f32;
+            let beat_time = t % beat_secs;
+            if beat_time >= thump_secs {
+                continue;
+            }
+            let env = (1.0 - beat_time / thump_secs).powf(3.0);
+            let value = (2.0 * std::f32::consts::PI * freq * beat_time).sin() * env * volume;
+            let mixed = (*sample as f32 / i16::MAX as f32 + value).clamp(-1.0, 1.0);
+            *sample = (mixed * i16::MAX as f32) as i16;
+        }
+    }
+
     fn apply_lowpass(samples: &mut [i16], factor: f32) {
         // Simple moving average low-pass filter
         let mut prev = samples[0] as f32;
@@ -216,18 +625,237 @@ impl MusicManager {
 
     pub fn set_volume(&mut self, volume: f32) {
         self.music_volume = volume.clamp(0.0, 1.0);
-        if let Some(sink) = &self.music_sink {
-            sink.set_volume(self.music_volume);
+        if let Some(playing) = &mut self.current {
+            playing.volume = self.music_volume;
+            self.backend.set_music_volume(playing.voice, playing.volume);
         }
     }
 
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
         if !enabled {
-            if let Some(sink) = self.music_sink.take() {
-                sink.stop();
+            for playing in self.current.take().into_iter().chain(self.fading_out.drain(..)) {
+                self.backend.stop_music(playing.voice);
+            }
+        }
+    }
+}
+
+/// Master level for ambient beds, below the music
+const AMBIENT_VOLUME: f32 = 0.12;
+/// Seconds for a bed to fade fully in or out
+const AMBIENT_FADE_TIME: f32 = 1.5;
+/// Lava and flowing water are heard from this many blocks away
+pub const AMBIENT_SOURCE_RANGE: f32 = 10.0;
+/// Wind starts above this height and is at full strength WIND_FULL_RANGE blocks higher
+const WIND_START_ALTITUDE: f32 = 80.0;
+const WIND_FULL_RANGE: f32 = 40.0;
+
+/// Looping background layers mixed together depending on where the player is
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AmbientBed {
+    Rain,
+    Wind,
+    Cave,
+    Lava,
+    FlowingWater,
+    Birds,
+}
+
+impl AmbientBed {
+    pub const ALL: [AmbientBed; 6] = [
+        AmbientBed::Rain, AmbientBed::Wind, AmbientBed::Cave,
+        AmbientBed::Lava, AmbientBed::FlowingWater, AmbientBed::Birds,
+    ];
+
+    fn asset(&self) -> &'static str {
+        match self {
+            AmbientBed::Rain => "ambient/rain",
+            AmbientBed::Wind => "ambient/wind",
+            AmbientBed::Cave => "ambient/cave",
+            AmbientBed::Lava => "ambient/lava",
+            AmbientBed::FlowingWater => "ambient/water",
+            AmbientBed::Birds => "ambient/birds",
+        }
+    }
+}
+
+/// What the ambient mix looks at
+#[derive(Clone, Copy, Debug)]
+pub struct AmbienceContext {
+    pub time_of_day: f32,
+    pub biome: Biome,
+    pub altitude: f32,
+    pub rain_intensity: f32,             // 0 when it isn't raining
+    pub sheltered: bool,                 // Roof overhead - rain sounds distant
+    pub underground: bool,
+    pub underwater: bool,
+    pub lava_distance: Option<f32>,
+    pub flowing_water_distance: Option<f32>,
+}
+
+// Ambient soundscape manager
+pub struct AmbienceManager {
+    backend: Box<dyn AudioBackend>,
+    playing: HashMap<AmbientBed, (MusicVoice, f32)>,  // Voice and current volume
+    bed_cache: HashMap<AmbientBed, SoundBuffer>,
+}
+
+impl AmbienceManager {
+    pub fn new() -> Self {
+        Self::with_backend(default_backend())
+    }
+
+    pub fn with_backend(backend: Box<dyn AudioBackend>) -> Self {
+        Self {
+            backend,
+            playing: HashMap::new(),
+            bed_cache: HashMap::new(),
+        }
+    }
+
+    /// Fade every bed toward its level for this context, starting and stopping loops as needed
+    pub fn update(&mut self, dt: f32, context: &AmbienceContext) {
+        let step = AMBIENT_VOLUME * dt / AMBIENT_FADE_TIME;
+        for bed in AmbientBed::ALL {
+            let target = Self::bed_level(bed, context) * AMBIENT_VOLUME;
+            match self.playing.get_mut(&bed) {
+                Some((voice, volume)) => {
+                    *volume = if *volume < target { (*volume + step).min(target) } else { (*volume - step).max(target) };
+                    if *volume <= 0.0 {
+                        self.backend.stop_music(*voice);
+                        self.playing.remove(&bed);
+                    } else {
+                        self.backend.set_music_volume(*voice, *volume);
+                    }
+                }
+                None if target > 0.0 => {
+                    let buffer = self.bed_cache.entry(bed)
+                        .or_insert_with(|| load_or_generate(bed.asset(), || Self::generate_bed(bed)));
+                    let voice = self.backend.play_loop(bed.asset(), &buffer.samples, buffer.sample_rate, 0.0);
+                    self.playing.insert(bed, (voice, 0.0));
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// How loud a bed should be in this context, 0.0 - 1.0
+    fn bed_level(bed: AmbientBed, context: &AmbienceContext) -> f32 {
+        let is_day = context.time_of_day > 0.25 && context.time_of_day < 0.75;
+        let proximity = |distance: Option<f32>| distance.map_or(0.0, |d| (1.0 - d / AMBIENT_SOURCE_RANGE).max(0.0));
+        let level = match bed {
+            AmbientBed::Rain if context.sheltered || context.underground => context.rain_intensity * 0.3,
+            AmbientBed::Rain => context.rain_intensity,
+            AmbientBed::Wind if context.underground => 0.0,
+            AmbientBed::Wind => ((context.altitude - WIND_START_ALTITUDE) / WIND_FULL_RANGE).clamp(0.0, 1.0),
+            AmbientBed::Cave => if context.underground { 1.0 } else { 0.0 },
+            AmbientBed::Lava => proximity(context.lava_distance),
+            AmbientBed::FlowingWater => proximity(context.flowing_water_distance),
+            AmbientBed::Birds => {
+                let birds_out = context.biome == Biome::Forest && is_day && !context.underground
+                    && context.rain_intensity < 0.1;
+                if birds_out && !context.underwater { 1.0 } else { 0.0 }
+            }
+        };
+        // Everything but the water itself is barely audible underwater
+        if context.underwater && bed != AmbientBed::FlowingWater { level * 0.3 } else { level }
+    }
+
+    fn generate_bed(bed: AmbientBed) -> Vec<i16> {
+        let sample_rate = 44100;
+        let mut rng = rand::thread_rng();
+        match bed {
+            AmbientBed::Rain => {
+                // Steady hiss with patters of individual drops on top
+                let mut samples = Self::steady_noise(4.0, 0.35, sample_rate);
+                MusicManager::apply_lowpass(&mut samples, 0.5);
+                for _ in 0..120 {
+                    let at = rng.gen_range(0..samples.len());
+                    Self::mix_in(&mut samples, at, &generate_noise_burst(8, 0.4));
+                }
+                samples
+            }
+            AmbientBed::Wind => {
+                // Heavily filtered noise swelling and fading (a whole number of swells per loop)
+                let mut samples = Self::steady_noise(10.0, 0.9, sample_rate);
+                MusicManager::apply_lowpass(&mut samples, 0.02);
+                MusicManager::apply_lowpass(&mut samples, 0.05);
+                let len = samples.len() as f32;
+                for (i, sample) in samples.iter_mut().enumerate() {
+                    let swell = 0.6 + 0.4 * (2.0 * std::f32::consts::PI * 3.0 * i as f32 / len).sin();
+                    *sample = (*sample as f32 * swell) as i16;
+                }
+                samples
+            }
+            AmbientBed::Cave => {
+                // Sparse drips, each followed by fading echoes
+                let mut samples = vec![0i16; sample_rate as usize * 8];
+                for _ in 0..5 {
+                    let at = rng.gen_range(0..samples.len());
+                    let pitch = rng.gen_range(1200.0..2200.0);
+                    let drip = generate_rising_tone(40, pitch, pitch * 1.4, 0.5);
+                    for (echo, gain) in [(0.0, 1.0), (0.25, 0.4), (0.5, 0.16), (0.75, 0.06)] {
+                        let echo_drip: Vec<i16> = drip.iter().map(|&s| (s as f32 * gain) as i16).collect();
+                        let offset = (at + (echo * sample_rate as f32) as usize) % samples.len();
+                        Self::mix_in(&mut samples, offset, &echo_drip);
+                    }
+                }
+                samples
+            }
+            AmbientBed::Lava => {
+                // Low rumble with occasional bubbles popping
+                let mut samples = Self::steady_noise(6.0, 0.5, sample_rate);
+                MusicManager::apply_lowpass(&mut samples, 0.03);
+                for _ in 0..10 {
+                    let at = rng.gen_range(0..samples.len());
+                    let pitch = rng.gen_range(70.0..120.0);
+                    Self::mix_in(&mut samples, at, &generate_rising_tone(90, pitch, pitch * 2.5, 0.5));
+                }
+                samples
+            }
+            AmbientBed::FlowingWater => {
+                // Babbling: filtered noise with a quick, uneven wobble
+                let mut samples = Self::steady_noise(4.0, 0.5, sample_rate);
+                MusicManager::apply_lowpass(&mut samples, 0.2);
+                let len = samples.len() as f32;
+                for (i, sample) in samples.iter_mut().enumerate() {
+                    let phase = 2.0 * std::f32::consts::PI * i as f32 / len;
+                    let wobble = 0.7 + 0.2 * (phase * 11.0).sin() + 0.1 * (phase * 29.0).sin();
+                    *sample = (*sample as f32 * wobble) as i16;
+                }
+                samples
+            }
+            AmbientBed::Birds => {
+                // A few short phrases of two or three chirps
+                let mut samples = vec![0i16; sample_rate as usize * 12];
+                for _ in 0..6 {
+                    let mut at = rng.gen_range(0..samples.len());
+                    let pitch = rng.gen_range(2500.0..4000.0);
+                    for _ in 0..rng.gen_range(2..=3) {
+                        Self::mix_in(&mut samples, at, &generate_rising_tone(70, pitch, pitch * 1.3, 0.3));
+                        at += sample_rate as usize / 8;
+                    }
+                }
+                samples
             }
-            self.current_track = None;
+        }
+    }
+
+    /// Unenveloped white noise, so the loop point doesn't click
+    fn steady_noise(duration_secs: f32, volume: f32, sample_rate: u32) -> Vec<i16> {
+        let mut rng = rand::thread_rng();
+        (0..(sample_rate as f32 * duration_secs) as usize)
+            .map(|_| (rng.gen_range(-1.0..1.0) * volume * i16::MAX as f32) as i16)
+            .collect()
+    }
+
+    /// Add a sound into a loop at `offset`, wrapping past the end back to the start
+    fn mix_in(samples: &mut [i16], offset: usize, sound: &[i16]) {
+        let len = samples.len();
+        for (i, &s) in sound.iter().enumerate() {
+            let target = &mut samples[(offset + i) % len];
+            *target = target.saturating_add(s);
         }
     }
 }
@@ -345,135 +973,560 @@ fn generate_thunder(volume: f32) -> Vec<i16> {
     samples
 }
 
-pub struct AudioManager {
-    _stream: OutputStream,
-    handle: OutputStreamHandle,
-    enabled: bool,
+fn generate_explosion(volume: f32) -> Vec<i16> {
+    // Thunder rumble cut short, with a sharp noise crack on top
+    let rumble = generate_thunder(volume);
+    let crack = generate_noise_burst(400, volume);
+    rumble.iter()
+        .take(44100)
+        .enumerate()
+        .map(|(i, r)| r.saturating_add(crack.get(i).copied().unwrap_or(0)))
+        .collect()
 }
 
-impl AudioManager {
-    pub fn new() -> Option<Self> {
-        match OutputStream::try_default() {
-            Ok((stream, handle)) => Some(Self {
-                _stream: stream,
-                handle,
-                enabled: true,
-            }),
-            Err(_) => {
-                eprintln!("Warning: Could not initialize audio system");
-                None
+// ========== Sound Registry ==========
+
+/// Where sound assets live: `<SOUND_ASSET_DIR>/<variant>.wav` (or `.ogg`)
+const SOUND_ASSET_DIR: &str = "src/sounds";
+const SOUND_ASSET_EXTENSIONS: [&str; 2] = ["wav", "ogg"];
+
+/// Procedural sound used when an event's asset file is missing (synthesized at full volume)
+#[derive(Clone, Copy, Debug)]
+pub enum SoundFallback {
+    NoiseBurst { duration_ms: u32 },
+    Sine { frequency: f32, duration_ms: u32 },
+    RisingTone { duration_ms: u32, freq_start: f32, freq_end: f32 },
+    Splash,
+    Thunder,
+    Explosion,
+}
+
+impl SoundFallback {
+    fn synthesize(&self) -> Vec<i16> {
+        match *self {
+            SoundFallback::NoiseBurst { duration_ms } => generate_noise_burst(duration_ms, 1.0),
+            SoundFallback::Sine { frequency, duration_ms } => generate_sine_wave(frequency, duration_ms, 1.0),
+            SoundFallback::RisingTone { duration_ms, freq_start, freq_end } => {
+                generate_rising_tone(duration_ms, freq_start, freq_end, 1.0)
             }
+            SoundFallback::Splash => {
+                // Noisy slosh over a low thump
+                let noise = generate_noise_burst(200, 1.0);
+                let thump = generate_sine_wave(80.0, 200, 0.6);
+                noise.iter()
+                    .zip(thump.iter())
+                    .map(|(n, s)| ((*n as i32 + *s as i32) / 2) as i16)
+                    .collect()
+            }
+            SoundFallback::Thunder => generate_thunder(1.0),
+            SoundFallback::Explosion => generate_explosion(1.0),
         }
     }
+}
 
-    fn random_pitch(&self, min: f32, max: f32) -> f32 {
-        rand::thread_rng().gen_range(min..max)
-    }
+/// A named sound (e.g. `block.break.stone`) and how to play it
+#[derive(Clone, Debug)]
+pub struct SoundEvent {
+    pub variants: Vec<String>,   // Asset paths relative to SOUND_ASSET_DIR, one picked at random
+    pub pitch: (f32, f32),       // Random playback speed range
+    pub volume: f32,
+    pub fallback: SoundFallback,
+    pub cooldown: f32,           // Minimum seconds between plays (0 = unlimited)
+}
 
-    pub fn play_block_break(&self, block_type: BlockType) {
-        if !self.enabled {
-            return;
+/// Decoded (or synthesized) mono samples, shared between plays
+#[derive(Clone, Debug)]
+pub struct SoundBuffer {
+    pub samples: Arc<Vec<i16>>,
+    pub sample_rate: u32,
+}
+
+/// Load a WAV/OGG file and mix it down to mono. Returns None if the file is missing or can't be decoded
+pub fn load_sound_asset(path: &Path) -> Option<SoundBuffer> {
+    let file = File::open(path).ok()?;
+    let decoder = match rodio::Decoder::new(BufReader::new(file)) {
+        Ok(decoder) => decoder,
+        Err(e) => {
+            eprintln!("Audio: Could not decode {}: {:?}", path.display(), e);
+            return None;
         }
+    };
+    let channels = decoder.channels().max(1) as usize;
+    let sample_rate = decoder.sample_rate();
+    let interleaved: Vec<i16> = decoder.collect();
+    let samples = interleaved
+        .chunks(channels)
+        .map(|frame| (frame.iter().map(|&s| s as i32).sum::<i32>() / frame.len() as i32) as i16)
+        .collect();
+    Some(SoundBuffer { samples: Arc::new(samples), sample_rate })
+}
+
+/// Maps sound event names to asset variants, caching every decoded or synthesized buffer
+pub struct SoundRegistry {
+    events: HashMap<String, SoundEvent>,
+    cache: RefCell<HashMap<String, SoundBuffer>>,
+    asset_dir: PathBuf,
+}
 
-        let (duration, volume) = match block_type {
-            BlockType::Stone | BlockType::Cobblestone => (80, 0.08),
-            BlockType::Dirt | BlockType::Grass => (60, 0.06),
-            BlockType::Sand | BlockType::Gravel => (50, 0.05),
-            BlockType::Wood => (70, 0.06),
-            BlockType::Leaves => (40, 0.04),
-            BlockType::Ice | BlockType::Snow => (60, 0.05),
-            _ => (70, 0.06),
+impl SoundRegistry {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            events: HashMap::new(),
+            cache: RefCell::new(HashMap::new()),
+            asset_dir: PathBuf::from(SOUND_ASSET_DIR),
         };
+        registry.register_default_sounds();
+        registry
+    }
 
-        let samples = generate_noise_burst(duration, volume);
-        let sample_rate = (44100.0 * self.random_pitch(0.9, 1.1)) as u32;
-        self.play_samples(samples, sample_rate);
+    /// Register an event with `variant_count` numbered assets (`<path>1`, `<path>2`, ...); returns it
+    /// so callers can tweak extras like the cooldown
+    pub fn register(&mut self, name: &str, path: &str, variant_count: usize, pitch: (f32, f32), volume: f32, fallback: SoundFallback) -> &mut SoundEvent {
+        let variants = if variant_count <= 1 {
+            vec![path.to_string()]
+        } else {
+            (1..=variant_count).map(|i| format!("{}{}", path, i)).collect()
+        };
+        self.events.insert(name.to_string(), SoundEvent { variants, pitch, volume, fallback, cooldown: 0.0 });
+        self.events.get_mut(name).unwrap()
     }
 
-    pub fn play_block_place(&self, block_type: BlockType) {
-        if !self.enabled {
-            return;
+    fn register_default_sounds(&mut self) {
+        use SoundFallback::*;
+
+        // === BLOCKS ===
+        for (material, duration_ms, volume) in [
+            ("stone", 80, 0.08), ("dirt", 60, 0.06), ("sand", 50, 0.05),
+            ("wood", 70, 0.06), ("leaves", 40, 0.04), ("snow", 60, 0.05), ("generic", 70, 0.06),
+        ] {
+            self.register(&format!("block.break.{}", material), &format!("block/break/{}", material), 3,
+                (0.9, 1.1), volume, NoiseBurst { duration_ms });
+        }
+        for (material, frequency, duration_ms, volume) in [
+            ("stone", 150.0, 100, 0.08), ("dirt", 120.0, 80, 0.06), ("sand", 140.0, 85, 0.06),
+            ("wood", 200.0, 90, 0.06), ("leaves", 140.0, 85, 0.06), ("snow", 140.0, 85, 0.06), ("generic", 140.0, 85, 0.06),
+        ] {
+            self.register(&format!("block.place.{}", material), &format!("block/place/{}", material), 3,
+                (0.95, 1.05), volume, Sine { frequency, duration_ms });
+        }
+        for (material, duration_ms, volume) in [
+            ("stone", 30, 0.015), ("dirt", 25, 0.012), ("sand", 35, 0.010),
+            ("wood", 20, 0.015), ("leaves", 25, 0.012), ("snow", 40, 0.008), ("generic", 25, 0.012),
+        ] {
+            self.register(&format!("step.{}", material), &format!("step/{}", material), 4,
+                (0.85, 1.15), volume, NoiseBurst { duration_ms });
         }
 
-        let pitch = self.random_pitch(0.95, 1.05);
-        let (freq, duration, volume) = match block_type {
-            BlockType::Stone | BlockType::Cobblestone => (150.0, 100, 0.08),
-            BlockType::Dirt | BlockType::Grass => (120.0, 80, 0.06),
-            BlockType::Wood => (200.0, 90, 0.06),
-            _ => (140.0, 85, 0.06),
-        };
+        // === PLAYER ===
+        self.register("player.jump", "player/jump", 1, (1.0, 1.0), 0.04,
+            RisingTone { duration_ms: 100, freq_start: 200.0, freq_end: 500.0 });
+        self.register("player.land", "player/land", 1, (1.0, 1.0), 0.05, NoiseBurst { duration_ms: 60 });
+        self.register("player.splash", "player/splash", 2, (0.95, 1.05), 0.08, Splash);
+
+        // === WORLD ===
+        self.register("weather.thunder", "weather/thunder", 3, (0.9, 1.1), 1.0, Thunder);
+        self.register("entity.explode", "entity/explode", 2, (0.9, 1.1), 0.5, Explosion);
+
+        // === MOBS ===
+        // Cooldowns keep a crowd of mobs from stacking the same sound dozens of times
+        for mob in [HostileMobType::Zombie, HostileMobType::Skeleton, HostileMobType::Spider, HostileMobType::Creeper] {
+            let name = Self::mob_name(mob);
+            let voice = match mob {
+                HostileMobType::Zombie => 110.0,
+                HostileMobType::Skeleton => 320.0,
+                HostileMobType::Spider => 520.0,
+                HostileMobType::Creeper => 180.0,
+            };
+            let idle = match mob {
+                HostileMobType::Skeleton | HostileMobType::Spider => NoiseBurst { duration_ms: 150 },
+                _ => RisingTone { duration_ms: 500, freq_start: voice, freq_end: voice * 0.8 },
+            };
+            self.register(&format!("mob.{}.idle", name), &format!("mob/{}/idle", name), 3, (0.9, 1.1), 0.08, idle)
+                .cooldown = 1.0;
+            self.register(&format!("mob.{}.hurt", name), &format!("mob/{}/hurt", name), 2, (0.9, 1.1), 0.10,
+                RisingTone { duration_ms: 180, freq_start: voice * 2.0, freq_end: voice })
+                .cooldown = 0.15;
+            self.register(&format!("mob.{}.death", name), &format!("mob/{}/death", name), 1, (0.9, 1.1), 0.12,
+                RisingTone { duration_ms: 600, freq_start: voice * 1.5, freq_end: voice * 0.5 })
+                .cooldown = 0.1;
+            self.register(&format!("mob.{}.attack", name), &format!("mob/{}/attack", name), 2, (0.9, 1.1), 0.10,
+                NoiseBurst { duration_ms: 90 })
+                .cooldown = 0.3;
+        }
+        // Hiss lasts exactly as long as the creeper's fuse
+        self.register("mob.creeper.fuse", "mob/creeper/fuse", 1, (1.0, 1.0), 0.15,
+            NoiseBurst { duration_ms: (CREEPER_FUSE_TIME * 1000.0) as u32 })
+            .cooldown = 0.5;
+
+        // === ANIMALS ===
+        for animal in [
+            AnimalType::Pig, AnimalType::Cow, AnimalType::Sheep, AnimalType::Chicken, AnimalType::Rabbit,
+            AnimalType::Horse, AnimalType::Wolf, AnimalType::Fox, AnimalType::Fish, AnimalType::Squid,
+            AnimalType::Dolphin, AnimalType::Bee, AnimalType::Parrot, AnimalType::Bat,
+        ] {
+            let name = Self::animal_name(animal);
+            let voice = match animal {
+                AnimalType::Cow | AnimalType::Horse => 140.0,
+                AnimalType::Pig => 220.0,
+                AnimalType::Sheep | AnimalType::Wolf => 300.0,
+                AnimalType::Fox | AnimalType::Squid => 400.0,
+                AnimalType::Chicken | AnimalType::Rabbit | AnimalType::Fish => 700.0,
+                AnimalType::Parrot | AnimalType::Dolphin => 1200.0,
+                AnimalType::Bee => 250.0,
+                AnimalType::Bat => 2400.0,
+            };
+            let idle = match animal {
+                AnimalType::Bee => Sine { frequency: voice, duration_ms: 400 },
+                AnimalType::Fish | AnimalType::Squid => NoiseBurst { duration_ms: 80 },
+                _ => RisingTone { duration_ms: 300, freq_start: voice, freq_end: voice * 1.2 },
+            };
+            self.register(&format!("animal.{}.idle", name), &format!("animal/{}/idle", name), 3, (0.9, 1.1), 0.06, idle)
+                .cooldown = 0.8;
+            self.register(&format!("animal.{}.hurt", name), &format!("animal/{}/hurt", name), 2, (0.9, 1.1), 0.08,
+                RisingTone { duration_ms: 150, freq_start: voice * 1.8, freq_end: voice })
+                .cooldown = 0.15;
+            self.register(&format!("animal.{}.death", name), &format!("animal/{}/death", name), 1, (0.9, 1.1), 0.10,
+                RisingTone { duration_ms: 400, freq_start: voice * 1.5, freq_end: voice * 0.5 })
+                .cooldown = 0.1;
+        }
 
-        let samples = generate_sine_wave(freq * pitch, duration, volume);
-        self.play_samples(samples, 44100);
+        // === PROJECTILES & PLANES ===
+        self.register("entity.arrow.shoot", "entity/arrow/shoot", 2, (0.9, 1.2), 0.08,
+            RisingTone { duration_ms: 120, freq_start: 900.0, freq_end: 300.0 })
+            .cooldown = 0.1;
+        self.register("entity.arrow.hit", "entity/arrow/hit", 3, (0.9, 1.1), 0.08, NoiseBurst { duration_ms: 40 })
+            .cooldown = 0.05;
+        self.register("entity.plane.engine", "entity/plane/engine", 1, (0.95, 1.05), 0.05,
+            Sine { frequency: 90.0, duration_ms: 520 })
+            .cooldown = 0.45;
+        self.register("entity.missile.launch", "entity/missile/launch", 1, (0.95, 1.05), 0.15,
+            RisingTone { duration_ms: 400, freq_start: 200.0, freq_end: 1200.0 })
+            .cooldown = 0.1;
+        self.register("entity.bomb.drop", "entity/bomb/drop", 1, (0.95, 1.05), 0.10,
+            RisingTone { duration_ms: 800, freq_start: 1400.0, freq_end: 500.0 })
+            .cooldown = 0.1;
     }
 
-    pub fn play_footstep(&self, block_type: BlockType) {
-        if !self.enabled {
-            return;
+    fn mob_name(mob: HostileMobType) -> &'static str {
+        match mob {
+            HostileMobType::Zombie => "zombie",
+            HostileMobType::Skeleton => "skeleton",
+            HostileMobType::Spider => "spider",
+            HostileMobType::Creeper => "creeper",
         }
+    }
 
-        let (duration, volume) = match block_type {
-            BlockType::Stone | BlockType::Cobblestone => (30, 0.015),
-            BlockType::Dirt | BlockType::Grass => (25, 0.012),
-            BlockType::Sand | BlockType::Gravel => (35, 0.010),
-            BlockType::Wood => (20, 0.015),
-            BlockType::Snow => (40, 0.008),
-            _ => (25, 0.012),
-        };
+    fn animal_name(animal: AnimalType) -> &'static str {
+        match animal {
+            AnimalType::Pig => "pig",
+            AnimalType::Cow => "cow",
+            AnimalType::Sheep => "sheep",
+            AnimalType::Chicken => "chicken",
+            AnimalType::Rabbit => "rabbit",
+            AnimalType::Horse => "horse",
+            AnimalType::Wolf => "wolf",
+            AnimalType::Fox => "fox",
+            AnimalType::Fish => "fish",
+            AnimalType::Squid => "squid",
+            AnimalType::Dolphin => "dolphin",
+            AnimalType::Bee => "bee",
+            AnimalType::Parrot => "parrot",
+            AnimalType::Bat => "bat",
+        }
+    }
 
-        let samples = generate_noise_burst(duration, volume);
-        let sample_rate = (44100.0 * self.random_pitch(0.85, 1.15)) as u32;
-        self.play_samples(samples, sample_rate);
+    /// Event name for a sound an entity made
+    pub fn entity_sound_event(sound: EntitySound) -> String {
+        match sound {
+            EntitySound::MobIdle(mob) => format!("mob.{}.idle", Self::mob_name(mob)),
+            EntitySound::MobHurt(mob) => format!("mob.{}.hurt", Self::mob_name(mob)),
+            EntitySound::MobDeath(mob) => format!("mob.{}.death", Self::mob_name(mob)),
+            EntitySound::MobAttack(mob) => format!("mob.{}.attack", Self::mob_name(mob)),
+            EntitySound::CreeperFuse => "mob.creeper.fuse".to_string(),
+            EntitySound::AnimalIdle(animal) => format!("animal.{}.idle", Self::animal_name(animal)),
+            EntitySound::AnimalHurt(animal) => format!("animal.{}.hurt", Self::animal_name(animal)),
+            EntitySound::AnimalDeath(animal) => format!("animal.{}.death", Self::animal_name(animal)),
+            EntitySound::ArrowShoot => "entity.arrow.shoot".to_string(),
+            EntitySound::ArrowHit => "entity.arrow.hit".to_string(),
+            EntitySound::PlaneEngine => "entity.plane.engine".to_string(),
+            EntitySound::MissileLaunch => "entity.missile.launch".to_string(),
+            EntitySound::BombDrop => "entity.bomb.drop".to_string(),
+        }
     }
 
-    pub fn play_splash(&self) {
-        if !self.enabled {
-            return;
+    /// Pick a random variant of an event and return its buffer (loading or synthesizing it on first use)
+    pub fn pick<R: Rng>(&self, name: &str, rng: &mut R) -> Option<(SoundBuffer, &SoundEvent)> {
+        let event = self.events.get(name)?;
+        let variant = &event.variants[rng.gen_range(0..event.variants.len())];
+        if let Some(buffer) = self.cache.borrow().get(variant) {
+            return Some((buffer.clone(), event));
         }
 
-        let noise_samples = generate_noise_burst(200, 0.08);
-        let sine_samples = generate_sine_wave(80.0, 200, 0.05);
+        let buffer = SOUND_ASSET_EXTENSIONS.iter()
+            .map(|ext| self.asset_dir.join(format!("{}.{}", variant, ext)))
+            .find(|path| path.exists())
+            .and_then(|path| load_sound_asset(&path))
+            .unwrap_or_else(|| SoundBuffer {
+                samples: Arc::new(event.fallback.synthesize()),
+                sample_rate: 44100,
+            });
+        self.cache.borrow_mut().insert(variant.clone(), buffer.clone());
+        Some((buffer, event))
+    }
+
+    /// Which material family a block's sounds come from
+    pub fn block_material(block_type: BlockType) -> &'static str {
+        match block_type {
+            BlockType::Stone | BlockType::Cobblestone => "stone",
+            BlockType::Dirt | BlockType::Grass => "dirt",
+            BlockType::Sand | BlockType::Gravel => "sand",
+            BlockType::Wood => "wood",
+            BlockType::Leaves => "leaves",
+            BlockType::Ice | BlockType::Snow => "snow",
+            _ => "generic",
+        }
+    }
+}
 
-        let samples: Vec<i16> = noise_samples.iter()
-            .zip(sine_samples.iter())
-            .map(|(n, s)| ((*n as i32 + *s as i32) / 2) as i16)
-            .collect();
+pub struct AudioManager {
+    backend: Box<dyn AudioBackend>,
+    enabled: bool,
+    listener: Listener,
+    sounds: SoundRegistry,
+    last_played: RefCell<HashMap<String, Instant>>,  // For per-event cooldowns
+}
 
-        self.play_samples(samples, 44100);
+impl AudioManager {
+    pub fn new() -> Self {
+        Self::with_backend(default_backend())
     }
 
-    pub fn play_jump(&self) {
-        if !self.enabled {
-            return;
+    pub fn with_backend(backend: Box<dyn AudioBackend>) -> Self {
+        Self {
+            backend,
+            enabled: true,
+            listener: Listener::new(Point3::new(0.0, 0.0, 0.0), 0.0, false),
+            sounds: SoundRegistry::new(),
+            last_played: RefCell::new(HashMap::new()),
         }
+    }
+
+    /// Move the listener to the camera (call once per frame)
+    pub fn set_listener(&mut self, position: Point3<f32>, yaw: f32, underwater: bool) {
+        self.listener = Listener::new(position, yaw, underwater);
+    }
+
+    pub fn play_block_break(&self, block_type: BlockType, pos: (i32, i32, i32)) {
+        let event = format!("block.break.{}", SoundRegistry::block_material(block_type));
+        self.play_event_at(&event, Self::block_center(pos));
+    }
+
+    pub fn play_block_place(&self, block_type: BlockType, pos: (i32, i32, i32)) {
+        let event = format!("block.place.{}", SoundRegistry::block_material(block_type));
+        self.play_event_at(&event, Self::block_center(pos));
+    }
+
+    /// Explosions (creepers, missiles, bombs) - loud enough to carry across the whole hearing range
+    pub fn play_explosion(&self, position: Point3<f32>) {
+        self.play_event_at("entity.explode", position);
+    }
+
+    /// Mob, animal, arrow and plane sounds reported by the EntityManager
+    pub fn play_entity_sound(&self, sound: EntitySound, position: Point3<f32>) {
+        self.play_event_at(&SoundRegistry::entity_sound_event(sound), position);
+    }
+
+    pub fn play_footstep(&self, block_type: BlockType) {
+        let event = format!("step.{}", SoundRegistry::block_material(block_type));
+        self.play_event(&event, 1.0);
+    }
+
+    pub fn play_splash(&self) {
+        self.play_event("player.splash", 1.0);
+    }
 
-        let samples = generate_rising_tone(100, 200.0, 500.0, 0.04);
-        self.play_samples(samples, 44100);
+    pub fn play_jump(&self) {
+        self.play_event("player.jump", 1.0);
     }
 
     pub fn play_land(&self) {
+        self.play_event("player.land", 1.0);
+    }
+
+    pub fn play_thunder(&self, volume: f32) {
+        self.play_event("weather.thunder", volume);
+    }
+
+    fn block_center((x, y, z): (i32, i32, i32)) -> Point3<f32> {
+        Point3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5)
+    }
+
+    /// Play one of the player's own sounds, scaled by `gain`
+    pub fn play_event(&self, name: &str, gain: f32) {
+        let mix = self.listener.own_mix();
+        self.play_event_with_mix(name, SpatialMix { left: mix.left * gain, right: mix.right * gain, ..mix });
+    }
+
+    /// Play a sound emitted somewhere in the world, panned and attenuated relative to the listener
+    pub fn play_event_at(&self, name: &str, position: Point3<f32>) {
+        if let Some(mix) = self.listener.spatialize(position) {
+            self.play_event_with_mix(name, mix);
+        }
+    }
+
+    fn play_event_with_mix(&self, name: &str, mix: SpatialMix) {
         if !self.enabled {
             return;
         }
+        let mut rng = rand::thread_rng();
+        let Some((buffer, event)) = self.sounds.pick(name, &mut rng) else {
+            eprintln!("Audio: Unknown sound event {}", name);
+            return;
+        };
+        if event.cooldown > 0.0 {
+            let now = Instant::now();
+            let mut last_played = self.last_played.borrow_mut();
+            if let Some(last) = last_played.get(name) {
+                if now.duration_since(*last).as_secs_f32() < event.cooldown {
+                    return;
+                }
+            }
+            last_played.insert(name.to_string(), now);
+        }
+        let (min, max) = event.pitch;
+        let pitch = if max > min { rng.gen_range(min..max) } else { min };
+        let mix = SpatialMix { left: mix.left * event.volume, right: mix.right * event.volume, ..mix };
+        self.backend.play_sound(name, &buffer.samples, (buffer.sample_rate as f32 * pitch) as u32, mix);
+    }
+}
 
-        let samples = generate_noise_burst(60, 0.05);
-        self.play_samples(samples, 44100);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    /// Something a RecordingBackend was asked to play
+    #[derive(Clone, Debug, PartialEq)]
+    enum AudioLogEntry {
+        Sound { event: String, mix: SpatialMix },
+        Music { voice: MusicVoice, track: String },
+        Loop { voice: MusicVoice, name: String },
+        MusicStopped { voice: MusicVoice },
     }
 
-    pub fn play_thunder(&self, volume: f32) {
-        if !self.enabled {
-            return;
+    /// Shared view of a RecordingBackend's log, kept by whoever wants to inspect it
+    type AudioLog = Rc<RefCell<Vec<AudioLogEntry>>>;
+
+    /// Test backend - plays nothing and logs every request
+    #[derive(Default)]
+    struct RecordingBackend {
+        log: AudioLog,
+        next_voice: MusicVoice,
+    }
+
+    impl RecordingBackend {
+        fn new() -> Self {
+            Self::default()
+        }
+
+        /// Handle to the log that stays valid after the backend is boxed into a manager
+        fn log(&self) -> AudioLog {
+            Rc::clone(&self.log)
         }
 
-        let samples = generate_thunder(volume);
-        self.play_samples(samples, 44100);
+        /// Whether a sound event with this name has been played (e.g. `block.break.stone`)
+        fn played(log: &AudioLog, event: &str) -> bool {
+            log.borrow().iter().any(|entry| matches!(entry, AudioLogEntry::Sound { event: e, .. } if e == event))
+        }
     }
 
-    fn play_samples(&self, samples: Vec<i16>, sample_rate: u32) {
-        if let Ok(sink) = Sink::try_new(&self.handle) {
-            let source = rodio::buffer::SamplesBuffer::new(1, sample_rate, samples);
-            sink.append(source);
-            sink.detach();
+    impl AudioBackend for RecordingBackend {
+        fn play_sound(&self, event: &str, _samples: &[i16], _sample_rate: u32, mix: SpatialMix) {
+            self.log.borrow_mut().push(AudioLogEntry::Sound { event: event.to_string(), mix });
+        }
+
+        fn play_music(&mut self, track: &str, _samples: &[i16], _sample_rate: u32, _volume: f32) -> MusicVoice {
+            let voice = self.next_voice;
+            self.next_voice += 1;
+            self.log.borrow_mut().push(AudioLogEntry::Music { voice, track: track.to_string() });
+            voice
+        }
+
+        fn play_loop(&mut self, name: &str, _samples: &[i16], _sample_rate: u32, _volume: f32) -> MusicVoice {
+            let voice = self.next_voice;
+            self.next_voice += 1;
+            self.log.borrow_mut().push(AudioLogEntry::Loop { voice, name: name.to_string() });
+            voice
+        }
+
+        fn stop_music(&mut self, voice: MusicVoice) {
+            self.log.borrow_mut().push(AudioLogEntry::MusicStopped { voice });
         }
+
+        fn set_music_volume(&mut self, _voice: MusicVoice, _volume: f32) {}
+    }
+
+    /// Listener at the origin looking toward +X, so +Z is to its right
+    fn listener(underwater: bool) -> Listener {
+        Listener::new(Point3::new(0.0, 0.0, 0.0), 0.0, underwater)
+    }
+
+    #[test]
+    fn distance_falloff_reaches_silence_at_max_range() {
+        let ears = listener(false);
+        let near = ears.spatialize(Point3::new(1.0, 0.0, 0.0)).unwrap();
+        let mid = ears.spatialize(Point3::new(10.0, 0.0, 0.0)).unwrap();
+        let edge = ears.spatialize(Point3::new(MAX_HEARING_DISTANCE - 0.01, 0.0, 0.0)).unwrap();
+        assert_eq!(near, SpatialMix::CENTERED);
+        assert!(mid.left < near.left && mid.left > 0.0);
+        assert!(edge.left < 0.01 && edge.right < 0.01);
+        assert!(ears.spatialize(Point3::new(MAX_HEARING_DISTANCE + 1.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn emitter_to_the_right_pans_right() {
+        let ears = listener(false);
+        let right = ears.spatialize(Point3::new(0.0, 0.0, 5.0)).unwrap();
+        let left = ears.spatialize(Point3::new(0.0, 0.0, -5.0)).unwrap();
+        assert!(right.right > right.left);
+        assert!(left.left > left.right);
+        assert!((right.right - left.left).abs() < 1e-6);
+    }
+
+    #[test]
+    fn emitter_behind_is_centered_and_audible() {
+        let ears = listener(false);
+        let ahead = ears.spatialize(Point3::new(5.0, 0.0, 0.0)).unwrap();
+        let behind = ears.spatialize(Point3::new(-5.0, 0.0, 0.0)).unwrap();
+        assert!(behind.left > 0.0);
+        assert!((behind.left - behind.right).abs() < 1e-6);
+        assert!((behind.left - ahead.left).abs() < 1e-6);
+    }
+
+    #[test]
+    fn underwater_muffles_and_lowers_gain() {
+        let emitter = Point3::new(3.0, 0.0, 2.0);
+        let dry = listener(false).spatialize(emitter).unwrap();
+        let wet = listener(true).spatialize(emitter).unwrap();
+        assert!(wet.left < dry.left && wet.right < dry.right);
+        assert_eq!(dry.lowpass, None);
+        assert_eq!(wet.lowpass, Some(UNDERWATER_LOWPASS));
+        assert_eq!(listener(true).own_mix().lowpass, Some(UNDERWATER_LOWPASS));
+    }
+
+    #[test]
+    fn breaking_stone_plays_its_break_sound() {
+        let backend = RecordingBackend::new();
+        let log = backend.log();
+        let audio = AudioManager::with_backend(Box::new(backend));
+        audio.play_block_break(BlockType::Stone, (0, 0, 0));
+        assert!(RecordingBackend::played(&log, "block.break.stone"));
+        assert!(!RecordingBackend::played(&log, "block.break.wood"));
+    }
+
+    #[test]
+    fn mix_applies_gains_per_ear() {
+        let mix = SpatialMix { left: 1.0, right: 0.5, lowpass: None };
+        assert_eq!(mix.apply(&[1000, -2000]), vec![1000, 500, -2000, -1000]);
     }
 }
@@ -2,26 +2,138 @@ use cgmath::{Matrix4, Vector3, Point3, Deg, perspective, InnerSpace};
 use winit::event::VirtualKeyCode;
 use wgpu::SurfaceConfiguration;
 use crate::world::{World, BlockType, TorchFace, CollisionShape, BlockFacing};
+use crate::entity::VehicleKind;
+use crate::effect::{StatusEffectType, StatusEffects};
 
 const PLAYER_HEIGHT: f32 = 1.8;
+const SNEAK_EYE_HEIGHT: f32 = 1.5;
+const PRONE_EYE_HEIGHT: f32 = 0.6;   // Swimming and crawling, low enough to pass one-block gaps
+const RIDING_EYE_HEIGHT: f32 = 1.0; // Eyes above the seat when sitting
 const PLAYER_WIDTH: f32 = 0.6;
 const GRAVITY: f32 = 32.0;
 const JUMP_VELOCITY: f32 = 10.0;
 const TERMINAL_VELOCITY: f32 = 50.0;  // Max fall speed
 const MAX_PHYSICS_DT: f32 = 0.016;    // Cap physics step to ~60fps equivalent
+const THIRD_PERSON_DISTANCE: f32 = 4.0;   // How far the view sits from the player's head
+const THIRD_PERSON_MIN_DISTANCE: f32 = 0.3;
+const CAMERA_WALL_MARGIN: f32 = 0.2;      // Keep the view this far off any block in the way
+const CAMERA_PULL_OUT_RATE: f32 = 6.0;    // Blocks per second the view eases back out once clear
+const FREE_CAMERA_SPEED: f32 = 10.0;
+const DEATH_FALL_TIME: f32 = 0.6;        // Seconds for the body to topple over on death
+const SWIM_SINK_SPEED: f32 = 3.0;        // How fast sneaking in water dives
+const ARMOR_POINT_REDUCTION: f32 = 0.04; // Damage taken off per armor point
+const MAX_ARMOR_REDUCTION: f32 = 0.8;
+const LAVA_BURN_TIME: f32 = 8.0;         // Seconds the player keeps burning after leaving lava
+const FIRE_BURN_TIME: f32 = 4.0;         // Seconds the player keeps burning after stepping out of fire
 
 #[derive(Clone, Copy)]
 pub enum HungerAction {
     Walk,
     Jump,
     Sprint,
+    Sneak,
+    Swim,
+    Crawl,
     Attack,
 }
 
+/// What hurt the player, which decides how much worn armor helps
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DamageSource {
+    Mob,
+    Arrow,
+    Explosion,
+    Fall,
+    Lava,
+    Drowning,
+    Starvation,
+    Poison,
+    Fire,
+    Lightning,
+}
+
+impl DamageSource {
+    /// Fraction of the armor's protection that applies to this kind of damage
+    fn armor_effectiveness(&self) -> f32 {
+        match self {
+            DamageSource::Mob | DamageSource::Arrow => 1.0,
+            DamageSource::Explosion => 0.75,
+            DamageSource::Fall | DamageSource::Lightning => 0.5,
+            DamageSource::Lava | DamageSource::Drowning | DamageSource::Starvation
+            | DamageSource::Poison | DamageSource::Fire => 0.0,
+        }
+    }
+}
+
+/// How the player is moving on foot, each with its own height, speed and hunger cost
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MovementState {
+    Walking,
+    Sprinting,
+    Sneaking,   // Slow, lower eyes, won't step off edges
+    Swimming,   // Sprinting underwater: flat, follows the view up and down
+    Crawling,   // Flat on the ground to fit through one-block gaps
+}
+
+impl MovementState {
+    /// Eyes above the feet; the collision box reaches just above the eyes
+    pub fn eye_height(&self) -> f32 {
+        match self {
+            MovementState::Walking | MovementState::Sprinting => PLAYER_HEIGHT,
+            MovementState::Sneaking => SNEAK_EYE_HEIGHT,
+            MovementState::Swimming | MovementState::Crawling => PRONE_EYE_HEIGHT,
+        }
+    }
+
+    /// Horizontal speed in blocks per second
+    pub fn speed(&self) -> f32 {
+        match self {
+            MovementState::Walking => 4.3,
+            MovementState::Sprinting => 5.6,
+            MovementState::Sneaking => 1.3,
+            MovementState::Swimming => 3.5,
+            MovementState::Crawling => 1.3,
+        }
+    }
+
+    /// Hunger spent for each block travelled
+    fn hunger_action(&self) -> HungerAction {
+        match self {
+            MovementState::Walking => HungerAction::Walk,
+            MovementState::Sprinting => HungerAction::Sprint,
+            MovementState::Sneaking => HungerAction::Sneak,
+            MovementState::Swimming => HungerAction::Swim,
+            MovementState::Crawling => HungerAction::Crawl,
+        }
+    }
+}
+
+/// Where the view sits relative to the player: F5 cycles the attached modes, F6 detaches a free camera
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CameraMode {
+    FirstPerson,
+    ThirdPersonBack,
+    ThirdPersonFront,
+    Free,
+}
+
+impl CameraMode {
+    /// Next mode in the F5 cycle; leaving the free camera goes back to first person
+    pub fn cycled(&self) -> Self {
+        match self {
+            CameraMode::FirstPerson => CameraMode::ThirdPersonBack,
+            CameraMode::ThirdPersonBack => CameraMode::ThirdPersonFront,
+            CameraMode::ThirdPersonFront | CameraMode::Free => CameraMode::FirstPerson,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum VehicleState {
     OnFoot,
     InPlane,
+    InBoat,
+    InMinecart,
 }
 
 pub struct Camera {
@@ -43,6 +155,11 @@ pub struct Camera {
     pub moving_left: bool,
     pub moving_right: bool,
     jump_pressed: bool,
+    sprint_pressed: bool,
+    sneak_pressed: bool,
+    crawl_toggled: bool,
+    pub movement_state: MovementState,
+    travel_distance: f32,      // Blocks moved since hunger was last charged for it
     bob_time: f32,
 
     // Footstep tracking
@@ -62,6 +179,7 @@ pub struct Camera {
     pub saturation: f32,       // Hidden hunger buffer
     pub damage_cooldown: f32,  // Invincibility frames after damage
     pub is_dead: bool,         // Death state
+    death_time: f32,           // Seconds since dying, drives the fall-over animation
     pub air_supply: f32,       // 0.0-10.0 (10 bubbles, 15 seconds)
     pub fall_distance: f32,    // Track fall for damage calculation
     starvation_timer: f32,     // Timer for starvation damage
@@ -69,14 +187,36 @@ pub struct Camera {
     pub spawn_point: Point3<f32>, // Respawn location
     pub damage_flash: f32,     // Visual feedback timer for damage
     pub pending_knockback: Option<Vector3<f32>>, // Knockback to apply
+    pub armor_points: u32,     // Protection from worn armor, synced from the inventory
+    armor_hits: Vec<f32>,      // Damage of each hit armor absorbed since it was last worn down
+    pub effects: StatusEffects, // Timed poison, regeneration, speed, burning and so on
 
     // Vehicle/Plane controls
     pub vehicle_state: VehicleState,
     pub piloted_plane_id: Option<u32>,
+    pub ridden_vehicle_id: Option<u32>,
+    riding_yaw: Option<f32>,  // Vehicle heading last frame, so turning it turns the view too
     pub throttle_up: bool,
     pub throttle_down: bool,
     pub pitch_up: bool,
     pub pitch_down: bool,
+
+    // View placement
+    pub camera_mode: CameraMode,
+    attached_mode: CameraMode,      // Mode to return to when the free camera is switched off
+    third_person_distance: f32,     // Current distance, pulled in when blocks are in the way
+    view_position: Point3<f32>,     // Where the view is actually rendered from
+    view_yaw: f32,
+    view_pitch: f32,
+    free_position: Point3<f32>,
+    free_yaw: f32,
+    free_pitch: f32,
+    free_forward: bool,
+    free_backward: bool,
+    free_left: bool,
+    free_right: bool,
+    free_up: bool,
+    free_down: bool,
 }
 
 impl Camera {
@@ -100,6 +240,11 @@ impl Camera {
             moving_left: false,
             moving_right: false,
             jump_pressed: false,
+            sprint_pressed: false,
+            sneak_pressed: false,
+            crawl_toggled: false,
+            movement_state: MovementState::Walking,
+            travel_distance: 0.0,
             bob_time: 0.0,
 
             // Footstep tracking
@@ -119,6 +264,7 @@ impl Camera {
             saturation: 5.0,
             damage_cooldown: 0.0,
             is_dead: false,
+            death_time: 0.0,
             air_supply: 10.0,
             fall_distance: 0.0,
             starvation_timer: 0.0,
@@ -126,14 +272,35 @@ impl Camera {
             spawn_point: Point3::new(0.5, 50.0, 0.5),
             damage_flash: 0.0,
             pending_knockback: None,
+            armor_points: 0,
+            armor_hits: Vec::new(),
+            effects: StatusEffects::new(),
 
             // Vehicle/Plane controls
             vehicle_state: VehicleState::OnFoot,
             piloted_plane_id: None,
+            ridden_vehicle_id: None,
+            riding_yaw: None,
             throttle_up: false,
             throttle_down: false,
             pitch_up: false,
             pitch_down: false,
+
+            camera_mode: CameraMode::FirstPerson,
+            attached_mode: CameraMode::FirstPerson,
+            third_person_distance: THIRD_PERSON_DISTANCE,
+            view_position: Point3::new(0.5, 50.0, 0.5),
+            view_yaw: 0.0,
+            view_pitch: 0.0,
+            free_position: Point3::new(0.5, 50.0, 0.5),
+            free_yaw: 0.0,
+            free_pitch: 0.0,
+            free_forward: false,
+            free_backward: false,
+            free_left: false,
+            free_right: false,
+            free_up: false,
+            free_down: false,
         };
         camera.update_view_proj();
         camera
@@ -154,6 +321,20 @@ impl Camera {
     }
     
     pub fn process_keyboard(&mut self, key: VirtualKeyCode, pressed: bool) {
+        // The free camera takes over movement keys; the player stands still meanwhile
+        if self.camera_mode == CameraMode::Free {
+            match key {
+                VirtualKeyCode::W => self.free_forward = pressed,
+                VirtualKeyCode::S => self.free_backward = pressed,
+                VirtualKeyCode::A => self.free_left = pressed,
+                VirtualKeyCode::D => self.free_right = pressed,
+                VirtualKeyCode::Space => self.free_up = pressed,
+                VirtualKeyCode::LShift => self.free_down = pressed,
+                _ => {}
+            }
+            return;
+        }
+
         match self.vehicle_state {
             VehicleState::OnFoot => {
                 match key {
@@ -162,6 +343,9 @@ impl Camera {
                     VirtualKeyCode::A => self.moving_left = pressed,
                     VirtualKeyCode::D => self.moving_right = pressed,
                     VirtualKeyCode::Space => self.jump_pressed = pressed,
+                    VirtualKeyCode::LShift => self.sprint_pressed = pressed,
+                    VirtualKeyCode::LControl => self.sneak_pressed = pressed,
+                    VirtualKeyCode::X if pressed => self.crawl_toggled = !self.crawl_toggled,
                     _ => {}
                 }
             }
@@ -176,11 +360,26 @@ impl Camera {
                     _ => {}
                 }
             }
+            VehicleState::InBoat | VehicleState::InMinecart => {
+                match key {
+                    VirtualKeyCode::W => self.moving_forward = pressed,  // Paddle / push
+                    VirtualKeyCode::S => self.moving_backward = pressed, // Backpaddle / brake
+                    VirtualKeyCode::A => self.moving_left = pressed,
+                    VirtualKeyCode::D => self.moving_right = pressed,
+                    _ => {}
+                }
+            }
         }
     }
     
     pub fn process_mouse(&mut self, delta_x: f32, delta_y: f32) {
         let sensitivity = 0.15;
+        if self.camera_mode == CameraMode::Free {
+            self.free_yaw += delta_x * sensitivity;
+            self.free_pitch = (self.free_pitch - delta_y * sensitivity).clamp(-89.0, 89.0);
+            self.update_view_proj();
+            return;
+        }
         self.yaw += delta_x * sensitivity;
         self.pitch -= delta_y * sensitivity;
 
@@ -192,50 +391,50 @@ impl Camera {
 
     /// Check if a block at (bx, by, bz) would collide with a player checking from player_y
     /// Returns true if the block would block horizontal movement at the given Y level
-    fn block_collides_horizontal(block: BlockType, by: i32, player_y: f32) -> bool {
+    fn block_collides_horizontal(block: BlockType, by: i32, player_y: f32, eye_height: f32) -> bool {
         match block.collision_shape() {
             CollisionShape::None => false,
             CollisionShape::FullBlock => true,
             CollisionShape::SlabBottom => {
                 // Bottom slab: collides at y range [by, by+0.5]
-                let feet_y = player_y - PLAYER_HEIGHT;
+                let feet_y = player_y - eye_height;
                 feet_y < (by as f32 + 0.5)
             }
             CollisionShape::SlabTop => {
                 // Top slab: collides at y range [by+0.5, by+1.0]
-                let feet_y = player_y - PLAYER_HEIGHT;
+                let feet_y = player_y - eye_height;
                 feet_y >= (by as f32 + 0.5) || player_y > (by as f32 + 0.5)
             }
             CollisionShape::Fence => {
                 // Fence: 1.5 block height, collides if player overlaps
-                let feet_y = player_y - PLAYER_HEIGHT;
+                let feet_y = player_y - eye_height;
                 feet_y < (by as f32 + 1.5)
             }
         }
     }
 
     /// Check if a block would stop vertical movement (for ground collision)
-    fn block_collides_ground(block: BlockType, by: i32, player_feet_y: f32) -> Option<f32> {
+    fn block_collides_ground(block: BlockType, by: i32, player_feet_y: f32, eye_height: f32) -> Option<f32> {
         match block.collision_shape() {
             CollisionShape::None => None,
-            CollisionShape::FullBlock => Some(by as f32 + 1.0 + PLAYER_HEIGHT),
+            CollisionShape::FullBlock => Some(by as f32 + 1.0 + eye_height),
             CollisionShape::SlabBottom => {
                 // Bottom slab top surface at by + 0.5
                 if player_feet_y >= by as f32 && player_feet_y < by as f32 + 0.5 {
-                    Some(by as f32 + 0.5 + PLAYER_HEIGHT)
+                    Some(by as f32 + 0.5 + eye_height)
                 } else if player_feet_y < by as f32 {
-                    Some(by as f32 + 0.5 + PLAYER_HEIGHT)
+                    Some(by as f32 + 0.5 + eye_height)
                 } else {
                     None
                 }
             }
             CollisionShape::SlabTop => {
                 // Top slab top surface at by + 1.0
-                Some(by as f32 + 1.0 + PLAYER_HEIGHT)
+                Some(by as f32 + 1.0 + eye_height)
             }
             CollisionShape::Fence => {
                 // Fence acts as full block for ground
-                Some(by as f32 + 1.0 + PLAYER_HEIGHT)
+                Some(by as f32 + 1.0 + eye_height)
             }
         }
     }
@@ -263,11 +462,19 @@ impl Camera {
     }
 
     // Survival methods
-    pub fn take_damage(&mut self, amount: f32, knockback: Option<Vector3<f32>>) -> bool {
+    pub fn take_damage(&mut self, amount: f32, source: DamageSource, knockback: Option<Vector3<f32>>) -> bool {
         if self.is_dead || self.damage_cooldown > 0.0 {
             return false;
         }
 
+        // Armor soaks up part of the hit and wears down for it
+        let reduction = (self.armor_points as f32 * ARMOR_POINT_REDUCTION).min(MAX_ARMOR_REDUCTION)
+            * source.armor_effectiveness();
+        if reduction > 0.0 {
+            self.armor_hits.push(amount);
+        }
+        let amount = amount * (1.0 - reduction);
+
         self.health = (self.health - amount).max(0.0);
         self.damage_cooldown = 0.5; // 0.5 seconds invincibility
         self.damage_flash = 0.3;    // Visual feedback duration
@@ -287,6 +494,11 @@ impl Camera {
         true
     }
 
+    /// Hits absorbed by armor since the last call, for wearing down the worn pieces once per hit
+    pub fn take_armor_hits(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.armor_hits)
+    }
+
     pub fn heal(&mut self, amount: f32) {
         if self.is_dead {
             return;
@@ -309,6 +521,7 @@ impl Camera {
     }
 
     pub fn respawn(&mut self) {
+        self.stand_up();
         self.position = self.spawn_point;
         self.velocity = Vector3::new(0.0, 0.0, 0.0);
         self.health = self.max_health;
@@ -316,6 +529,7 @@ impl Camera {
         self.saturation = 5.0;
         self.air_supply = 10.0;
         self.is_dead = false;
+        self.death_time = 0.0;
         self.damage_cooldown = 0.0;
         self.damage_flash = 0.0;
         self.fall_distance = 0.0;
@@ -323,6 +537,8 @@ impl Camera {
         self.regen_timer = 0.0;
         self.on_ground = true;
         self.pending_knockback = None;
+        self.armor_hits.clear();
+        self.effects.clear();
         self.update_view_proj();
     }
 
@@ -331,9 +547,18 @@ impl Camera {
     }
 
     pub fn is_in_lava(&self, world: &World) -> bool {
+        self.is_touching_block(world, BlockType::Lava)
+    }
+
+    pub fn is_in_fire(&self, world: &World) -> bool {
+        self.is_touching_block(world, BlockType::Fire)
+    }
+
+    /// Whether any part of the player's body (feet, middle or eyes) is inside this block type
+    fn is_touching_block(&self, world: &World, target: BlockType) -> bool {
         let check_ys = [
-            self.position.y - PLAYER_HEIGHT,
-            self.position.y - PLAYER_HEIGHT / 2.0,
+            self.position.y - self.eye_height(),
+            self.position.y - self.eye_height() / 2.0,
             self.position.y,
         ];
         let check_offsets = [-0.3, 0.0, 0.3];
@@ -345,7 +570,7 @@ impl Camera {
                     let check_x = (self.position.x + dx).floor() as i32;
                     let check_z = (self.position.z + dz).floor() as i32;
                     if let Some(block) = world.get_block(check_x, block_y, check_z) {
-                        if block == BlockType::Lava {
+                        if block == target {
                             return true;
                         }
                     }
@@ -357,6 +582,7 @@ impl Camera {
 
     pub fn update_survival(&mut self, dt: f32, world: &World) {
         if self.is_dead {
+            self.death_time += dt;
             return;
         }
 
@@ -377,17 +603,36 @@ impl Camera {
             self.velocity.z += kb.z;
         }
 
-        // Lava damage (4 damage per second)
+        // Lava damage (4 damage per second) and fire blocks set the player alight; water puts the flames out
         if self.is_in_lava(world) {
-            self.take_damage(4.0 * dt, None);
+            self.take_damage(4.0 * dt, DamageSource::Lava, None);
+            self.effects.apply(StatusEffectType::Fire, LAVA_BURN_TIME, 0);
+        } else if self.is_in_fire(world) {
+            self.effects.apply(StatusEffectType::Fire, FIRE_BURN_TIME, 0);
+        }
+        if self.was_in_water {
+            self.effects.remove(StatusEffectType::Fire);
+        }
+
+        // Status effects: poison and burning hurt, regeneration heals
+        let tick = self.effects.update(dt);
+        if tick.poison > 0.0 && self.health > 1.0 {
+            // Poison never takes the last half heart
+            self.take_damage(tick.poison.min(self.health - 1.0), DamageSource::Poison, None);
+        }
+        if tick.fire > 0.0 {
+            self.take_damage(tick.fire, DamageSource::Fire, None);
+        }
+        if tick.regeneration > 0.0 {
+            self.heal(tick.regeneration);
         }
 
-        // Drowning - update air supply
-        if self.is_underwater(world) {
+        // Drowning - update air supply (water breathing keeps it topped up)
+        if self.is_underwater(world) && !self.effects.has(StatusEffectType::WaterBreathing) {
             self.air_supply -= dt / 1.5; // Lose 1 bubble per 1.5 seconds
             if self.air_supply <= 0.0 {
                 self.air_supply = 0.0;
-                self.take_damage(2.0 * dt, None); // Drowning damage
+                self.take_damage(2.0 * dt, DamageSource::Drowning, None); // Drowning damage
             }
         } else {
             self.air_supply = 10.0; // Refill instantly when above water
@@ -409,7 +654,7 @@ impl Camera {
         if self.hunger <= 0.0 {
             self.starvation_timer += dt;
             if self.starvation_timer >= 4.0 {
-                self.take_damage(1.0, None);
+                self.take_damage(1.0, DamageSource::Starvation, None);
                 self.starvation_timer = 0.0;
             }
         } else {
@@ -443,6 +688,9 @@ impl Camera {
             HungerAction::Walk => 0.01,
             HungerAction::Jump => 0.05,
             HungerAction::Sprint => 0.03,
+            HungerAction::Sneak => 0.005,
+            HungerAction::Swim => 0.015,
+            HungerAction::Crawl => 0.01,
             HungerAction::Attack => 0.1,
         };
 
@@ -467,8 +715,8 @@ impl Camera {
     fn is_on_ladder(&self, world: &World) -> bool {
         // Check if player's body overlaps with a ladder block
         let check_positions = [
-            (self.position.x, self.position.y - PLAYER_HEIGHT * 0.5),  // Lower body
-            (self.position.x, self.position.y - PLAYER_HEIGHT * 0.25), // Mid body
+            (self.position.x, self.position.y - self.eye_height() * 0.5),  // Lower body
+            (self.position.x, self.position.y - self.eye_height() * 0.25), // Mid body
             (self.position.x, self.position.y),                        // Upper body
         ];
 
@@ -539,7 +787,7 @@ impl Camera {
         
         // Determine if in water
         let mut is_in_water = false;
-        let check_ys = [self.position.y - PLAYER_HEIGHT, self.position.y - PLAYER_HEIGHT / 2.0, self.position.y];
+        let check_ys = [self.position.y - self.eye_height(), self.position.y - self.eye_height() / 2.0, self.position.y];
         let check_offsets = [-0.3, 0.0, 0.3];
         
         'water_check: for &check_y in &check_ys {
@@ -558,10 +806,21 @@ impl Camera {
             }
         }
         
+        self.update_movement_state(world, is_in_water);
+        let swimming = self.movement_state == MovementState::Swimming;
+
         // Check if on ladder
         let is_on_ladder = self.is_on_ladder(world);
 
-        let move_speed = if is_in_water { 2.0 } else if is_on_ladder { 2.5 } else { 4.3 };
+        let move_speed = if swimming {
+            self.movement_state.speed()
+        } else if is_in_water {
+            2.0
+        } else if is_on_ladder {
+            2.5
+        } else {
+            self.movement_state.speed()
+        } * self.effects.speed_multiplier();
         let mut horizontal_velocity = Vector3::new(0.0, 0.0, 0.0);
         if move_dir.magnitude() > 0.0 {
             horizontal_velocity = move_dir.normalize() * move_speed;
@@ -591,14 +850,26 @@ impl Camera {
             // Clamp to terminal velocity
             self.velocity.y = self.velocity.y.clamp(-TERMINAL_VELOCITY, TERMINAL_VELOCITY);
 
-            if is_in_water {
+            if swimming {
+                // Swim where the view points, up and down included
+                let look = self.get_look_direction();
+                horizontal_velocity = Vector3::new(look.x, 0.0, look.z) * move_speed;
+                self.velocity.y = look.y * move_speed;
+            } else if is_in_water {
                 self.velocity.y += GRAVITY * 0.9 * dt;
                 self.velocity.y *= 0.95;
                 self.velocity.y += (self.bob_time * 3.0).sin() * 0.05;
+                // Sneaking dives
+                if self.sneak_pressed {
+                    self.velocity.y = self.velocity.y.min(-SWIM_SINK_SPEED);
+                }
             }
 
-            // Jump if on ground or in water
-            if self.jump_pressed && (self.on_ground || is_in_water) {
+            // Jump if on ground or swim up in water (crawling keeps too low to jump)
+            if self.jump_pressed && !swimming && self.movement_state != MovementState::Crawling && (self.on_ground || is_in_water) {
+                if self.on_ground && !is_in_water {
+                    self.deplete_hunger(HungerAction::Jump);
+                }
                 self.velocity.y = if is_in_water { 5.0 } else { JUMP_VELOCITY };
             }
         }
@@ -607,11 +878,13 @@ impl Camera {
         let mut new_position = self.position;
         
         // Check X movement collision
+        let eye_height = self.eye_height();
+        let body_levels = (eye_height / 0.6).ceil() as i32; // 3 levels standing, to catch fences
         let test_x = self.position.x + horizontal_velocity.x * dt;
         let mut can_move_x = true;
 
-        for dy in 0..3 { // Check collision for player height (3 levels to catch fences)
-            let check_y = (self.position.y - PLAYER_HEIGHT + dy as f32 * 0.6).floor() as i32;
+        for dy in 0..body_levels { // Check collision for player height
+            let check_y = (self.position.y - eye_height + dy as f32 * 0.6).floor() as i32;
             for dz in [-1, 0, 1] { // Check around player width
                 let check_z = (self.position.z + dz as f32 * PLAYER_WIDTH * 0.5).floor() as i32;
                 let check_x = if horizontal_velocity.x > 0.0 {
@@ -621,7 +894,7 @@ impl Camera {
                 };
 
                 if let Some(block) = world.get_block(check_x, check_y, check_z) {
-                    if block != BlockType::Water && Self::block_collides_horizontal(block, check_y, self.position.y) {
+                    if block != BlockType::Water && Self::block_collides_horizontal(block, check_y, self.position.y, eye_height) {
                         can_move_x = false;
                         break;
                     }
@@ -630,6 +903,12 @@ impl Camera {
             if !can_move_x { break; }
         }
 
+        // Sneaking won't walk off an edge
+        let guard_edges = self.movement_state == MovementState::Sneaking && self.on_ground && !is_in_water;
+        if can_move_x && guard_edges && !Self::has_ground_below(world, test_x, self.position.z, self.position.y - eye_height) {
+            can_move_x = false;
+        }
+
         if can_move_x {
             new_position.x = test_x;
         }
@@ -638,8 +917,8 @@ impl Camera {
         let test_z = self.position.z + horizontal_velocity.z * dt;
         let mut can_move_z = true;
 
-        for dy in 0..3 { // Check collision for player height (3 levels to catch fences)
-            let check_y = (self.position.y - PLAYER_HEIGHT + dy as f32 * 0.6).floor() as i32;
+        for dy in 0..body_levels { // Check collision for player height
+            let check_y = (self.position.y - eye_height + dy as f32 * 0.6).floor() as i32;
             for dx in [-1, 0, 1] { // Check around player width
                 let check_x = (new_position.x + dx as f32 * PLAYER_WIDTH * 0.5).floor() as i32;
                 let check_z = if horizontal_velocity.z > 0.0 {
@@ -649,7 +928,7 @@ impl Camera {
                 };
 
                 if let Some(block) = world.get_block(check_x, check_y, check_z) {
-                    if block != BlockType::Water && Self::block_collides_horizontal(block, check_y, self.position.y) {
+                    if block != BlockType::Water && Self::block_collides_horizontal(block, check_y, self.position.y, eye_height) {
                         can_move_z = false;
                         break;
                     }
@@ -658,6 +937,10 @@ impl Camera {
             if !can_move_z { break; }
         }
 
+        if can_move_z && guard_edges && !Self::has_ground_below(world, new_position.x, test_z, self.position.y - eye_height) {
+            can_move_z = false;
+        }
+
         if can_move_z {
             new_position.z = test_z;
         }
@@ -667,7 +950,7 @@ impl Camera {
 
         // Check ground collision
         self.on_ground = false;
-        let feet_y = new_position.y - PLAYER_HEIGHT;
+        let feet_y = new_position.y - eye_height;
         let check_block_y = feet_y.floor() as i32;
 
         for dx in [-1, 0, 1] {
@@ -677,7 +960,7 @@ impl Camera {
 
                 if let Some(block) = world.get_block(check_x, check_block_y, check_z) {
                     if block != BlockType::Water {
-                        if let Some(new_y) = Self::block_collides_ground(block, check_block_y, feet_y) {
+                        if let Some(new_y) = Self::block_collides_ground(block, check_block_y, feet_y, eye_height) {
                             if self.velocity.y <= 0.0 {
                                 new_position.y = new_y;
                                 self.velocity.y = 0.0;
@@ -785,7 +1068,7 @@ impl Camera {
             // Apply fall damage if fell more than 5 blocks (reduced damage)
             if self.fall_distance > 5.0 {
                 let damage = ((self.fall_distance - 5.0) * 0.5).floor().max(1.0);
-                self.take_damage(damage, None);
+                self.take_damage(damage, DamageSource::Fall, None);
             }
             self.fall_distance = 0.0;
         }
@@ -800,7 +1083,7 @@ impl Camera {
                 self.distance_walked += distance_moved;
 
                 // Get the block type under feet for footstep sound
-                let feet_block_y = (new_position.y - PLAYER_HEIGHT - 0.1).floor() as i32;
+                let feet_block_y = (new_position.y - eye_height - 0.1).floor() as i32;
                 self.last_ground_block = world.get_block(
                     new_position.x.floor() as i32,
                     feet_block_y,
@@ -815,9 +1098,96 @@ impl Camera {
             }
         }
 
+        // Charge hunger for each block travelled, at the rate of the current movement
+        if self.on_ground || is_in_water {
+            self.travel_distance += ((new_position.x - self.position.x).powi(2)
+                + (new_position.z - self.position.z).powi(2)).sqrt();
+            while self.travel_distance >= 1.0 {
+                self.travel_distance -= 1.0;
+                self.deplete_hunger(self.movement_state.hunger_action());
+            }
+        }
+
         self.position = new_position;
     }
 
+    /// Eyes above the feet for the current movement state
+    fn eye_height(&self) -> f32 {
+        self.movement_state.eye_height()
+    }
+
+    /// Pick the movement state from the held keys and surroundings. Getting taller needs room
+    /// overhead, so leaving a tunnel or the water under a low ceiling keeps the player crawling.
+    fn update_movement_state(&mut self, world: &World, is_in_water: bool) {
+        let submerged = is_in_water && self.is_underwater(world);
+        let wanted = if self.sprint_pressed && self.moving_forward && (submerged
+            || (self.movement_state == MovementState::Swimming && is_in_water)) {
+            MovementState::Swimming
+        } else if self.crawl_toggled && !is_in_water {
+            MovementState::Crawling
+        } else if self.sneak_pressed {
+            MovementState::Sneaking
+        } else if self.sprint_pressed && self.moving_forward && !is_in_water && self.can_sprint() {
+            MovementState::Sprinting
+        } else {
+            MovementState::Walking
+        };
+
+        let current_height = self.eye_height();
+        let fits = |state: &MovementState| {
+            state.eye_height() <= current_height || self.has_headroom(world, state.eye_height())
+        };
+        let state = [wanted, MovementState::Sneaking, MovementState::Crawling]
+            .into_iter()
+            .find(fits)
+            .unwrap_or(MovementState::Crawling);
+
+        if state != self.movement_state {
+            // Keep the feet where they are and move the eyes
+            self.position.y += state.eye_height() - current_height;
+            self.movement_state = state;
+        }
+    }
+
+    /// Whether the body has room to grow to `eye_height` without its head entering a block
+    fn has_headroom(&self, world: &World, eye_height: f32) -> bool {
+        // Only the space above the current head is new
+        let from_y = (self.position.y + 0.1).floor() as i32;
+        let to_y = (self.position.y - self.eye_height() + eye_height + 0.1).floor() as i32;
+        for by in from_y..=to_y {
+            for dx in [-1, 0, 1] {
+                for dz in [-1, 0, 1] {
+                    let check_x = (self.position.x + dx as f32 * PLAYER_WIDTH * 0.3).floor() as i32;
+                    let check_z = (self.position.z + dz as f32 * PLAYER_WIDTH * 0.3).floor() as i32;
+                    let blocked = world.get_block(check_x, by, check_z)
+                        .is_some_and(|b| b != BlockType::Water && b.collision_shape() != CollisionShape::None);
+                    if blocked {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether anything under the player's footprint would hold them up at (x, z)
+    fn has_ground_below(world: &World, x: f32, z: f32, feet_y: f32) -> bool {
+        let below_y = (feet_y - 0.05).floor() as i32;
+        // Same footprint the ground collision stands on
+        for dx in [-1.0, 1.0] {
+            for dz in [-1.0, 1.0] {
+                let check_x = (x + dx * PLAYER_WIDTH * 0.3).floor() as i32;
+                let check_z = (z + dz * PLAYER_WIDTH * 0.3).floor() as i32;
+                let supported = world.get_block(check_x, below_y, check_z)
+                    .is_some_and(|b| b != BlockType::Water && b.collision_shape() != CollisionShape::None);
+                if supported {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
     pub fn get_look_direction(&self) -> Vector3<f32> {
         let yaw_rad = self.yaw.to_radians();
         let pitch_rad = self.pitch.to_radians();
@@ -1060,8 +1430,23 @@ impl Camera {
     }
 
     fn update_view_proj(&mut self) {
-        let yaw_rad = self.yaw.to_radians();
-        let pitch_rad = self.pitch.to_radians();
+        // Place the view for the current camera mode; front view looks back at the player
+        let (eye, yaw, pitch) = match self.active_mode() {
+            CameraMode::FirstPerson => (self.position, self.yaw, self.pitch),
+            CameraMode::ThirdPersonBack => {
+                (self.position - self.get_look_direction() * self.third_person_distance, self.yaw, self.pitch)
+            }
+            CameraMode::ThirdPersonFront => {
+                (self.position + self.get_look_direction() * self.third_person_distance, self.yaw + 180.0, -self.pitch)
+            }
+            CameraMode::Free => (self.free_position, self.free_yaw, self.free_pitch),
+        };
+        self.view_position = eye;
+        self.view_yaw = yaw;
+        self.view_pitch = pitch;
+
+        let yaw_rad = yaw.to_radians();
+        let pitch_rad = pitch.to_radians();
         
         let front = Vector3::new(
             yaw_rad.cos() * pitch_rad.cos(),
@@ -1070,8 +1455,8 @@ impl Camera {
         );
         
         let view = Matrix4::look_at_rh(
-            self.position,
-            self.position + front,
+            eye,
+            eye + front,
             Vector3::unit_y(),
         );
         
@@ -1085,6 +1470,144 @@ impl Camera {
         self.view_proj = OPENGL_TO_WGPU_MATRIX * proj * view;
     }
 
+    // ========================================================================
+    // Camera modes
+    // ========================================================================
+
+    /// Where the scene is rendered from (the player's eyes only in first person)
+    pub fn view_position(&self) -> Point3<f32> {
+        self.view_position
+    }
+
+    /// Yaw and pitch of the rendered view in degrees
+    pub fn view_angles(&self) -> (f32, f32) {
+        (self.view_yaw, self.view_pitch)
+    }
+
+    /// Bottom of the player's body: the feet when standing, the seat when riding
+    pub fn feet_position(&self) -> Point3<f32> {
+        let height = if self.is_riding() { RIDING_EYE_HEIGHT } else { self.eye_height() };
+        Point3::new(self.position.x, self.position.y - height, self.position.z)
+    }
+
+    pub fn horizontal_speed(&self) -> f32 {
+        (self.velocity.x * self.velocity.x + self.velocity.z * self.velocity.z).sqrt()
+    }
+
+    /// Running clock for body animations
+    pub fn animation_time(&self) -> f32 {
+        self.bob_time
+    }
+
+    /// Mode actually used for the view: dying in first person steps back so the body can be seen
+    fn active_mode(&self) -> CameraMode {
+        if self.is_dead && self.camera_mode == CameraMode::FirstPerson {
+            CameraMode::ThirdPersonBack
+        } else {
+            self.camera_mode
+        }
+    }
+
+    /// 0.0 while alive, rising to 1.0 as the body topples over after death
+    pub fn death_progress(&self) -> f32 {
+        if self.is_dead {
+            (self.death_time / DEATH_FALL_TIME).min(1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether the player's own body should be drawn
+    pub fn shows_player_body(&self) -> bool {
+        self.active_mode() != CameraMode::FirstPerson && !self.is_piloting()
+    }
+
+    /// F5: first person -> behind the player -> in front of the player
+    pub fn cycle_camera_mode(&mut self) {
+        self.camera_mode = self.camera_mode.cycled();
+        self.attached_mode = self.camera_mode;
+        self.third_person_distance = THIRD_PERSON_DISTANCE;
+        self.update_view_proj();
+    }
+
+    /// F6: detach a free-flying camera from the current view, or snap back to the player
+    pub fn toggle_free_camera(&mut self) {
+        if self.camera_mode == CameraMode::Free {
+            self.camera_mode = self.attached_mode;
+        } else {
+            self.free_position = self.view_position;
+            self.free_yaw = self.view_yaw;
+            self.free_pitch = self.view_pitch;
+            self.camera_mode = CameraMode::Free;
+            // Leave the player standing still while the camera flies around
+            self.moving_forward = false;
+            self.moving_backward = false;
+            self.moving_left = false;
+            self.moving_right = false;
+            self.jump_pressed = false;
+            self.sprint_pressed = false;
+            self.sneak_pressed = false;
+        }
+        self.free_forward = false;
+        self.free_backward = false;
+        self.free_left = false;
+        self.free_right = false;
+        self.free_up = false;
+        self.free_down = false;
+        self.update_view_proj();
+    }
+
+    /// Fly the free camera, or pull the third-person view in front of any blocks between it and the player
+    pub fn update_camera_mode(&mut self, dt: f32, world: &World) {
+        // Planes keep their own chase view unless the camera is detached
+        if self.is_piloting() && self.camera_mode != CameraMode::Free {
+            return;
+        }
+        let mode = self.active_mode();
+        match mode {
+            CameraMode::FirstPerson => return,
+            CameraMode::Free => {
+                let yaw_rad = self.free_yaw.to_radians();
+                let forward = Vector3::new(yaw_rad.cos(), 0.0, yaw_rad.sin());
+                let right = Vector3::new(-yaw_rad.sin(), 0.0, yaw_rad.cos());
+                let axis = |positive: bool, negative: bool| positive as i32 as f32 - negative as i32 as f32;
+                let movement = forward * axis(self.free_forward, self.free_backward)
+                    + right * axis(self.free_right, self.free_left)
+                    + Vector3::unit_y() * axis(self.free_up, self.free_down);
+                if movement.magnitude2() > 0.0 {
+                    self.free_position += movement.normalize() * FREE_CAMERA_SPEED * dt;
+                }
+            }
+            CameraMode::ThirdPersonBack | CameraMode::ThirdPersonFront => {
+                let direction = if mode == CameraMode::ThirdPersonBack {
+                    -self.get_look_direction()
+                } else {
+                    self.get_look_direction()
+                };
+                // March out from the head until something solid is hit
+                let mut clear = THIRD_PERSON_DISTANCE;
+                let mut t = 0.0;
+                while t < THIRD_PERSON_DISTANCE + CAMERA_WALL_MARGIN {
+                    let point = self.position + direction * t;
+                    let blocked = world.get_block(point.x.floor() as i32, point.y.floor() as i32, point.z.floor() as i32)
+                        .is_some_and(|b| b.collision_shape() != CollisionShape::None);
+                    if blocked {
+                        clear = (t - CAMERA_WALL_MARGIN).max(THIRD_PERSON_MIN_DISTANCE);
+                        break;
+                    }
+                    t += 0.05;
+                }
+                // Snap in at once so walls never cover the view, ease back out when clear
+                self.third_person_distance = if clear < self.third_person_distance {
+                    clear
+                } else {
+                    (self.third_person_distance + CAMERA_PULL_OUT_RATE * dt).min(clear)
+                };
+            }
+        }
+        self.update_view_proj();
+    }
+
     // ========================================================================
     // Plane/Vehicle methods
     // ========================================================================
@@ -1108,6 +1631,7 @@ impl Camera {
             self.pitch_down = false;
             self.moving_left = false;
             self.moving_right = false;
+            self.stand_up();
             return true;
         }
         false
@@ -1138,6 +1662,83 @@ impl Camera {
         }
     }
 
+    /// Riding a boat or minecart
+    pub fn is_riding(&self) -> bool {
+        matches!(self.vehicle_state, VehicleState::InBoat | VehicleState::InMinecart)
+    }
+
+    /// Controls for the ridden boat or minecart this frame
+    pub fn vehicle_controls(&self) -> crate::entity::VehicleControls {
+        crate::entity::VehicleControls {
+            forward: self.moving_forward,
+            backward: self.moving_backward,
+            turn_left: self.moving_left,
+            turn_right: self.moving_right,
+            look: self.get_look_direction(),
+        }
+    }
+
+    pub fn try_enter_vehicle(&mut self, nearby_vehicle: Option<(u32, VehicleKind)>) -> bool {
+        if self.vehicle_state != VehicleState::OnFoot {
+            return false;
+        }
+
+        if let Some((vehicle_id, kind)) = nearby_vehicle {
+            self.vehicle_state = match kind {
+                VehicleKind::Boat => VehicleState::InBoat,
+                VehicleKind::Minecart => VehicleState::InMinecart,
+            };
+            self.ridden_vehicle_id = Some(vehicle_id);
+            self.riding_yaw = None;
+            self.moving_forward = false;
+            self.moving_backward = false;
+            self.moving_left = false;
+            self.moving_right = false;
+            self.jump_pressed = false;
+            self.stand_up();
+            return true;
+        }
+        false
+    }
+
+    /// Back to walking upright with no movement modifiers held; vehicles are left standing
+    fn stand_up(&mut self) {
+        self.movement_state = MovementState::Walking;
+        self.sprint_pressed = false;
+        self.sneak_pressed = false;
+        self.crawl_toggled = false;
+        self.travel_distance = 0.0;
+    }
+
+    /// Climb out of a boat or minecart, standing at `feet_pos`
+    pub fn exit_vehicle(&mut self, feet_pos: Point3<f32>) {
+        if self.is_riding() {
+            self.vehicle_state = VehicleState::OnFoot;
+            self.ridden_vehicle_id = None;
+            self.riding_yaw = None;
+            self.position = Point3::new(feet_pos.x, feet_pos.y + PLAYER_HEIGHT, feet_pos.z);
+            self.velocity = Vector3::new(0.0, 0.0, 0.0);
+            self.fall_distance = 0.0;
+            self.moving_forward = false;
+            self.moving_backward = false;
+            self.moving_left = false;
+            self.moving_right = false;
+        }
+    }
+
+    /// Sit the player in a boat or minecart seat; the view turns with the vehicle
+    pub fn update_riding_view(&mut self, seat: Point3<f32>, vehicle_yaw: f32) {
+        if let Some(last_yaw) = self.riding_yaw {
+            // Vehicle yaw is measured the other way round from the camera's
+            self.yaw -= vehicle_yaw - last_yaw;
+        }
+        self.riding_yaw = Some(vehicle_yaw);
+        self.position = Point3::new(seat.x, seat.y + RIDING_EYE_HEIGHT, seat.z);
+        self.velocity = Vector3::new(0.0, 0.0, 0.0);
+        self.fall_distance = 0.0;
+        self.update_view_proj();
+    }
+
     /// Update camera for third-person flight view
     pub fn update_flight_view(&mut self, plane_yaw: f32, plane_pitch: f32, plane_roll: f32, plane_pos: Point3<f32>) {
         let plane_yaw_rad = plane_yaw.to_radians();
@@ -1190,6 +1791,9 @@ impl Camera {
         );
         self.view_proj = OPENGL_TO_WGPU_MATRIX * proj * view;
         self.position = cam_pos; // Update position for audio/other systems
+        self.view_position = cam_pos;
+        self.view_yaw = -90.0 - plane_yaw;
+        self.view_pitch = 0.0;
     }
 }
 
@@ -0,0 +1,247 @@
+const POISON_INTERVAL: f32 = 1.25;        // Seconds between poison hits at level I
+const REGENERATION_INTERVAL: f32 = 2.5;   // Seconds between heals at level I
+const FIRE_INTERVAL: f32 = 1.0;           // Seconds between burns
+const SPEED_PER_LEVEL: f32 = 0.2;         // +20% movement per level of speed
+const SLOWNESS_PER_LEVEL: f32 = 0.15;     // -15% movement per level of slowness
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusEffectType {
+    Poison,
+    Regeneration,
+    Speed,
+    Slowness,
+    NightVision,
+    WaterBreathing,
+    Fire,
+}
+
+impl StatusEffectType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusEffectType::Poison => "Poison",
+            StatusEffectType::Regeneration => "Regeneration",
+            StatusEffectType::Speed => "Speed",
+            StatusEffectType::Slowness => "Slowness",
+            StatusEffectType::NightVision => "Night Vision",
+            StatusEffectType::WaterBreathing => "Water Breathing",
+            StatusEffectType::Fire => "Burning",
+        }
+    }
+
+    /// HUD icon colour
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            StatusEffectType::Poison => [0.3, 0.6, 0.15, 1.0],
+            StatusEffectType::Regeneration => [0.85, 0.35, 0.6, 1.0],
+            StatusEffectType::Speed => [0.5, 0.75, 0.95, 1.0],
+            StatusEffectType::Slowness => [0.35, 0.4, 0.5, 1.0],
+            StatusEffectType::NightVision => [0.2, 0.25, 0.7, 1.0],
+            StatusEffectType::WaterBreathing => [0.2, 0.55, 0.75, 1.0],
+            StatusEffectType::Fire => [1.0, 0.5, 0.1, 1.0],
+        }
+    }
+
+    /// Seconds between damage or healing ticks, shorter at higher levels (None for effects
+    /// that only change how the target moves or sees)
+    fn tick_interval(&self, amplifier: u8) -> Option<f32> {
+        let level = amplifier as f32 + 1.0;
+        match self {
+            StatusEffectType::Poison => Some(POISON_INTERVAL / level),
+            StatusEffectType::Regeneration => Some(REGENERATION_INTERVAL / level),
+            StatusEffectType::Fire => Some(FIRE_INTERVAL),
+            _ => None,
+        }
+    }
+}
+
+/// An effect to start on a player or mob: (effect, seconds, amplifier)
+pub type TimedEffect = (StatusEffectType, f32, u8);
+
+/// One running effect: `amplifier` 0 is level I
+#[derive(Clone, Copy, Debug)]
+pub struct StatusEffect {
+    pub effect_type: StatusEffectType,
+    pub amplifier: u8,
+    pub remaining: f32,
+    tick_timer: f32,
+}
+
+impl StatusEffect {
+    /// Name with its level in roman numerals above I, e.g. "Poison II"
+    pub fn display_name(&self) -> String {
+        const LEVELS: [&str; 5] = ["", " II", " III", " IV", " V"];
+        let level = LEVELS.get(self.amplifier as usize).copied().unwrap_or(" V");
+        format!("{}{}", self.effect_type.name(), level)
+    }
+}
+
+/// Damage and healing that came due during one update
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EffectTick {
+    pub poison: f32,
+    pub fire: f32,
+    pub regeneration: f32,
+}
+
+/// Timed effects on the player or a mob
+#[derive(Clone, Debug, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start an effect; one already running keeps the higher level and the longer time left
+    pub fn apply(&mut self, effect_type: StatusEffectType, duration: f32, amplifier: u8) {
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.effect_type == effect_type) {
+            if amplifier > effect.amplifier {
+                effect.amplifier = amplifier;
+                effect.remaining = duration;
+            } else if amplifier == effect.amplifier {
+                effect.remaining = effect.remaining.max(duration);
+            }
+        } else {
+            self.effects.push(StatusEffect { effect_type, amplifier, remaining: duration, tick_timer: 0.0 });
+        }
+    }
+
+    pub fn apply_timed(&mut self, (effect_type, duration, amplifier): TimedEffect) {
+        self.apply(effect_type, duration, amplifier);
+    }
+
+    pub fn remove(&mut self, effect_type: StatusEffectType) {
+        self.effects.retain(|e| e.effect_type != effect_type);
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    pub fn has(&self, effect_type: StatusEffectType) -> bool {
+        self.get(effect_type).is_some()
+    }
+
+    pub fn get(&self, effect_type: StatusEffectType) -> Option<&StatusEffect> {
+        self.effects.iter().find(|e| e.effect_type == effect_type)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Count down every effect, dropping expired ones, and collect the damage and healing due
+    pub fn update(&mut self, dt: f32) -> EffectTick {
+        let mut tick = EffectTick::default();
+        for effect in self.effects.iter_mut() {
+            effect.remaining -= dt;
+            let Some(interval) = effect.effect_type.tick_interval(effect.amplifier) else {
+                continue;
+            };
+            effect.tick_timer += dt;
+            while effect.tick_timer >= interval {
+                effect.tick_timer -= interval;
+                match effect.effect_type {
+                    StatusEffectType::Poison => tick.poison += 1.0,
+                    StatusEffectType::Fire => tick.fire += 1.0,
+                    StatusEffectType::Regeneration => tick.regeneration += 1.0,
+                    _ => {}
+                }
+            }
+        }
+        self.effects.retain(|e| e.remaining > 0.0);
+        tick
+    }
+
+    /// Movement speed multiplier from speed and slowness
+    pub fn speed_multiplier(&self) -> f32 {
+        let level = |effect_type| self.get(effect_type).map_or(0.0, |e| e.amplifier as f32 + 1.0);
+        (1.0 + level(StatusEffectType::Speed) * SPEED_PER_LEVEL - level(StatusEffectType::Slowness) * SLOWNESS_PER_LEVEL)
+            .max(0.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `seconds` of updates in quarter-second steps, adding up what came due
+    fn run(effects: &mut StatusEffects, seconds: f32) -> EffectTick {
+        let mut total = EffectTick::default();
+        for _ in 0..(seconds / 0.25) as u32 {
+            let tick = effects.update(0.25);
+            total.poison += tick.poison;
+            total.fire += tick.fire;
+            total.regeneration += tick.regeneration;
+        }
+        total
+    }
+
+    #[test]
+    fn reapplying_keeps_the_longer_time_and_higher_level() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectType::Poison, 5.0, 0);
+        effects.apply(StatusEffectType::Poison, 3.0, 0);
+        assert_eq!(effects.get(StatusEffectType::Poison).unwrap().remaining, 5.0, "a shorter dose doesn't cut the timer");
+        effects.apply(StatusEffectType::Poison, 8.0, 0);
+        assert_eq!(effects.get(StatusEffectType::Poison).unwrap().remaining, 8.0);
+
+        effects.apply(StatusEffectType::Poison, 2.0, 1);
+        let poison = effects.get(StatusEffectType::Poison).unwrap();
+        assert_eq!((poison.amplifier, poison.remaining), (1, 2.0), "a higher level takes over with its own time");
+        effects.apply(StatusEffectType::Poison, 30.0, 0);
+        let poison = effects.get(StatusEffectType::Poison).unwrap();
+        assert_eq!((poison.amplifier, poison.remaining), (1, 2.0), "a lower level is ignored");
+        assert_eq!(effects.iter().count(), 1);
+    }
+
+    #[test]
+    fn effects_tick_at_their_interval_until_they_expire() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectType::Poison, 5.0, 0);
+        effects.apply(StatusEffectType::Regeneration, 5.0, 0);
+        effects.apply(StatusEffectType::Fire, 5.0, 0);
+        effects.apply(StatusEffectType::NightVision, 5.0, 0);
+        let tick = run(&mut effects, 5.0);
+        assert_eq!(tick.poison, 5.0 / POISON_INTERVAL);
+        assert_eq!(tick.regeneration, 5.0 / REGENERATION_INTERVAL);
+        assert_eq!(tick.fire, 5.0 / FIRE_INTERVAL);
+        assert!(effects.is_empty(), "every effect should have run out");
+
+        let tick = run(&mut effects, 5.0);
+        assert_eq!((tick.poison, tick.regeneration, tick.fire), (0.0, 0.0, 0.0), "expired effects stop ticking");
+    }
+
+    #[test]
+    fn higher_levels_tick_faster() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectType::Poison, 5.0, 1);
+        effects.apply(StatusEffectType::Regeneration, 5.0, 1);
+        effects.apply(StatusEffectType::Fire, 5.0, 1);
+        let tick = run(&mut effects, 5.0);
+        assert_eq!(tick.poison, 2.0 * 5.0 / POISON_INTERVAL);
+        assert_eq!(tick.regeneration, 2.0 * 5.0 / REGENERATION_INTERVAL);
+        assert_eq!(tick.fire, 5.0 / FIRE_INTERVAL, "burning doesn't scale with level");
+    }
+
+    #[test]
+    fn speed_and_slowness_scale_movement() {
+        let mut effects = StatusEffects::new();
+        assert_eq!(effects.speed_multiplier(), 1.0);
+
+        effects.apply(StatusEffectType::Speed, 10.0, 1);
+        assert!((effects.speed_multiplier() - (1.0 + 2.0 * SPEED_PER_LEVEL)).abs() < 1e-6);
+        effects.apply(StatusEffectType::Slowness, 10.0, 0);
+        assert!((effects.speed_multiplier() - (1.0 + 2.0 * SPEED_PER_LEVEL - SLOWNESS_PER_LEVEL)).abs() < 1e-6);
+
+        effects.remove(StatusEffectType::Speed);
+        effects.apply(StatusEffectType::Slowness, 10.0, 9);
+        assert_eq!(effects.speed_multiplier(), 0.1, "slowness never stops movement entirely");
+    }
+}
@@ -1,20 +1,164 @@
 use cgmath::{Point3, Vector3, InnerSpace};
 use rand::Rng;
 
-use crate::world::{World, BlockType, ItemStack, Tool};
+use crate::effect::{StatusEffectType, StatusEffects, TimedEffect};
+use crate::world::{World, BlockType, BlockFacing, CollisionShape, ItemStack, RailShape, Tool, ToolType, ToolMaterial, VillageSites};
 
 // Villager constants
 pub const VILLAGER_HEIGHT: f32 = 1.8;
 pub const VILLAGER_WIDTH: f32 = 0.6;
 pub const VILLAGER_SPEED: f32 = 1.5;
+const VILLAGER_FLEE_SPEED: f32 = 3.5;
+const VILLAGER_MAX_HEALTH: f32 = 20.0;
+const VILLAGER_FLEE_RANGE: f32 = 8.0;       // Villagers run from hostile mobs this close
+const MEETING_RADIUS: f32 = 4.0;            // Close enough to the well or church to count as gathered
+const HOME_ARRIVE_DISTANCE: f32 = 0.8;      // Close enough to a door-side waypoint to move on
 const GRAVITY: f32 = 32.0;
 const TERMINAL_VELOCITY: f32 = 50.0;
 
+// Trading constants
+pub const TRADE_RANGE: f32 = 3.0;           // How close the player must be to start trading
+const WORKSTATION_REACH: f32 = 2.0;         // Villagers restock when this close to their workstation
+const RESTOCK_COOLDOWN: f32 = 120.0;        // Seconds between restocks
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum VillagerState {
     Idle,
     Walking,
     LookingAround,
+    Fleeing,
+}
+
+/// What the village schedule has villagers doing at a given time of day
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VillagerActivity {
+    Wander,   // Morning and afternoon: roam, work and restock
+    Gather,   // Midday: meet at the well or church
+    GoHome,   // Dusk until dawn: indoors with the door shut
+}
+
+impl VillagerActivity {
+    pub fn at_time(time_of_day: f32) -> Self {
+        if !(0.27..0.7).contains(&time_of_day) {
+            VillagerActivity::GoHome
+        } else if (0.45..0.58).contains(&time_of_day) {
+            VillagerActivity::Gather
+        } else {
+            VillagerActivity::Wander
+        }
+    }
+}
+
+/// A villager's house door and the waypoints either side of it
+#[derive(Clone, Copy, Debug)]
+pub struct VillagerHome {
+    pub door: (i32, i32, i32),
+    outside: Point3<f32>,
+    inside: Point3<f32>,
+}
+
+impl VillagerHome {
+    /// Work out which side of a door is indoors (roofed over) and which is the street
+    pub fn from_door(world: &World, door: (i32, i32, i32)) -> Self {
+        let (x, y, z) = door;
+        let center = Point3::new(x as f32 + 0.5, y as f32, z as f32 + 0.5);
+        let (dx, dz) = [(0, -1), (0, 1), (-1, 0), (1, 0)].into_iter()
+            .find(|&(dx, dz)| world.is_sheltered(x + dx, y, z + dz) && !world.is_sheltered(x - dx, y, z - dz))
+            .unwrap_or((0, -1)); // Generated houses open towards +Z
+        let step = Vector3::new(dx as f32, 0.0, dz as f32);
+        Self {
+            door,
+            inside: center + step * 1.5,
+            outside: center - step * 1.5,
+        }
+    }
+}
+
+/// A villager's job, taken from the workstation in the village building they live by
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VillagerProfession {
+    Unemployed,
+    Farmer,     // Composter on the village farm
+    Blacksmith, // Anvil in the blacksmith
+    Cleric,     // Lectern in the church
+}
+
+impl VillagerProfession {
+    /// The profession a workstation block gives to the villager who claims it
+    pub fn from_workstation(block: BlockType) -> Option<Self> {
+        match block {
+            BlockType::Composter => Some(VillagerProfession::Farmer),
+            BlockType::Anvil => Some(VillagerProfession::Blacksmith),
+            BlockType::Lectern => Some(VillagerProfession::Cleric),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            VillagerProfession::Unemployed => "Villager",
+            VillagerProfession::Farmer => "Farmer",
+            VillagerProfession::Blacksmith => "Blacksmith",
+            VillagerProfession::Cleric => "Cleric",
+        }
+    }
+
+    /// Robe colour (block types 18-23); the unemployed keep a random one
+    fn robe_color(&self) -> Option<f32> {
+        match self {
+            VillagerProfession::Unemployed => None,
+            VillagerProfession::Farmer => Some(18.0),     // Brown
+            VillagerProfession::Blacksmith => Some(21.0), // Blue
+            VillagerProfession::Cleric => Some(22.0),     // Purple
+        }
+    }
+
+    /// Trade offer table: goods bought for emeralds, and what emeralds buy
+    pub fn trade_offers(&self) -> Vec<TradeOffer> {
+        match self {
+            VillagerProfession::Unemployed => Vec::new(),
+            VillagerProfession::Farmer => vec![
+                TradeOffer::new((BlockType::Wheat, 20), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::Carrot, 22), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::Potato, 26), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::Emerald, 1), ItemStack::Block(BlockType::Bread, 6), 16),
+                TradeOffer::new((BlockType::Emerald, 1), ItemStack::Block(BlockType::Apple, 4), 16),
+            ],
+            VillagerProfession::Blacksmith => vec![
+                TradeOffer::new((BlockType::Coal, 15), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::IronIngot, 4), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::Emerald, 3), ItemStack::Tool(Tool::new(ToolType::Pickaxe, ToolMaterial::Iron)), 3),
+                TradeOffer::new((BlockType::Emerald, 4), ItemStack::Tool(Tool::new(ToolType::Sword, ToolMaterial::Iron)), 3),
+                TradeOffer::new((BlockType::Emerald, 12), ItemStack::Tool(Tool::new(ToolType::Pickaxe, ToolMaterial::Diamond)), 1),
+            ],
+            VillagerProfession::Cleric => vec![
+                TradeOffer::new((BlockType::GoldIngot, 3), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::RawBeef, 8), ItemStack::Block(BlockType::Emerald, 1), 12),
+                TradeOffer::new((BlockType::Emerald, 1), ItemStack::Block(BlockType::RedstoneWire, 4), 12),
+                TradeOffer::new((BlockType::Emerald, 1), ItemStack::Block(BlockType::Glass, 4), 12),
+                TradeOffer::new((BlockType::Emerald, 5), ItemStack::Block(BlockType::Diamond, 1), 3),
+            ],
+        }
+    }
+}
+
+/// One line of a villager's shop: pay `cost`, receive `result`, until it sells out
+#[derive(Clone, Debug)]
+pub struct TradeOffer {
+    pub cost: (BlockType, u32),
+    pub result: ItemStack,
+    pub uses: u32,
+    pub max_uses: u32,
+}
+
+impl TradeOffer {
+    fn new(cost: (BlockType, u32), result: ItemStack, max_uses: u32) -> Self {
+        Self { cost, result, uses: 0, max_uses }
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.uses >= self.max_uses
+    }
 }
 
 pub struct Villager {
@@ -27,6 +171,16 @@ pub struct Villager {
     pub home_chunk: (i32, i32),
     pub on_ground: bool,
     pub robe_color: f32,             // Block type for robe (18-23)
+    pub profession: VillagerProfession,
+    pub workstation: Option<(i32, i32, i32)>,
+    pub trades: Vec<TradeOffer>,
+    restock_cooldown: f32,
+    pub health: f32,
+    pub home: Option<VillagerHome>,
+    pub meeting_point: Option<(i32, i32, i32)>,
+    pub sheltered: bool,             // Indoors for the night
+    leaving_home: bool,              // Heading out through the door in the morning
+    detour_timer: f32,               // Sidestepping an obstacle before re-aiming at a target
 
     // State timers
     state_timer: f32,
@@ -51,6 +205,16 @@ impl Villager {
             home_chunk,
             on_ground: false,
             robe_color: ROBE_COLORS[rng.gen_range(0..ROBE_COLORS.len())],
+            profession: VillagerProfession::Unemployed,
+            workstation: None,
+            trades: Vec::new(),
+            restock_cooldown: 0.0,
+            health: VILLAGER_MAX_HEALTH,
+            home: None,
+            meeting_point: None,
+            sheltered: false,
+            leaving_home: false,
+            detour_timer: 0.0,
             state_timer: 0.0,
             idle_timer: 0.5,  // Start moving quickly
             walk_timer: 0.0,
@@ -58,8 +222,41 @@ impl Villager {
         }
     }
 
+    /// Claim a workstation, taking its profession, robe and trade table
+    pub fn assign_workstation(&mut self, pos: (i32, i32, i32), profession: VillagerProfession) {
+        self.workstation = Some(pos);
+        self.profession = profession;
+        self.trades = profession.trade_offers();
+        if let Some(robe) = profession.robe_color() {
+            self.robe_color = robe;
+        }
+    }
+
+    /// Count a completed trade against an offer's stock
+    pub fn record_trade(&mut self, offer_index: usize) {
+        if let Some(offer) = self.trades.get_mut(offer_index) {
+            offer.uses += 1;
+        }
+    }
+
+    fn needs_restock(&self) -> bool {
+        self.restock_cooldown <= 0.0 && self.trades.iter().any(|offer| offer.uses > 0)
+    }
+
+    /// Take damage and a knockback shove; returns false once the villager dies
+    pub fn take_damage(&mut self, damage: f32, knockback: Vector3<f32>) -> bool {
+        self.health -= damage;
+        self.velocity += knockback;
+        self.health > 0.0
+    }
+
+    fn workstation_center(&self) -> Option<Point3<f32>> {
+        self.workstation.map(|(x, y, z)| Point3::new(x as f32 + 0.5, y as f32, z as f32 + 0.5))
+    }
+
     pub fn update(&mut self, dt: f32, world: &World) {
         self.animation_time += dt;
+        self.restock_cooldown = (self.restock_cooldown - dt).max(0.0);
 
         // Apply physics
         self.update_physics(dt, world);
@@ -71,10 +268,11 @@ impl Villager {
         self.velocity.y = self.velocity.y.max(-TERMINAL_VELOCITY).min(TERMINAL_VELOCITY);
 
         // Apply horizontal movement based on state
-        if self.state == VillagerState::Walking {
+        if matches!(self.state, VillagerState::Walking | VillagerState::Fleeing) {
+            let speed = if self.state == VillagerState::Fleeing { VILLAGER_FLEE_SPEED } else { VILLAGER_SPEED };
             let yaw_rad = self.yaw.to_radians();
-            self.velocity.x = -yaw_rad.sin() * VILLAGER_SPEED;
-            self.velocity.z = -yaw_rad.cos() * VILLAGER_SPEED;
+            self.velocity.x = -yaw_rad.sin() * speed;
+            self.velocity.z = -yaw_rad.cos() * speed;
         } else {
             self.velocity.x *= 0.8; // Friction
             self.velocity.z *= 0.8;
@@ -84,6 +282,15 @@ impl Villager {
         self.apply_collision(dt, world);
     }
 
+    /// Solid to a walking villager: anything but air, water and open doors
+    fn blocks_movement(world: &World, x: i32, y: i32, z: i32) -> bool {
+        match world.get_block(x, y, z) {
+            Some(BlockType::Air) | Some(BlockType::Water) | None => false,
+            Some(BlockType::DoorBottom) | Some(BlockType::DoorTop) => !world.is_door_open(x, y, z),
+            Some(_) => true,
+        }
+    }
+
     fn apply_collision(&mut self, dt: f32, world: &World) {
         let half_width = VILLAGER_WIDTH / 2.0;
 
@@ -101,11 +308,9 @@ impl Villager {
                     (new_x - half_width).floor() as i32
                 };
 
-                if let Some(block) = world.get_block(check_x, check_y, check_z) {
-                    if block != BlockType::Air && block != BlockType::Water {
-                        can_move_x = false;
-                        break;
-                    }
+                if Self::blocks_movement(world, check_x, check_y, check_z) {
+                    can_move_x = false;
+                    break;
                 }
             }
             if !can_move_x { break; }
@@ -131,11 +336,9 @@ impl Villager {
                     (new_z - half_width).floor() as i32
                 };
 
-                if let Some(block) = world.get_block(check_x, check_y, check_z) {
-                    if block != BlockType::Air && block != BlockType::Water {
-                        can_move_z = false;
-                        break;
-                    }
+                if Self::blocks_movement(world, check_x, check_y, check_z) {
+                    can_move_z = false;
+                    break;
                 }
             }
             if !can_move_z { break; }
@@ -192,9 +395,110 @@ impl Villager {
         self.position.y = new_y;
     }
 
-    pub fn update_ai(&mut self, dt: f32, world: &World, rng: &mut impl Rng) {
+    /// Run the villager's schedule for this AI tick. Returns a door this villager
+    /// wants opened (true) or shut (false) on its way in or out of its house.
+    pub fn update_ai(&mut self, dt: f32, world: &World, time_of_day: f32, threat: Option<Point3<f32>>, rng: &mut impl Rng) -> Option<((i32, i32, i32), bool)> {
         self.state_timer -= dt;
+        let activity = VillagerActivity::at_time(time_of_day);
+
+        // Indoors for the night: stay put until morning, then open up and head out
+        if self.sheltered {
+            let home = self.home?;
+            if activity == VillagerActivity::GoHome {
+                self.state = VillagerState::Idle;
+                self.idle_timer = 1.0;
+                return None;
+            }
+            self.sheltered = false;
+            self.leaving_home = true;
+            return Some((home.door, true));
+        }
+        if self.leaving_home {
+            let home = self.home?;
+            if self.horizontal_distance(home.outside) < HOME_ARRIVE_DISTANCE {
+                // Out on the street - shut the door behind
+                self.leaving_home = false;
+                return Some((home.door, false));
+            }
+            self.head_toward(home.outside, dt, world, rng);
+            return None;
+        }
+
+        // Run from nearby hostile mobs
+        if let Some(threat) = threat {
+            let dx = self.position.x - threat.x;
+            let dz = self.position.z - threat.z;
+            self.yaw = (-dx).atan2(-dz).to_degrees().rem_euclid(360.0);
+            if self.is_blocked(world) || self.is_water_ahead(world) {
+                self.yaw = (self.yaw + if rng.gen::<bool>() { 90.0 } else { -90.0 }).rem_euclid(360.0);
+            }
+            self.state = VillagerState::Fleeing;
+            self.walk_timer = 1.0;
+            return None;
+        }
+        if self.state == VillagerState::Fleeing {
+            self.state = VillagerState::Idle;
+            self.idle_timer = 0.5;
+        }
+
+        match activity {
+            VillagerActivity::GoHome => {
+                if let Some(home) = self.home {
+                    return self.go_home(home, dt, world, rng);
+                }
+            }
+            VillagerActivity::Gather => {
+                if let Some((x, y, z)) = self.meeting_point {
+                    let meeting = Point3::new(x as f32 + 0.5, y as f32, z as f32 + 0.5);
+                    if self.horizontal_distance(meeting) > MEETING_RADIUS {
+                        self.head_toward(meeting, dt, world, rng);
+                        return None;
+                    }
+                }
+            }
+            VillagerActivity::Wander => {
+                // Restock at the workstation once anything has been traded
+                if let Some(target) = self.workstation_center().filter(|_| self.needs_restock()) {
+                    if self.horizontal_distance(target) < WORKSTATION_REACH {
+                        for offer in &mut self.trades {
+                            offer.uses = 0;
+                        }
+                        self.restock_cooldown = RESTOCK_COOLDOWN;
+                        self.state = VillagerState::Idle;
+                        self.idle_timer = 2.0;
+                    } else {
+                        self.head_toward(target, dt, world, rng);
+                    }
+                    return None;
+                }
+            }
+        }
+
+        self.update_wandering(dt, world, rng);
+        None
+    }
+
+    /// Walk to the street side of the door, open it, step inside and shut it
+    fn go_home(&mut self, home: VillagerHome, dt: f32, world: &World, rng: &mut impl Rng) -> Option<((i32, i32, i32), bool)> {
+        if self.horizontal_distance(home.inside) < HOME_ARRIVE_DISTANCE {
+            self.sheltered = true;
+            self.state = VillagerState::Idle;
+            return Some((home.door, false));
+        }
+        let door_center = Point3::new(home.door.0 as f32 + 0.5, home.door.1 as f32, home.door.2 as f32 + 0.5);
+        if self.horizontal_distance(home.outside) < HOME_ARRIVE_DISTANCE || self.horizontal_distance(door_center) < 1.0 {
+            // At the door: walk straight through once it's open
+            self.face(home.inside);
+            self.state = VillagerState::Walking;
+            self.walk_timer = 1.0;
+            return Some((home.door, true));
+        }
+        self.head_toward(home.outside, dt, world, rng);
+        None
+    }
 
+    /// Free roaming: idle, stroll in a random direction, or look around
+    fn update_wandering(&mut self, dt: f32, world: &World, rng: &mut impl Rng) {
         match self.state {
             VillagerState::Idle => {
                 self.idle_timer -= dt;
@@ -207,7 +511,7 @@ impl Villager {
                     }
                 }
             }
-            VillagerState::Walking => {
+            VillagerState::Walking | VillagerState::Fleeing => {
                 self.walk_timer -= dt;
 
                 // Check for obstacles, cliffs, or water ahead
@@ -238,6 +542,36 @@ impl Villager {
         }
     }
 
+    /// Walk toward a point, sidestepping for a moment when something is in the way
+    fn head_toward(&mut self, target: Point3<f32>, dt: f32, world: &World, rng: &mut impl Rng) {
+        self.state = VillagerState::Walking;
+        self.walk_timer = 1.0;
+        if self.detour_timer > 0.0 {
+            self.detour_timer -= dt;
+            if !self.is_blocked(world) {
+                return;
+            }
+        } else {
+            self.face(target);
+        }
+        if self.is_blocked(world) || self.is_water_ahead(world) {
+            self.yaw = (self.yaw + if rng.gen::<bool>() { 90.0 } else { -90.0 }).rem_euclid(360.0);
+            self.detour_timer = 1.0;
+        }
+    }
+
+    fn face(&mut self, target: Point3<f32>) {
+        let dx = target.x - self.position.x;
+        let dz = target.z - self.position.z;
+        self.yaw = (-dx).atan2(-dz).to_degrees().rem_euclid(360.0);
+    }
+
+    fn horizontal_distance(&self, target: Point3<f32>) -> f32 {
+        let dx = target.x - self.position.x;
+        let dz = target.z - self.position.z;
+        (dx * dx + dz * dz).sqrt()
+    }
+
     fn start_walking(&mut self, rng: &mut impl Rng) {
         self.state = VillagerState::Walking;
         self.walk_timer = 3.0 + rng.gen::<f32>() * 5.0;
@@ -321,6 +655,23 @@ pub const ANIMAL_GRAVITY: f32 = 32.0;
 pub const ANIMAL_TERMINAL_VELOCITY: f32 = 50.0;
 pub const MAX_ANIMALS: usize = 200;
 
+// Breeding, taming and leash constants
+const LOVE_DURATION: f32 = 30.0;          // Seconds a fed animal looks for a mate
+const BREED_COOLDOWN: f32 = 120.0;        // Parents can't breed again for this long
+const BREED_SEARCH_RANGE: f32 = 8.0;
+const BREED_DISTANCE: f32 = 1.5;          // Mates this close produce a baby
+const BABY_GROWTH_TIME: f32 = 300.0;      // Seconds until a baby is fully grown
+const BABY_FEED_GROWTH: f32 = 0.1;        // Feeding a baby skips this fraction of its growth
+pub const BABY_SCALE: f32 = 0.5;
+const TAME_CHANCE: f64 = 0.33;            // Chance each taming food succeeds
+const PET_FOLLOW_DISTANCE: f32 = 5.0;     // Pets start following when further than this
+const PET_TELEPORT_DISTANCE: f32 = 24.0;  // ...and catch up instantly past this
+const PET_DEFEND_RANGE: f32 = 12.0;       // Pets attack mobs going for the player within this range
+const PET_ATTACK_RANGE: f32 = 1.8;
+const PET_ATTACK_COOLDOWN: f32 = 1.0;
+pub const LEASH_LENGTH: f32 = 4.0;        // Leashed animals are pulled along past this
+const LEASH_BREAK_DISTANCE: f32 = 12.0;   // ...and the lead snaps past this
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum AnimalType {
     // Existing farm animals
@@ -478,6 +829,41 @@ impl AnimalType {
         }
     }
 
+    /// Whether feeding this item puts the animal in love (or helps a baby grow)
+    pub fn is_breeding_food(&self, item: BlockType) -> bool {
+        match self {
+            AnimalType::Cow | AnimalType::Sheep => item == BlockType::Wheat,
+            AnimalType::Horse => matches!(item, BlockType::Wheat | BlockType::Apple),
+            AnimalType::Pig => matches!(item, BlockType::Carrot | BlockType::Potato),
+            AnimalType::Chicken => item == BlockType::Seeds,
+            AnimalType::Rabbit => item == BlockType::Carrot,
+            AnimalType::Wolf => Self::is_raw_meat(item),
+            _ => false,
+        }
+    }
+
+    /// Whether this item can tame the animal (wolves take meat, parrots take seeds)
+    pub fn is_taming_food(&self, item: BlockType) -> bool {
+        match self {
+            AnimalType::Wolf => Self::is_raw_meat(item),
+            AnimalType::Parrot => item == BlockType::Seeds,
+            _ => false,
+        }
+    }
+
+    fn is_raw_meat(item: BlockType) -> bool {
+        matches!(item, BlockType::RawPork | BlockType::RawBeef | BlockType::RawChicken | BlockType::RawMutton)
+    }
+
+    /// Damage a tamed pet deals to mobs attacking its owner
+    pub fn pet_damage(&self) -> f32 {
+        match self {
+            AnimalType::Wolf => 4.0,
+            AnimalType::Parrot => 1.0,
+            _ => 0.0,
+        }
+    }
+
     /// Returns the meat drop type and quantity range for this animal
     pub fn meat_drop(&self) -> Option<(BlockType, u32, u32)> {
         match self {
@@ -514,6 +900,28 @@ pub struct Animal {
     pub health: f32,
     pub max_health: f32,
     pub damage_flash: f32,
+    idle_sound_timer: f32,
+    pub love_timer: f32,     // > 0 while looking for a mate
+    breed_cooldown: f32,
+    pub growth_timer: f32,   // > 0 while still a baby
+    pub tamed: bool,
+    pub sitting: bool,
+    pub leashed: bool,
+    attack_cooldown: f32,
+    directed: bool,          // Following, mating or defending - wandering AI is skipped
+}
+
+/// What happened when the player used an item (or an empty hand) on an animal
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AnimalInteraction {
+    None,
+    Fed,         // Item consumed - now in love, or a baby grew a bit
+    Tamed,       // Item consumed - the animal is now a pet
+    TameFailed,  // Item consumed - try again
+    Leashed,     // Lead consumed
+    Unleashed,   // Lead returned to the player
+    Sat,
+    Stood,
 }
 
 impl Animal {
@@ -539,17 +947,129 @@ impl Animal {
             health,
             max_health: health,
             damage_flash: 0.0,
+            idle_sound_timer: rng.gen_range(IDLE_SOUND_INTERVAL.0..IDLE_SOUND_INTERVAL.1),
+            love_timer: 0.0,
+            breed_cooldown: 0.0,
+            growth_timer: 0.0,
+            tamed: false,
+            sitting: false,
+            leashed: false,
+            attack_cooldown: 0.0,
+            directed: false,
         }
     }
 
+    /// A newborn - small, and can't breed until it grows up
+    pub fn new_baby(id: u32, animal_type: AnimalType, position: Point3<f32>) -> Self {
+        let mut baby = Self::new(id, animal_type, position);
+        baby.growth_timer = BABY_GROWTH_TIME;
+        baby
+    }
+
+    pub fn is_baby(&self) -> bool {
+        self.growth_timer > 0.0
+    }
+
+    /// Render and collision scale (babies are smaller)
+    pub fn scale(&self) -> f32 {
+        if self.is_baby() { BABY_SCALE } else { 1.0 }
+    }
+
+    /// Collision (width, height), scaled down for babies
+    pub fn dimensions(&self) -> (f32, f32) {
+        let (width, height) = self.animal_type.dimensions();
+        (width * self.scale(), height * self.scale())
+    }
+
     pub fn update(&mut self, dt: f32, world: &World) {
         self.animation_time += dt;
         if self.damage_flash > 0.0 {
             self.damage_flash = (self.damage_flash - dt).max(0.0);
         }
+        self.love_timer = (self.love_timer - dt).max(0.0);
+        self.breed_cooldown = (self.breed_cooldown - dt).max(0.0);
+        self.attack_cooldown = (self.attack_cooldown - dt).max(0.0);
+        if self.growth_timer > 0.0 {
+            self.growth_timer = (self.growth_timer - dt).max(0.0);
+            if self.growth_timer == 0.0 {
+                // Grew up - lift so the bigger body doesn't end up in the ground
+                let (_, height) = self.animal_type.dimensions();
+                self.position.y += height * (1.0 - BABY_SCALE);
+            }
+        }
         self.update_physics(dt, world);
     }
 
+    /// Use an item (or an empty hand) on this animal
+    pub fn interact(&mut self, item: Option<BlockType>, rng: &mut impl Rng) -> AnimalInteraction {
+        if self.leashed && matches!(item, None | Some(BlockType::Lead)) {
+            self.leashed = false;
+            return AnimalInteraction::Unleashed;
+        }
+
+        match item {
+            Some(BlockType::Lead) if self.animal_type.movement_type() != MovementType::Aquatic => {
+                self.leashed = true;
+                self.sitting = false;
+                AnimalInteraction::Leashed
+            }
+            Some(food) if !self.tamed && self.animal_type.is_taming_food(food) => {
+                if rng.gen_bool(TAME_CHANCE) {
+                    self.tamed = true;
+                    self.love_timer = 0.0;
+                    AnimalInteraction::Tamed
+                } else {
+                    AnimalInteraction::TameFailed
+                }
+            }
+            // Wolves only breed once they're someone's pet
+            Some(food) if self.animal_type.is_breeding_food(food)
+                && (self.tamed || self.animal_type != AnimalType::Wolf) =>
+            {
+                if self.is_baby() {
+                    self.growth_timer = (self.growth_timer - BABY_GROWTH_TIME * BABY_FEED_GROWTH).max(0.01);
+                    AnimalInteraction::Fed
+                } else if self.love_timer <= 0.0 && self.breed_cooldown <= 0.0 {
+                    self.love_timer = LOVE_DURATION;
+                    AnimalInteraction::Fed
+                } else {
+                    AnimalInteraction::None
+                }
+            }
+            None if self.tamed => {
+                self.sitting = !self.sitting;
+                if self.sitting { AnimalInteraction::Sat } else { AnimalInteraction::Stood }
+            }
+            _ => AnimalInteraction::None,
+        }
+    }
+
+    /// Face a point and head for it (breeding, following, leash pulls, defending)
+    fn steer_toward(&mut self, target: Point3<f32>, run: bool) {
+        let dx = target.x - self.position.x;
+        let dz = target.z - self.position.z;
+        self.yaw = (-dx).atan2(-dz).to_degrees().rem_euclid(360.0);
+        self.state = match self.animal_type.movement_type() {
+            MovementType::Ground if run => AnimalState::Running,
+            MovementType::Ground => AnimalState::Walking,
+            MovementType::Aquatic => AnimalState::Swimming,
+            MovementType::Flying => AnimalState::Flying,
+        };
+        self.state_timer = 1.0;
+        self.directed = true;
+    }
+
+    /// Stand still (sitting pets, animals that reached what they were heading for)
+    fn hold_still(&mut self) {
+        self.state = match self.animal_type.movement_type() {
+            MovementType::Ground => AnimalState::Idle,
+            MovementType::Aquatic => AnimalState::Idle,
+            MovementType::Flying => AnimalState::Hovering,
+        };
+        self.state_timer = 1.0;
+        self.directed = true;
+    }
+
     /// Take damage and return true if still alive
     pub fn take_damage(&mut self, amount: f32, knockback: Option<Vector3<f32>>) -> bool {
         self.health = (self.health - amount).max(0.0);
@@ -583,7 +1103,7 @@ impl Animal {
     }
 
     fn update_ground_physics(&mut self, dt: f32, world: &World) {
-        let (width, height) = self.animal_type.dimensions();
+        let (width, height) = self.dimensions();
         let half_width = width / 2.0;
 
         // Apply gravity
@@ -684,7 +1204,7 @@ impl Animal {
     }
 
     fn update_aquatic_physics(&mut self, dt: f32, world: &World) {
-        let (_, height) = self.animal_type.dimensions();
+        let (_, height) = self.dimensions();
 
         // Check if currently in water
         let center_y = (self.position.y - height / 2.0).floor() as i32;
@@ -741,7 +1261,7 @@ impl Animal {
     }
 
     fn update_flying_physics(&mut self, dt: f32, world: &World) {
-        let (width, height) = self.animal_type.dimensions();
+        let (width, height) = self.dimensions();
 
         // Flying - no gravity, maintain altitude
         if self.state == AnimalState::Flying {
@@ -816,7 +1336,7 @@ impl Animal {
 
     /// Check if this animal is in water
     fn is_in_water(&self, world: &World) -> bool {
-        let (_, height) = self.animal_type.dimensions();
+        let (_, height) = self.dimensions();
         let center_y = (self.position.y - height / 2.0).floor() as i32;
         world.get_block(
             self.position.x.floor() as i32,
@@ -979,7 +1499,7 @@ impl Animal {
     }
 
     fn is_blocked(&self, world: &World) -> bool {
-        let (_, height) = self.animal_type.dimensions();
+        let (_, height) = self.dimensions();
         let yaw_rad = self.yaw.to_radians();
         let check_x = (self.position.x - yaw_rad.sin() * 1.2).floor() as i32;
         let check_y = (self.position.y - height + 0.5).floor() as i32;
@@ -994,7 +1514,7 @@ impl Animal {
     }
 
     fn is_cliff_ahead(&self, world: &World) -> bool {
-        let (_, height) = self.animal_type.dimensions();
+        let (_, height) = self.dimensions();
         let yaw_rad = self.yaw.to_radians();
         let check_x = (self.position.x - yaw_rad.sin() * 1.5).floor() as i32;
         let check_y = (self.position.y - height - 1.0).floor() as i32;
@@ -1013,7 +1533,7 @@ impl Animal {
     }
 
     fn is_water_ahead(&self, world: &World) -> bool {
-        let (_, height) = self.animal_type.dimensions();
+        let (_, height) = self.dimensions();
         let yaw_rad = self.yaw.to_radians();
         let check_x = (self.position.x - yaw_rad.sin() * 1.5).floor() as i32;
         let feet_y = (self.position.y - height).floor() as i32;
@@ -1050,6 +1570,18 @@ pub const SKELETON_HEALTH: f32 = 20.0;
 pub const SKELETON_SHOOT_COOLDOWN: f32 = 2.0;
 pub const ARROW_SPEED: f32 = 20.0;
 
+// Arrow flight constants
+const ARROW_GRAVITY: f32 = 12.0;
+const ARROW_DRAG: f32 = 0.3;              // Fraction of speed lost per second in air
+const ARROW_WATER_DRAG: f32 = 3.0;        // Arrows slow quickly underwater
+const ARROW_FLIGHT_TIME: f32 = 10.0;      // Despawn if still flying after this long
+const ARROW_STUCK_TIME: f32 = 60.0;       // Arrows stuck in blocks despawn after a minute
+const ARROW_KNOCKBACK: f32 = 5.0;
+pub const BOW_FULL_DRAW_TIME: f32 = 1.0;  // Seconds to pull a bow all the way back
+pub const BOW_MIN_DRAW: f32 = 0.1;        // Weaker draws than this don't fire
+const BOW_ARROW_SPEED: f32 = 30.0;        // Launch speed at full draw
+const BOW_DAMAGE: f32 = 9.0;              // Damage at full draw
+
 // Spider constants
 pub const SPIDER_HEIGHT: f32 = 0.9;
 pub const SPIDER_WIDTH: f32 = 1.4;
@@ -1070,8 +1602,21 @@ pub const CREEPER_FUSE_TIME: f32 = 1.5;
 pub const CREEPER_EXPLOSION_RADIUS: f32 = 3.0;
 pub const CREEPER_EXPLOSION_DAMAGE: f32 = 25.0;
 pub const CREEPER_HEALTH: f32 = 20.0;
+pub const CHARGED_CREEPER_EXPLOSION_RADIUS: f32 = 6.0;  // Creepers struck by lightning
+
+// Lightning strike constants
+pub const LIGHTNING_DAMAGE: f32 = 5.0;
+pub const LIGHTNING_STRIKE_RADIUS: f32 = 3.0;  // Bodies this close to the struck block are hit
+pub const LIGHTNING_BURN_TIME: f32 = 8.0;      // Seconds anything struck keeps burning
+const LIGHTNING_KNOCKBACK: f32 = 8.0;
+
+// Status effects mobs inflict or suffer
+const SPIDER_POISON_TIME: f32 = 5.0;        // Seconds of poison from a spider bite
+const FIRE_BLOCK_BURN_TIME: f32 = 4.0;      // Seconds a mob keeps burning after walking out of fire
 
 pub const MAX_HOSTILE_MOBS: usize = 30;
+/// Mobs chasing or attacking within this range count as the player being in combat
+const COMBAT_RANGE: f32 = 24.0;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum HostileMobType {
@@ -1160,6 +1705,14 @@ impl HostileMobType {
     pub fn explodes(&self) -> bool {
         matches!(self, HostileMobType::Creeper)
     }
+
+    /// Effect a melee hit from this mob leaves on its target
+    pub fn attack_effect(&self) -> Option<TimedEffect> {
+        match self {
+            HostileMobType::Spider => Some((StatusEffectType::Poison, SPIDER_POISON_TIME, 0)),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -1171,6 +1724,29 @@ pub enum HostileMobState {
     Fusing,  // Creeper about to explode
 }
 
+/// Seconds between idle sounds from a mob or animal (random within range)
+const IDLE_SOUND_INTERVAL: (f32, f32) = (8.0, 20.0);
+/// Flying planes repeat their engine sound this often
+const PLANE_ENGINE_SOUND_INTERVAL: f32 = 0.5;
+
+/// Sounds entities made this frame - collected by EntityManager and played by main.rs
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EntitySound {
+    MobIdle(HostileMobType),
+    MobHurt(HostileMobType),
+    MobDeath(HostileMobType),
+    MobAttack(HostileMobType),
+    CreeperFuse,
+    AnimalIdle(AnimalType),
+    AnimalHurt(AnimalType),
+    AnimalDeath(AnimalType),
+    ArrowShoot,
+    ArrowHit,
+    PlaneEngine,
+    MissileLaunch,
+    BombDrop,
+}
+
 /// Actions that mobs can trigger
 #[derive(Debug)]
 pub enum MobAction {
@@ -1178,53 +1754,120 @@ pub enum MobAction {
     Explode,                    // Creeper explosion
 }
 
-/// Projectile (arrows shot by skeletons)
+/// Who fired an arrow, so it doesn't hit its own shooter
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ArrowShooter {
+    Player,
+    Mob(u32),
+}
+
+/// Projectile (arrows shot by skeletons and the player's bow)
 pub struct Projectile {
     pub id: u32,
     pub position: Point3<f32>,
     pub velocity: Vector3<f32>,
     pub damage: f32,
     pub lifetime: f32,
+    pub shooter: ArrowShooter,
+    pub stuck: bool,         // Lodged in a block; can be picked up
 }
 
 impl Projectile {
-    pub fn new(id: u32, position: Point3<f32>, direction: Vector3<f32>) -> Self {
+    pub fn new(id: u32, position: Point3<f32>, direction: Vector3<f32>, shooter: ArrowShooter) -> Self {
         Self {
             id,
             position,
             velocity: direction * ARROW_SPEED,
             damage: SKELETON_DAMAGE,
-            lifetime: 5.0,  // 5 seconds before despawn
+            lifetime: ARROW_FLIGHT_TIME,
+            shooter,
+            stuck: false,
         }
     }
 
+    /// An arrow loosed from the player's bow; speed and damage scale with how far it was drawn
+    pub fn from_bow(id: u32, position: Point3<f32>, direction: Vector3<f32>, draw_strength: f32) -> Self {
+        let mut arrow = Self::new(id, position, direction, ArrowShooter::Player);
+        arrow.velocity = direction * BOW_ARROW_SPEED * draw_strength;
+        arrow.damage = (BOW_DAMAGE * draw_strength).max(1.0);
+        arrow
+    }
+
     /// Update projectile, returns true if still alive
     pub fn update(&mut self, dt: f32, world: &World) -> bool {
         self.lifetime -= dt;
         if self.lifetime <= 0.0 {
             return false;
         }
+        if self.stuck {
+            return true;
+        }
 
-        // Apply gravity
-        self.velocity.y -= GRAVITY * 0.5 * dt;
-
-        // Move
-        let new_pos = self.position + self.velocity * dt;
-
-        // Check for block collision
-        let block_x = new_pos.x.floor() as i32;
-        let block_y = new_pos.y.floor() as i32;
-        let block_z = new_pos.z.floor() as i32;
-
-        if let Some(block) = world.get_block(block_x, block_y, block_z) {
-            if block != BlockType::Air && block != BlockType::Water {
-                return false;  // Hit a block
+        // Apply gravity and drag (much stronger underwater)
+        let in_water = world.get_block(
+            self.position.x.floor() as i32,
+            self.position.y.floor() as i32,
+            self.position.z.floor() as i32,
+        ) == Some(BlockType::Water);
+        let drag = if in_water { ARROW_WATER_DRAG } else { ARROW_DRAG };
+        self.velocity.y -= ARROW_GRAVITY * dt;
+        self.velocity *= (1.0 - drag * dt).max(0.0);
+
+        // Move in small steps so fast arrows can't skip through thin walls
+        let travel = self.velocity * dt;
+        let steps = (travel.magnitude() / 0.25).ceil().max(1.0) as i32;
+        let step = travel / steps as f32;
+        for _ in 0..steps {
+            let new_pos = self.position + step;
+            let block = world.get_block(new_pos.x.floor() as i32, new_pos.y.floor() as i32, new_pos.z.floor() as i32);
+            if block.is_some_and(|b| b != BlockType::Air && b != BlockType::Water) {
+                // Bury the tip slightly in the block and stay there
+                self.position += step * 0.5;
+                self.stuck = true;
+                self.lifetime = ARROW_STUCK_TIME;
+                return true;
             }
+            self.position = new_pos;
         }
-
-        self.position = new_pos;
         true
     }
+
+    /// Whether a flying arrow is inside a body whose top is at `top`
+    fn hits_body(&self, top: Point3<f32>, width: f32, height: f32) -> bool {
+        let half_width = width / 2.0 + 0.1;
+        !self.stuck
+            && (self.position.x - top.x).abs() < half_width
+            && (self.position.z - top.z).abs() < half_width
+            && self.position.y < top.y
+            && self.position.y > top.y - height
+    }
+
+    /// Knockback for whatever this arrow hits, pushing along its flight path
+    fn knockback(&self) -> Vector3<f32> {
+        let horizontal = Vector3::new(self.velocity.x, 0.0, self.velocity.z);
+        let push = if horizontal.magnitude() > 0.01 { horizontal.normalize() * ARROW_KNOCKBACK } else { Vector3::new(0.0, 0.0, 0.0) };
+        Vector3::new(push.x, 2.0, push.z)
+    }
+}
+
+/// How far a bow has been drawn (0.0-1.0) after holding it for `draw_time` seconds
+pub fn bow_draw_strength(draw_time: f32) -> f32 {
+    let t = (draw_time / BOW_FULL_DRAW_TIME).min(1.0);
+    // Quick to start pulling, slower to reach full draw
+    (t * t + 2.0 * t) / 3.0
+}
+
+/// Whether a body whose top is at `top` is close enough to a lightning strike to be hit
+pub fn within_lightning_strike(strike: Point3<f32>, top: Point3<f32>, height: f32) -> bool {
+    let closest = Point3::new(top.x, strike.y.clamp(top.y - height, top.y), top.z);
+    (closest - strike).magnitude() < LIGHTNING_STRIKE_RADIUS
+}
+
+/// Knockback from a lightning strike: thrown away from where it hit and up off the ground
+pub fn lightning_knockback(strike: Point3<f32>, position: Point3<f32>) -> Vector3<f32> {
+    let away = Vector3::new(position.x - strike.x, 0.0, position.z - strike.z);
+    let push = if away.magnitude() > 0.01 { away.normalize() * LIGHTNING_KNOCKBACK } else { Vector3::new(0.0, 0.0, 0.0) };
+    Vector3::new(push.x, LIGHTNING_KNOCKBACK * 0.6, push.z)
 }
 
 pub struct HostileMob {
@@ -1243,9 +1886,12 @@ pub struct HostileMob {
     state_timer: f32,
     // Mob-specific fields
     pub fuse_timer: f32,        // Creeper explosion countdown
+    pub charged: bool,          // Creeper struck by lightning, explodes with a bigger blast
     pub shoot_cooldown: f32,    // Skeleton arrow cooldown
     pub is_climbing: bool,      // Spider wall climbing
     pub was_attacked: bool,     // Spider becomes hostile if attacked during day
+    pub effects: StatusEffects,
+    idle_sound_timer: f32,
 }
 
 impl HostileMob {
@@ -1266,9 +1912,12 @@ impl HostileMob {
             on_ground: false,
             state_timer: rng.gen_range(1.0..3.0),
             fuse_timer: 0.0,
+            charged: false,
             shoot_cooldown: 0.0,
             is_climbing: false,
             was_attacked: false,
+            effects: StatusEffects::new(),
+            idle_sound_timer: rng.gen_range(IDLE_SOUND_INTERVAL.0..IDLE_SOUND_INTERVAL.1),
         }
     }
 
@@ -1286,9 +1935,39 @@ impl HostileMob {
             self.shoot_cooldown = (self.shoot_cooldown - dt).max(0.0);
         }
 
+        self.update_effects(dt, world);
         self.update_physics(dt, world);
     }
 
+    /// Tick status effects: fire blocks set the mob alight and water puts it out,
+    /// poison and burning hurt, regeneration heals
+    fn update_effects(&mut self, dt: f32, world: &World) {
+        let (_, height) = self.mob_type.dimensions();
+        let feet = world.get_block(
+            self.position.x.floor() as i32,
+            (self.position.y - height + 0.1).floor() as i32,
+            self.position.z.floor() as i32,
+        );
+        match feet {
+            Some(BlockType::Water) => self.effects.remove(StatusEffectType::Fire),
+            Some(BlockType::Fire) => self.effects.apply(StatusEffectType::Fire, FIRE_BLOCK_BURN_TIME, 0),
+            _ => {}
+        }
+        if self.effects.is_empty() {
+            return;
+        }
+
+        let tick = self.effects.update(dt);
+        // Poison never finishes a mob off
+        let poison = tick.poison.min(self.health - 1.0).max(0.0);
+        if poison + tick.fire > 0.0 {
+            self.take_damage(poison + tick.fire, None);
+        }
+        if tick.regeneration > 0.0 {
+            self.health = (self.health + tick.regeneration).min(self.max_health);
+        }
+    }
+
     fn update_physics(&mut self, dt: f32, world: &World) {
         let (width, height) = self.mob_type.dimensions();
         let half_width = width / 2.0;
@@ -1303,7 +1982,7 @@ impl HostileMob {
                 self.mob_type.speed()
             } else {
                 self.mob_type.speed() * 0.4 // Slower when wandering
-            };
+            } * self.effects.speed_multiplier();
             let yaw_rad = self.yaw.to_radians();
             self.velocity.x = -yaw_rad.sin() * speed;
             self.velocity.z = -yaw_rad.cos() * speed;
@@ -1504,7 +2183,10 @@ impl HostileMob {
                         // Shoot arrow
                         if self.shoot_cooldown <= 0.0 {
                             self.shoot_cooldown = SKELETON_SHOOT_COOLDOWN;
-                            let direction = (player_pos - self.position).normalize();
+                            // Aim above the target to allow for the arrow's drop
+                            let flight_time = distance_to_player / ARROW_SPEED;
+                            let aim_point = player_pos + Vector3::new(0.0, 0.5 * ARROW_GRAVITY * flight_time * flight_time, 0.0);
+                            let direction = (aim_point - self.position).normalize();
                             self.state = HostileMobState::Chasing;
                             return Some(MobAction::ShootArrow(direction));
                         }
@@ -1626,6 +2308,11 @@ impl HostileMob {
     pub fn is_dead(&self) -> bool {
         self.health <= 0.0
     }
+
+    /// Blast radius when this creeper goes off
+    pub fn explosion_radius(&self) -> f32 {
+        if self.charged { CHARGED_CREEPER_EXPLOSION_RADIUS } else { CREEPER_EXPLOSION_RADIUS }
+    }
 }
 
 // Dropped item that can be picked up
@@ -1733,6 +2420,36 @@ const PLANE_TURN_SPEED: f32 = 80.0;         // Degrees per second
 const PLANE_CLIMB_SPEED: f32 = 8.0;         // Vertical speed
 const PLANE_MAX_ALTITUDE: f32 = 180.0;
 
+// Realistic flight model
+const PLANE_GRAVITY: f32 = 12.0;
+const PLANE_MAX_THRUST: f32 = 8.0;          // Acceleration at full throttle
+const PLANE_THROTTLE_RATE: f32 = 0.5;       // Throttle change per second while W/S is held
+const PLANE_DRAG: f32 = 0.008;              // Drag acceleration per (speed squared); tops out around 32
+const PLANE_LIFT: f32 = 0.054;              // Lift acceleration per (speed squared) per unit lift coefficient
+const PLANE_BASE_LIFT_COEFF: f32 = 0.1;     // Lift coefficient with the wings level to the airflow
+const PLANE_LIFT_PER_DEGREE: f32 = 0.08;    // Extra lift per degree of angle of attack
+const PLANE_STALL_ANGLE: f32 = 15.0;        // Angle of attack where the wings stop lifting
+pub const PLANE_STALL_SPEED: f32 = 12.0;    // Below this airspeed the wings stall
+const PLANE_STALL_LIFT: f32 = 0.3;          // Fraction of lift left in a stall
+const PLANE_PITCH_RATE: f32 = 30.0;         // Degrees per second of pitch at full control authority
+const PLANE_MAX_PITCH: f32 = 40.0;
+const PLANE_TAKEOFF_SPEED: f32 = 15.0;      // Rotate speed; ~20 blocks of runway from a standing start
+const PLANE_ROLLING_FRICTION: f32 = 1.5;    // Wheel friction while taxiing
+const PLANE_BRAKE_DECEL: f32 = 6.0;         // Braking with S at idle throttle
+const PLANE_TAXI_TURN_SPEED: f32 = 40.0;    // Degrees per second of nose-wheel steering
+const PLANE_GROUND_CRASH_SPEED: f32 = 6.0;  // Hitting an obstacle faster than this on the ground wrecks the plane
+pub const PLANE_MAX_LANDING_SINK_RATE: f32 = 4.0; // Touching down faster than this is a crash
+const PLANE_MAX_LANDING_ROLL: f32 = 25.0;   // Wings must be roughly level to land
+
+// Damage, fuel and repair
+pub const PLANE_MAX_HEALTH: f32 = 100.0;
+pub const PLANE_MAX_FUEL: f32 = 100.0;
+const PLANE_FUEL_BURN_RATE: f32 = 0.5;      // Fuel per second with the engine at full power
+const PLANE_FUEL_PER_COAL: f32 = 25.0;
+const PLANE_REPAIR_PER_INGOT: f32 = 25.0;
+const PLANE_ARROW_DAMAGE_MULTIPLIER: f32 = 2.0;
+const PLANE_EXPLOSION_DAMAGE: f32 = 60.0;   // At the centre of a blast, falling off with distance
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum PlaneState {
     Grounded,   // On ground
@@ -1740,6 +2457,40 @@ pub enum PlaneState {
     Crashed,    // Destroyed
 }
 
+/// How planes handle: the original arcade controls, or lift, drag and stalls
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FlightModel {
+    Arcade,
+    Realistic,
+}
+
+impl FlightModel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FlightModel::Arcade => "Arcade",
+            FlightModel::Realistic => "Realistic",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            FlightModel::Arcade => FlightModel::Realistic,
+            FlightModel::Realistic => FlightModel::Arcade,
+        }
+    }
+}
+
+/// Pilot inputs for one frame
+#[derive(Clone, Copy, Default, Debug)]
+pub struct PlaneControls {
+    pub throttle_up: bool,
+    pub throttle_down: bool,
+    pub turn_left: bool,
+    pub turn_right: bool,
+    pub pitch_up: bool,
+    pub pitch_down: bool,
+}
+
 // Missile constants
 const MISSILE_SPEED: f32 = 50.0;
 const MISSILE_LIFETIME: f32 = 5.0;  // Seconds before despawn
@@ -1920,6 +2671,9 @@ pub struct Plane {
     pub propeller_rotation: f32,
     pub health: f32,
     pub color: PlaneColor,
+    pub velocity: Vector3<f32>,
+    pub throttle: f32,          // 0.0-1.0, realistic flight model only
+    pub fuel: f32,
 }
 
 impl Plane {
@@ -1934,33 +2688,122 @@ impl Plane {
             propeller_rotation: 0.0,
             health: 100.0,
             color: PlaneColor::random(),
+            velocity: Vector3::new(0.0, 0.0, 0.0),
+            throttle: 0.0,
+            fuel: PLANE_MAX_FUEL,
         }
     }
 
-    pub fn is_crashed(&self) -> bool {
-        self.state == PlaneState::Crashed
-    }
-
-    pub fn is_grounded(&self) -> bool {
-        self.state == PlaneState::Grounded
-    }
-
-    /// Simple arcade flight: W=forward, A/D=turn, Space=up, Shift=down
-    pub fn fly(&mut self, dt: f32, forward: bool, turn_left: bool, turn_right: bool, go_up: bool, go_down: bool, world: &World) {
+    /// Take damage from arrows or explosions; the plane goes down once health runs out
+    pub fn take_damage(&mut self, damage: f32) {
         if self.is_crashed() {
             return;
         }
+        self.health = (self.health - damage).max(0.0);
+        if self.health <= 0.0 {
+            self.state = PlaneState::Crashed;
+        }
+    }
 
-        // Smooth interpolation factors (higher = faster response)
-        let roll_lerp = (4.0 * dt).min(1.0);
-        let pitch_lerp = (3.0 * dt).min(1.0);
+    /// Trails smoke once badly damaged
+    pub fn is_smoking(&self) -> bool {
+        !self.is_crashed() && self.health < PLANE_MAX_HEALTH * 0.5
+    }
 
-        // Calculate target roll and yaw based on input
-        let target_roll: f32;
-        let turn_input: f32;
+    pub fn has_fuel(&self) -> bool {
+        self.fuel > 0.0
+    }
 
-        if turn_left {
-            target_roll = -35.0;
+    /// Tip a lump of coal into the tank, returns false if it's already full
+    pub fn refuel(&mut self) -> bool {
+        if self.fuel >= PLANE_MAX_FUEL {
+            return false;
+        }
+        self.fuel = (self.fuel + PLANE_FUEL_PER_COAL).min(PLANE_MAX_FUEL);
+        true
+    }
+
+    /// Patch the plane up with an iron ingot, returns false if it's undamaged
+    pub fn repair(&mut self) -> bool {
+        if self.is_crashed() || self.health >= PLANE_MAX_HEALTH {
+            return false;
+        }
+        self.health = (self.health + PLANE_REPAIR_PER_INGOT).min(PLANE_MAX_HEALTH);
+        true
+    }
+
+    fn burn_fuel(&mut self, dt: f32, power: f32) {
+        self.fuel = (self.fuel - PLANE_FUEL_BURN_RATE * power * dt).max(0.0);
+    }
+
+    /// Speed through the air
+    pub fn airspeed(&self) -> f32 {
+        self.velocity.magnitude()
+    }
+
+    /// How fast the plane is descending (positive = sinking)
+    pub fn sink_rate(&self) -> f32 {
+        -self.velocity.y
+    }
+
+    pub fn is_crashed(&self) -> bool {
+        self.state == PlaneState::Crashed
+    }
+
+    pub fn is_grounded(&self) -> bool {
+        self.state == PlaneState::Grounded
+    }
+
+    /// Fly for one frame with the chosen flight model: W/S throttle, A/D turn, Space/Shift pitch
+    pub fn fly(&mut self, dt: f32, controls: &PlaneControls, model: FlightModel, world: &World) {
+        match model {
+            FlightModel::Arcade => self.fly_arcade(dt, controls, world),
+            FlightModel::Realistic => {
+                if self.is_crashed() {
+                    return;
+                }
+                let ground_y = self.find_ground_y(world);
+                self.step_physics(dt, controls, ground_y);
+                if !self.is_crashed() && self.check_collision(world) {
+                    if self.state == PlaneState::Grounded && self.airspeed() < PLANE_GROUND_CRASH_SPEED {
+                        // Bumped into something while taxiing - stop dead
+                        self.position -= self.velocity * dt;
+                        self.velocity = Vector3::new(0.0, 0.0, 0.0);
+                    } else {
+                        self.health = 0.0;
+                        self.state = PlaneState::Crashed;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Simple arcade flight: W=forward, A/D=turn, Space=up, Shift=down
+    fn fly_arcade(&mut self, dt: f32, controls: &PlaneControls, world: &World) {
+        if self.is_crashed() {
+            return;
+        }
+        // With an empty tank the engine is dead: no take-off, and a flying plane glides down
+        let engine_running = self.has_fuel();
+        let forward = controls.throttle_up && engine_running;
+        let turn_left = controls.turn_left;
+        let turn_right = controls.turn_right;
+        let go_up = controls.pitch_up && engine_running;
+        let go_down = controls.pitch_down || !engine_running;
+        if self.state == PlaneState::Flying {
+            self.burn_fuel(dt, 1.0);
+        }
+
+        // Smooth interpolation factors (higher = faster response)
+        let roll_lerp = (4.0 * dt).min(1.0);
+        let pitch_lerp = (3.0 * dt).min(1.0);
+
+        // Calculate target roll and yaw based on input
+        let target_roll: f32;
+        let turn_input: f32;
+
+        if turn_left {
+            target_roll = -35.0;
             turn_input = -1.0;
         } else if turn_right {
             target_roll = 35.0;
@@ -2012,9 +2855,8 @@ impl Plane {
             let vel_z = -yaw_rad.cos() * PLANE_SPEED;
 
             // Apply movement
-            self.position.x += vel_x * dt;
-            self.position.y += vel_y * dt;
-            self.position.z += vel_z * dt;
+            self.velocity = Vector3::new(vel_x, vel_y, vel_z);
+            self.position += self.velocity * dt;
 
             // Altitude cap
             if self.position.y > PLANE_MAX_ALTITUDE {
@@ -2034,6 +2876,7 @@ impl Plane {
                 // Landing
                 self.position.y = ground_y + 1.0;
                 self.state = PlaneState::Grounded;
+                self.velocity = Vector3::new(0.0, 0.0, 0.0);
             }
         } else {
             // Grounded - smoothly level the plane
@@ -2056,6 +2899,135 @@ impl Plane {
         }
     }
 
+    /// One step of the realistic flight model over ground at height `ground_y`.
+    /// Needs no world access, so it can be driven headlessly.
+    pub fn step_physics(&mut self, dt: f32, controls: &PlaneControls, ground_y: f32) {
+        if self.is_crashed() {
+            return;
+        }
+
+        // Throttle creeps up and down while W/S are held
+        if controls.throttle_up {
+            self.throttle = (self.throttle + PLANE_THROTTLE_RATE * dt).min(1.0);
+        }
+        if controls.throttle_down {
+            self.throttle = (self.throttle - PLANE_THROTTLE_RATE * dt).max(0.0);
+        }
+
+        let turn_input = match (controls.turn_left, controls.turn_right) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        };
+        let pitch_input = match (controls.pitch_up, controls.pitch_down) {
+            (true, false) => 1.0,
+            (false, true) => -1.0,
+            _ => 0.0,
+        };
+        let airspeed = self.airspeed();
+        // The engine idles on the ground and burns more the harder it's pushed; no fuel, no thrust
+        let engine_power = if self.has_fuel() { self.throttle } else { 0.0 };
+        if self.has_fuel() && (self.state == PlaneState::Flying || self.throttle > 0.0) {
+            self.burn_fuel(dt, 0.2 + 0.8 * self.throttle);
+        }
+        // Thrust fades away in the thin air above the altitude limit
+        let thrust = PLANE_MAX_THRUST * engine_power * (1.0 - (self.position.y - PLANE_MAX_ALTITUDE) / 20.0).clamp(0.0, 1.0);
+
+        if self.state == PlaneState::Grounded {
+            // Level out on the wheels
+            self.pitch *= (1.0 - 5.0 * dt).max(0.0);
+            self.roll *= (1.0 - 5.0 * dt).max(0.0);
+
+            // Nose-wheel steering
+            self.yaw = (self.yaw + turn_input * PLANE_TAXI_TURN_SPEED * dt).rem_euclid(360.0);
+
+            // Take-off roll: thrust against wheel friction and drag, brakes at idle
+            let brake = if controls.throttle_down && self.throttle == 0.0 { PLANE_BRAKE_DECEL } else { 0.0 };
+            let resistance = if airspeed > 0.0 { PLANE_ROLLING_FRICTION + brake + PLANE_DRAG * airspeed * airspeed } else { 0.0 };
+            let speed = (airspeed + (thrust - resistance) * dt).max(0.0);
+
+            let yaw_rad = self.yaw.to_radians();
+            self.velocity = Vector3::new(-yaw_rad.sin() * speed, 0.0, -yaw_rad.cos() * speed);
+            self.position += self.velocity * dt;
+
+            if self.position.y > ground_y + 1.5 {
+                // Rolled off a ledge - now we're flying whether we like it or not
+                self.state = PlaneState::Flying;
+            } else {
+                self.position.y = ground_y + 1.0;
+                if speed >= PLANE_TAKEOFF_SPEED && controls.pitch_up {
+                    // Rotate and lift off
+                    self.state = PlaneState::Flying;
+                    self.pitch = 8.0;
+                    self.velocity.y = 2.0;
+                }
+            }
+        } else {
+            // Bank into turns; a banked wing turns the plane (coordinated turn)
+            let target_roll = turn_input * 35.0;
+            self.roll += (target_roll - self.roll) * (4.0 * dt).min(1.0);
+            if airspeed > 1.0 {
+                let turn_rate = (PLANE_GRAVITY * self.roll.to_radians().tan() / airspeed).to_degrees();
+                self.yaw = (self.yaw + turn_rate * dt).rem_euclid(360.0);
+            }
+
+            // Angle of attack: where the nose points versus where the plane is going
+            let horizontal_speed = (self.velocity.x * self.velocity.x + self.velocity.z * self.velocity.z).sqrt();
+            let flight_path_angle = self.velocity.y.atan2(horizontal_speed.max(0.01)).to_degrees();
+            let angle_of_attack = self.pitch - flight_path_angle;
+            let stalled = airspeed < PLANE_STALL_SPEED || angle_of_attack > PLANE_STALL_ANGLE;
+
+            // Elevators lose authority at low speed; a stalled plane drops its nose
+            let authority = (airspeed / PLANE_STALL_SPEED).min(1.0);
+            self.pitch += pitch_input * PLANE_PITCH_RATE * authority * dt;
+            if stalled {
+                self.pitch -= PLANE_PITCH_RATE * 0.7 * dt;
+            }
+            self.pitch = self.pitch.clamp(-PLANE_MAX_PITCH, PLANE_MAX_PITCH);
+
+            let mut lift_coeff = PLANE_BASE_LIFT_COEFF + PLANE_LIFT_PER_DEGREE * angle_of_attack.clamp(-5.0, PLANE_STALL_ANGLE);
+            if stalled {
+                lift_coeff *= PLANE_STALL_LIFT;
+            }
+            // Lift acts at right angles to the airflow; banking tilts it sideways, leaving less to hold the plane up
+            let lift = PLANE_LIFT * airspeed * airspeed * lift_coeff.max(0.0) * self.roll.to_radians().cos();
+            let flow = if airspeed > 0.1 { self.velocity / airspeed } else { Vector3::new(0.0, 0.0, 0.0) };
+            let lift_dir = Vector3::unit_y() - flow * flow.y;
+            let lift_dir = if lift_dir.magnitude() > 0.01 { lift_dir.normalize() } else { Vector3::unit_y() };
+
+            let yaw_rad = self.yaw.to_radians();
+            let pitch_rad = self.pitch.to_radians();
+            let nose = Vector3::new(-yaw_rad.sin() * pitch_rad.cos(), pitch_rad.sin(), -yaw_rad.cos() * pitch_rad.cos());
+            let drag = if airspeed > 0.0 { -self.velocity * PLANE_DRAG * airspeed } else { Vector3::new(0.0, 0.0, 0.0) };
+            let accel = nose * thrust + drag + lift_dir * lift - Vector3::unit_y() * PLANE_GRAVITY;
+            self.velocity += accel * dt;
+
+            // The tail keeps the plane pointed into the wind: horizontal motion follows the heading
+            let horizontal_speed = (self.velocity.x * self.velocity.x + self.velocity.z * self.velocity.z).sqrt();
+            self.velocity.x = -yaw_rad.sin() * horizontal_speed;
+            self.velocity.z = -yaw_rad.cos() * horizontal_speed;
+
+            self.position += self.velocity * dt;
+
+            // Touchdown: gentle and wings-level lands, anything else is a crash
+            if self.position.y < ground_y + 1.0 {
+                self.position.y = ground_y + 1.0;
+                if self.sink_rate() > PLANE_MAX_LANDING_SINK_RATE || self.roll.abs() > PLANE_MAX_LANDING_ROLL {
+                    self.health = 0.0;
+                    self.state = PlaneState::Crashed;
+                    return;
+                }
+                self.state = PlaneState::Grounded;
+                self.velocity.y = 0.0;
+            }
+        }
+
+        // Propeller spins with the throttle, and stops once the tank runs dry
+        if self.has_fuel() {
+            self.propeller_rotation = (self.propeller_rotation + 360.0 * 5.0 * self.throttle.max(0.2) * dt) % 360.0;
+        }
+    }
+
     fn find_ground_y(&self, world: &World) -> f32 {
         let x = self.position.x.floor() as i32;
         let z = self.position.z.floor() as i32;
@@ -2103,6 +3075,447 @@ impl Plane {
     }
 }
 
+// ============================================================================
+// BOATS AND MINECARTS - Rideable vehicles
+// ============================================================================
+
+pub const MAX_VEHICLES: usize = 50;
+const VEHICLE_GRAVITY: f32 = 20.0;
+const VEHICLE_GROUND_FRICTION: f32 = 6.0;   // Speed lost per second while scraping over land
+
+// Boat handling
+const BOAT_HALF_WIDTH: f32 = 0.6;
+const BOAT_PADDLE_ACCEL: f32 = 5.0;
+const BOAT_MAX_SPEED: f32 = 6.0;
+const BOAT_REVERSE_FACTOR: f32 = 0.5;       // Backpaddling is slower than rowing forward
+const BOAT_LAND_GRIP: f32 = 0.1;            // Paddles barely move a beached boat
+const BOAT_TURN_SPEED: f32 = 90.0;          // Degrees per second
+const BOAT_WATER_DRAG: f32 = 0.8;           // Fraction of forward speed lost per second while coasting
+const BOAT_KEEL_DRAG: f32 = 4.0;            // The keel stops the hull sliding sideways
+const BOAT_BUOYANCY: f32 = 30.0;
+const BOAT_BOB_DAMPING: f32 = 6.0;
+const BOAT_DRAFT: f32 = 0.2;                // How deep the hull sits in the water
+
+// Minecart handling
+const MINECART_HALF_WIDTH: f32 = 0.45;
+const MINECART_PUSH_ACCEL: f32 = 3.0;
+const MINECART_PUSH_SPEED: f32 = 4.0;       // Pushing alone can't go faster than this
+const MINECART_MAX_SPEED: f32 = 10.0;
+const MINECART_BRAKE_DECEL: f32 = 6.0;
+const MINECART_FRICTION: f32 = 0.3;
+const MINECART_SLOPE_ACCEL: f32 = 8.0;      // Downhill pull on a sloped rail
+const MINECART_SLOPE_PITCH: f32 = 45.0;     // Slopes rise one block per block
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VehicleKind {
+    Boat,
+    Minecart,
+}
+
+impl VehicleKind {
+    /// The vehicle an item places, if any
+    pub fn from_item(item: BlockType) -> Option<Self> {
+        match item {
+            BlockType::Boat => Some(VehicleKind::Boat),
+            BlockType::Minecart => Some(VehicleKind::Minecart),
+            _ => None,
+        }
+    }
+
+    /// The item a vehicle drops when broken
+    pub fn item(&self) -> BlockType {
+        match self {
+            VehicleKind::Boat => BlockType::Boat,
+            VehicleKind::Minecart => BlockType::Minecart,
+        }
+    }
+
+    fn half_width(&self) -> f32 {
+        match self {
+            VehicleKind::Boat => BOAT_HALF_WIDTH,
+            VehicleKind::Minecart => MINECART_HALF_WIDTH,
+        }
+    }
+
+    /// Height of the rider's seat above the bottom of the hull
+    fn seat_height(&self) -> f32 {
+        match self {
+            VehicleKind::Boat => 0.25,
+            VehicleKind::Minecart => 0.3,
+        }
+    }
+}
+
+/// Rider inputs for one frame
+#[derive(Clone, Copy, Debug)]
+pub struct VehicleControls {
+    pub forward: bool,
+    pub backward: bool,
+    pub turn_left: bool,
+    pub turn_right: bool,
+    pub look: Vector3<f32>,  // Minecarts get pushed the way the rider is looking
+}
+
+#[derive(Clone, Debug)]
+pub struct Vehicle {
+    pub id: u32,
+    pub kind: VehicleKind,
+    pub position: Point3<f32>,  // Bottom centre of the hull
+    pub velocity: Vector3<f32>,
+    pub yaw: f32,               // Degrees, 0 faces north (-Z) like planes
+    pub pitch: f32,             // Minecarts tip up and down rail slopes
+    pub roll: f32,              // Boats rock gently on the water
+    on_ground: bool,
+    bob_time: f32,
+    heading: Option<BlockFacing>, // Which way a minecart is rolling along its track
+    track_speed: f32,
+}
+
+impl Vehicle {
+    pub fn new(id: u32, kind: VehicleKind, position: Point3<f32>, yaw: f32) -> Self {
+        Self {
+            id,
+            kind,
+            position,
+            velocity: Vector3::new(0.0, 0.0, 0.0),
+            yaw,
+            pitch: 0.0,
+            roll: 0.0,
+            on_ground: false,
+            bob_time: 0.0,
+            heading: None,
+            track_speed: 0.0,
+        }
+    }
+
+    /// Where the rider sits
+    pub fn seat_position(&self) -> Point3<f32> {
+        Point3::new(self.position.x, self.position.y + self.kind.seat_height(), self.position.z)
+    }
+
+    fn forward(&self) -> Vector3<f32> {
+        let yaw_rad = self.yaw.to_radians();
+        Vector3::new(-yaw_rad.sin(), 0.0, -yaw_rad.cos())
+    }
+
+    fn is_solid(world: &World, x: i32, y: i32, z: i32) -> bool {
+        world.get_block(x, y, z).is_some_and(|b| b.collision_shape() != CollisionShape::None)
+    }
+
+    /// Height of the water surface at a point, if there is water within a block of it
+    fn water_surface(world: &World, pos: Point3<f32>) -> Option<f32> {
+        let (bx, bz) = (pos.x.floor() as i32, pos.z.floor() as i32);
+        let top = pos.y.floor() as i32 + 1;
+        (top - 2..=top + 1).rev().find_map(|by| {
+            let level = world.get_water_level(bx, by, bz);
+            (level > 0).then(|| by as f32 + level as f32 / 8.0)
+        })
+    }
+
+    /// The rail a minecart at this point is riding on, checking one block down for slopes
+    fn rail_under(world: &World, pos: Point3<f32>) -> Option<((i32, i32, i32), RailShape)> {
+        let (bx, bz) = (pos.x.floor() as i32, pos.z.floor() as i32);
+        let by = (pos.y + 0.05).floor() as i32;
+        [by, by - 1].into_iter()
+            .find(|&y| world.get_block(bx, y, bz) == Some(BlockType::Rail))
+            .map(|y| ((bx, y, bz), world.rail_shape(bx, y, bz)))
+    }
+
+    fn facing_yaw(facing: BlockFacing) -> f32 {
+        match facing {
+            BlockFacing::North => 0.0,
+            BlockFacing::West => 90.0,
+            BlockFacing::South => 180.0,
+            BlockFacing::East => -90.0,
+        }
+    }
+
+    fn facing_vector(facing: BlockFacing) -> Vector3<f32> {
+        let (dx, dz) = facing.offset();
+        Vector3::new(dx as f32, 0.0, dz as f32)
+    }
+
+    /// A spot beside the vehicle to stand on when getting out (feet position)
+    pub fn dismount_position(&self, world: &World) -> Point3<f32> {
+        let (x, z) = (self.position.x.floor() as i32, self.position.z.floor() as i32);
+        let feet = (self.position.y + 0.5).floor() as i32;
+        for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            for dy in [0, 1, -1] {
+                let (bx, by, bz) = (x + dx, feet + dy, z + dz);
+                let clear = |y: i32| !Self::is_solid(world, bx, y, bz) && world.get_block(bx, y, bz) != Some(BlockType::Water);
+                if Self::is_solid(world, bx, by - 1, bz) && clear(by) && clear(by + 1) {
+                    return Point3::new(bx as f32 + 0.5, by as f32, bz as f32 + 0.5);
+                }
+            }
+        }
+        Point3::new(self.position.x, self.position.y + 0.5, self.position.z)
+    }
+
+    pub fn update(&mut self, dt: f32, controls: Option<&VehicleControls>, world: &World) {
+        match self.kind {
+            VehicleKind::Boat => self.update_boat(dt, controls, world),
+            VehicleKind::Minecart => self.update_minecart(dt, controls, world),
+        }
+    }
+
+    fn update_boat(&mut self, dt: f32, controls: Option<&VehicleControls>, world: &World) {
+        let surface = Self::water_surface(world, self.position)
+            .filter(|&surface| self.position.y < surface);
+        self.bob_time += dt;
+
+        let mut forward = self.forward();
+        if let Some(controls) = controls {
+            let turn = controls.turn_left as i32 - controls.turn_right as i32;
+            self.yaw += turn as f32 * BOAT_TURN_SPEED * dt;
+            forward = self.forward();
+
+            let paddle = if controls.forward {
+                1.0
+            } else if controls.backward {
+                -BOAT_REVERSE_FACTOR
+            } else {
+                0.0
+            };
+            let grip = if surface.is_some() { 1.0 } else { BOAT_LAND_GRIP };
+            self.velocity += forward * paddle * BOAT_PADDLE_ACCEL * grip * dt;
+        }
+
+        // Split motion into the way the bow points and sideways drift
+        let horizontal = Vector3::new(self.velocity.x, 0.0, self.velocity.z);
+        let mut along = horizontal.dot(forward);
+        let mut side = horizontal - forward * along;
+
+        if let Some(surface) = surface {
+            along *= (1.0 - BOAT_WATER_DRAG * dt).max(0.0);
+            along = along.clamp(-BOAT_MAX_SPEED * BOAT_REVERSE_FACTOR, BOAT_MAX_SPEED);
+            side *= (1.0 - BOAT_KEEL_DRAG * dt).max(0.0);
+            // Spring toward floating height so waves of falling water bob it rather than sink it
+            let target_y = surface - BOAT_DRAFT;
+            self.velocity.y += ((target_y - self.position.y) * BOAT_BUOYANCY - self.velocity.y * BOAT_BOB_DAMPING) * dt;
+            self.roll = (self.bob_time * 1.7).sin() * 3.0;
+        } else {
+            self.velocity.y -= VEHICLE_GRAVITY * dt;
+            if self.on_ground {
+                let speed = (along * along + side.magnitude2()).sqrt();
+                let slowed = (speed - VEHICLE_GROUND_FRICTION * dt).max(0.0);
+                let scale = if speed > 0.0 { slowed / speed } else { 0.0 };
+                along *= scale;
+                side *= scale;
+            }
+            self.roll *= (1.0 - 4.0 * dt).max(0.0);
+        }
+
+        let horizontal = forward * along + side;
+        self.velocity.x = horizontal.x;
+        self.velocity.z = horizontal.z;
+        self.move_with_collision(dt, world);
+    }
+
+    fn update_minecart(&mut self, dt: f32, controls: Option<&VehicleControls>, world: &World) {
+        let Some((_, shape)) = Self::rail_under(world, self.position) else {
+            // Off the track a minecart is just a heavy box
+            self.heading = None;
+            self.pitch = 0.0;
+            self.velocity.y -= VEHICLE_GRAVITY * dt;
+            if self.on_ground {
+                let horizontal = Vector3::new(self.velocity.x, 0.0, self.velocity.z);
+                let speed = horizontal.magnitude();
+                if speed > 0.0 {
+                    let scale = (speed - VEHICLE_GROUND_FRICTION * dt).max(0.0) / speed;
+                    self.velocity.x *= scale;
+                    self.velocity.z *= scale;
+                }
+            }
+            self.move_with_collision(dt, world);
+            return;
+        };
+
+        let mut heading = match self.heading {
+            Some(heading) => heading,
+            None => {
+                // Just landed on or was set down on the track: keep rolling the way it was going
+                let horizontal = Vector3::new(self.velocity.x, 0.0, self.velocity.z);
+                let [a, b] = shape.exits();
+                let candidates = [a, a.opposite(), b, b.opposite()];
+                let best = candidates.into_iter()
+                    .max_by(|&p, &q| {
+                        let score = |f: BlockFacing| Self::facing_vector(f).dot(horizontal) + Self::facing_vector(f).dot(self.forward()) * 0.01;
+                        score(p).total_cmp(&score(q))
+                    })
+                    .unwrap_or(a);
+                self.track_speed = Self::facing_vector(best).dot(horizontal).max(0.0);
+                best
+            }
+        };
+
+        let mut speed = self.track_speed;
+        if let Some(controls) = controls {
+            if controls.forward {
+                // From a standstill, push toward where the rider is looking
+                let look = Vector3::new(controls.look.x, 0.0, controls.look.z);
+                if speed < 0.5 && Self::facing_vector(heading).dot(look) < 0.0 {
+                    heading = heading.opposite();
+                }
+                if speed < MINECART_PUSH_SPEED {
+                    speed = (speed + MINECART_PUSH_ACCEL * dt).min(MINECART_PUSH_SPEED);
+                }
+            }
+            if controls.backward {
+                speed = (speed - MINECART_BRAKE_DECEL * dt).max(0.0);
+            }
+        }
+
+        if let RailShape::Ascending(up) = shape {
+            if heading == up {
+                speed -= MINECART_SLOPE_ACCEL * dt;
+            } else if heading == up.opposite() {
+                speed += MINECART_SLOPE_ACCEL * dt;
+            }
+        }
+        speed = if speed > 0.0 {
+            (speed - MINECART_FRICTION * dt).max(0.0)
+        } else {
+            (speed + MINECART_FRICTION * dt).min(0.0)
+        };
+        // Rolling back down a slope it couldn't climb
+        if speed < 0.0 {
+            heading = heading.opposite();
+            speed = -speed;
+        }
+
+        self.heading = Some(heading);
+        self.track_speed = speed.min(MINECART_MAX_SPEED);
+        self.follow_track(self.track_speed * dt, world);
+    }
+
+    /// Move a minecart along the rails, turning at the middle of curves and riding up and down slopes
+    fn follow_track(&mut self, distance: f32, world: &World) {
+        let mut remaining = distance;
+        // Bounded so a zero-length step can never spin forever
+        for _ in 0..64 {
+            let Some(heading) = self.heading else { return };
+            let Some(((rx, _, rz), shape)) = Self::rail_under(world, self.position) else {
+                // Ran off the end of the track: fly on with the speed it had
+                self.velocity = Self::facing_vector(heading) * self.track_speed;
+                self.heading = None;
+                return;
+            };
+
+            let exits = shape.exits();
+            let target = if exits.contains(&heading) {
+                heading
+            } else if exits.contains(&heading.opposite()) {
+                if exits[0] == heading.opposite() { exits[1] } else { exits[0] }
+            } else {
+                exits[0]
+            };
+
+            let center = (rx as f32 + 0.5, rz as f32 + 0.5);
+            let (dx, dz) = heading.offset();
+            let along = (self.position.x - center.0) * dx as f32 + (self.position.z - center.1) * dz as f32;
+
+            if target != heading && along + remaining >= 0.0 {
+                // Reached the middle of a curve: swing onto the other leg
+                remaining -= (-along).max(0.0);
+                self.position.x = center.0;
+                self.position.z = center.1;
+                self.heading = Some(target);
+                continue;
+            }
+            if remaining <= 0.0 {
+                break;
+            }
+
+            // Buffers, walls and anything else solid across the track stop the cart.
+            // Going uphill the track ahead rises a block per block, so look that much higher
+            let step = remaining.min(0.25);
+            let rise = if shape == RailShape::Ascending(heading) { 1.0 } else { 0.0 };
+            let reach = step + MINECART_HALF_WIDTH;
+            let front = self.position + Self::facing_vector(heading) * reach;
+            let front_y = (self.position.y + rise * reach + 0.6).floor() as i32;
+            if Self::is_solid(world, front.x.floor() as i32, front_y, front.z.floor() as i32) {
+                self.track_speed = 0.0;
+                break;
+            }
+
+            self.position.x += dx as f32 * step;
+            self.position.z += dz as f32 * step;
+            if dx != 0 {
+                self.position.z = center.1;
+            } else {
+                self.position.x = center.0;
+            }
+            remaining -= step;
+
+            let probe = Point3::new(self.position.x, self.position.y + rise * step, self.position.z);
+            if let Some(((nx, ny, nz), next_shape)) = Self::rail_under(world, probe) {
+                let fx = self.position.x - nx as f32;
+                let fz = self.position.z - nz as f32;
+                self.position.y = ny as f32 + next_shape.height_at(fx, fz);
+            }
+        }
+
+        let heading = self.heading.unwrap_or(BlockFacing::North);
+        self.yaw = Self::facing_yaw(heading);
+        self.velocity = Self::facing_vector(heading) * self.track_speed;
+        self.pitch = match Self::rail_under(world, self.position).map(|(_, shape)| shape) {
+            Some(RailShape::Ascending(up)) if up == heading => MINECART_SLOPE_PITCH,
+            Some(RailShape::Ascending(up)) if up == heading.opposite() => -MINECART_SLOPE_PITCH,
+            _ => 0.0,
+        };
+    }
+
+    /// Move by velocity, stopping against solid blocks and landing on the ground
+    fn move_with_collision(&mut self, dt: f32, world: &World) {
+        let half_width = self.kind.half_width();
+        let body_y = (self.position.y + 0.3).floor() as i32;
+
+        let new_x = self.position.x + self.velocity.x * dt;
+        let edge_x = (new_x + half_width * self.velocity.x.signum()).floor() as i32;
+        let blocked_x = [-1.0, 1.0].iter().any(|&side| {
+            Self::is_solid(world, edge_x, body_y, (self.position.z + side * half_width * 0.9).floor() as i32)
+        });
+        if blocked_x {
+            self.velocity.x = 0.0;
+        } else {
+            self.position.x = new_x;
+        }
+
+        let new_z = self.position.z + self.velocity.z * dt;
+        let edge_z = (new_z + half_width * self.velocity.z.signum()).floor() as i32;
+        let blocked_z = [-1.0, 1.0].iter().any(|&side| {
+            Self::is_solid(world, (self.position.x + side * half_width * 0.9).floor() as i32, body_y, edge_z)
+        });
+        if blocked_z {
+            self.velocity.z = 0.0;
+        } else {
+            self.position.z = new_z;
+        }
+
+        let new_y = self.position.y + self.velocity.y * dt;
+        self.on_ground = false;
+        if self.velocity.y <= 0.0 {
+            let floor_y = new_y.floor() as i32;
+            let landed = [-1.0, 1.0].iter().any(|&sx| [-1.0, 1.0].iter().any(|&sz| {
+                Self::is_solid(world,
+                    (self.position.x + sx * half_width * 0.8).floor() as i32,
+                    floor_y,
+                    (self.position.z + sz * half_width * 0.8).floor() as i32)
+            }));
+            if landed {
+                self.position.y = floor_y as f32 + 1.0;
+                self.velocity.y = 0.0;
+                self.on_ground = true;
+                return;
+            }
+        } else if Self::is_solid(world, self.position.x.floor() as i32, (new_y + 0.6).floor() as i32, self.position.z.floor() as i32) {
+            self.velocity.y = 0.0;
+            return;
+        }
+        self.position.y = new_y;
+    }
+}
+
 pub struct EntityManager {
     pub villagers: Vec<Villager>,
     pub dropped_items: Vec<DroppedItem>,
@@ -2110,6 +3523,7 @@ pub struct EntityManager {
     pub hostile_mobs: Vec<HostileMob>,
     pub projectiles: Vec<Projectile>,
     pub planes: Vec<Plane>,
+    pub vehicles: Vec<Vehicle>,
     pub missiles: Vec<Missile>,
     pub bombs: Vec<Bomb>,
     next_id: u32,
@@ -2119,6 +3533,10 @@ pub struct EntityManager {
     animal_spawn_timer: f32,
     hostile_spawn_timer: f32,
     plane_spawn_timer: f32,
+    plane_engine_sound_timer: f32,
+    sound_events: Vec<(EntitySound, Point3<f32>)>,
+    door_requests: Vec<((i32, i32, i32), bool)>,
+    creeper_explosions: Vec<(Point3<f32>, f32)>,
 }
 
 impl EntityManager {
@@ -2130,6 +3548,7 @@ impl EntityManager {
             hostile_mobs: Vec::new(),
             projectiles: Vec::new(),
             planes: Vec::new(),
+            vehicles: Vec::new(),
             missiles: Vec::new(),
             bombs: Vec::new(),
             next_id: 0,
@@ -2139,14 +3558,41 @@ impl EntityManager {
             animal_spawn_timer: 0.0,
             hostile_spawn_timer: 0.0,
             plane_spawn_timer: 0.0,
+            plane_engine_sound_timer: 0.0,
+            sound_events: Vec::new(),
+            door_requests: Vec::new(),
+            creeper_explosions: Vec::new(),
         }
     }
 
+    /// Sounds entities made since the last call, with where they came from
+    pub fn take_sound_events(&mut self) -> Vec<(EntitySound, Point3<f32>)> {
+        std::mem::take(&mut self.sound_events)
+    }
+
+    /// Doors villagers want opened (true) or shut (false) since the last call
+    pub fn take_door_requests(&mut self) -> Vec<((i32, i32, i32), bool)> {
+        std::mem::take(&mut self.door_requests)
+    }
+
+    /// Creepers that blew up since the last call: (position, blast radius)
+    pub fn take_creeper_explosions(&mut self) -> Vec<(Point3<f32>, f32)> {
+        std::mem::take(&mut self.creeper_explosions)
+    }
+
     /// Spawn a projectile (arrow)
-    pub fn spawn_projectile(&mut self, position: Point3<f32>, direction: Vector3<f32>) {
+    pub fn spawn_projectile(&mut self, position: Point3<f32>, direction: Vector3<f32>, shooter: ArrowShooter) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.projectiles.push(Projectile::new(id, position, direction, shooter));
+    }
+
+    /// Fire an arrow from the player's bow
+    pub fn shoot_arrow(&mut self, position: Point3<f32>, direction: Vector3<f32>, draw_strength: f32) {
         let id = self.next_id;
         self.next_id += 1;
-        self.projectiles.push(Projectile::new(id, position, direction));
+        self.projectiles.push(Projectile::from_bow(id, position, direction, draw_strength));
+        self.sound_events.push((EntitySound::ArrowShoot, position));
     }
 
     /// Spawn a dropped item at a position
@@ -2175,6 +3621,19 @@ impl EntityManager {
         let vertical_range_below = 2.0;  // Items at feet level
         let vertical_range_above = 1.0;  // Items slightly above eye level
 
+        // Arrows stuck in blocks can be pulled out and reused
+        self.projectiles.retain(|proj| {
+            let dx = proj.position.x - player_pos.x;
+            let dy = proj.position.y - player_pos.y;
+            let dz = proj.position.z - player_pos.z;
+            if proj.stuck && dx * dx + dz * dz < horizontal_dist_sq && dy > -vertical_range_below && dy < vertical_range_above {
+                collected.push(ItemStack::Block(BlockType::Arrow, 1));
+                false
+            } else {
+                true
+            }
+        });
+
         self.dropped_items.retain(|item| {
             let dx = item.position.x - player_pos.x;
             let dy = item.position.y - player_pos.y;
@@ -2209,6 +3668,9 @@ impl EntityManager {
             0.0
         };
 
+        // Villagers run from any hostile mob that gets close
+        let mob_positions: Vec<Point3<f32>> = self.hostile_mobs.iter().map(|m| m.position).collect();
+
         // Update each villager
         for villager in &mut self.villagers {
             // Always update physics
@@ -2219,7 +3681,13 @@ impl EntityManager {
                 let dist_sq = (villager.position.x - player_pos.x).powi(2)
                     + (villager.position.z - player_pos.z).powi(2);
                 if dist_sq < 100.0 * 100.0 { // Within 100 blocks
-                    villager.update_ai(ai_dt, world, &mut self.rng);
+                    let threat = mob_positions.iter()
+                        .filter(|p| (**p - villager.position).magnitude() < VILLAGER_FLEE_RANGE)
+                        .min_by(|a, b| (**a - villager.position).magnitude().total_cmp(&(**b - villager.position).magnitude()))
+                        .copied();
+                    if let Some(request) = villager.update_ai(ai_dt, world, time_of_day, threat, &mut self.rng) {
+                        self.door_requests.push(request);
+                    }
                 }
             }
         }
@@ -2227,14 +3695,25 @@ impl EntityManager {
         // Update dropped items
         self.dropped_items.retain_mut(|item| item.update(dt, world));
 
+        // Pets, leashed animals and animals in love decide where to go before the wandering AI runs
+        if update_ai {
+            self.update_animal_behaviors(player_pos);
+        }
+
         // Update animals
         for animal in &mut self.animals {
             animal.update(dt, world);
 
+            animal.idle_sound_timer -= dt;
+            if animal.idle_sound_timer <= 0.0 {
+                animal.idle_sound_timer = self.rng.gen_range(IDLE_SOUND_INTERVAL.0..IDLE_SOUND_INTERVAL.1);
+                self.sound_events.push((EntitySound::AnimalIdle(animal.animal_type), animal.position));
+            }
+
             if update_ai {
                 let dist_sq = (animal.position.x - player_pos.x).powi(2)
                     + (animal.position.z - player_pos.z).powi(2);
-                if dist_sq < 80.0 * 80.0 {
+                if dist_sq < 80.0 * 80.0 && !animal.directed {
                     animal.update_ai(ai_dt, world, &mut self.rng);
                 }
             }
@@ -2256,41 +3735,100 @@ impl EntityManager {
             self.cleanup_distant_animals(player_pos);
         }
 
+        // Zombies go after villagers caught outdoors when the player isn't around
+        let villager_targets: Vec<(u32, Point3<f32>)> = self.villagers.iter()
+            .filter(|v| !v.sheltered)
+            .map(|v| (v.id, v.position))
+            .collect();
+        let mut villager_attacks: Vec<(u32, Point3<f32>, f32)> = Vec::new();
+
         // Update hostile mobs - collect actions to process
         let mut mob_actions: Vec<(u32, Point3<f32>, MobAction)> = Vec::new();
         for mob in &mut self.hostile_mobs {
             mob.update(dt, world);
 
+            mob.idle_sound_timer -= dt;
+            if mob.idle_sound_timer <= 0.0 {
+                mob.idle_sound_timer = self.rng.gen_range(IDLE_SOUND_INTERVAL.0..IDLE_SOUND_INTERVAL.1);
+                self.sound_events.push((EntitySound::MobIdle(mob.mob_type), mob.position));
+            }
+
             if update_ai {
                 let dist_sq = (mob.position.x - player_pos.x).powi(2)
                     + (mob.position.z - player_pos.z).powi(2);
                 if dist_sq < 100.0 * 100.0 {
-                    if let Some(action) = mob.update_ai(ai_dt, world, player_pos, time_of_day, &mut self.rng) {
+                    let was_fusing = mob.state == HostileMobState::Fusing;
+                    let villager_target = if mob.mob_type == HostileMobType::Zombie
+                        && (mob.position - player_pos).magnitude() >= mob.mob_type.detection_range()
+                    {
+                        villager_targets.iter()
+                            .map(|(id, pos)| (*id, *pos, (*pos - mob.position).magnitude()))
+                            .filter(|(_, _, dist)| *dist < mob.mob_type.detection_range())
+                            .min_by(|a, b| a.2.total_cmp(&b.2))
+                    } else {
+                        None
+                    };
+                    let target = villager_target.map_or(player_pos, |(_, pos, _)| pos);
+                    if let Some(action) = mob.update_ai(ai_dt, world, target, time_of_day, &mut self.rng) {
                         mob_actions.push((mob.id, mob.position, action));
                     }
+                    if let Some((villager_id, _, dist)) = villager_target {
+                        if dist < mob.mob_type.attack_range() && mob.can_attack() {
+                            let damage = mob.perform_attack();
+                            self.sound_events.push((EntitySound::MobAttack(mob.mob_type), mob.position));
+                            villager_attacks.push((villager_id, mob.position, damage));
+                        }
+                    }
+                    if !was_fusing && mob.state == HostileMobState::Fusing {
+                        self.sound_events.push((EntitySound::CreeperFuse, mob.position));
+                    }
                 }
             }
         }
 
+        // Villagers hit by zombies get knocked back, and die at zero health
+        for (villager_id, attacker_pos, damage) in villager_attacks {
+            if let Some(villager) = self.villagers.iter_mut().find(|v| v.id == villager_id) {
+                let knockback = Vector3::new(
+                    (villager.position.x - attacker_pos.x).signum() * 4.0,
+                    2.0,
+                    (villager.position.z - attacker_pos.z).signum() * 4.0,
+                );
+                villager.take_damage(damage, knockback);
+            }
+        }
+        self.villagers.retain(|v| v.health > 0.0);
+
         // Process mob actions
         for (mob_id, pos, action) in mob_actions {
             match action {
                 MobAction::ShootArrow(direction) => {
                     // Spawn arrow at mob's eye level
                     let arrow_pos = Point3::new(pos.x, pos.y - 0.5, pos.z);
-                    self.spawn_projectile(arrow_pos, direction);
+                    self.spawn_projectile(arrow_pos, direction, ArrowShooter::Mob(mob_id));
+                    self.sound_events.push((EntitySound::ArrowShoot, arrow_pos));
                 }
                 MobAction::Explode => {
-                    // Mark creeper for removal (explosion handled in main.rs)
+                    // Remove the creeper and queue its blast (explosion handled in main.rs)
                     if let Some(mob) = self.hostile_mobs.iter_mut().find(|m| m.id == mob_id) {
                         mob.health = 0.0;  // Kill the creeper
+                        self.creeper_explosions.push((mob.position, mob.explosion_radius()));
                     }
                 }
             }
         }
 
-        // Update projectiles
-        self.projectiles.retain_mut(|proj| proj.update(dt, world));
+        // Update projectiles (play a thunk when an arrow lodges in a block)
+        let sound_events = &mut self.sound_events;
+        self.projectiles.retain_mut(|proj| {
+            let was_stuck = proj.stuck;
+            let alive = proj.update(dt, world);
+            if proj.stuck && !was_stuck {
+                sound_events.push((EntitySound::ArrowHit, proj.position));
+            }
+            alive
+        });
+        self.check_projectile_entity_hits();
 
         // Remove dead hostile mobs
         self.hostile_mobs.retain(|mob| !mob.is_dead());
@@ -2303,6 +3841,15 @@ impl EntityManager {
             self.cleanup_distant_hostile_mobs(player_pos);
         }
 
+        // Engine drone from every plane in the air
+        self.plane_engine_sound_timer -= dt;
+        if self.plane_engine_sound_timer <= 0.0 {
+            self.plane_engine_sound_timer = PLANE_ENGINE_SOUND_INTERVAL;
+            for plane in self.planes.iter().filter(|p| p.state == PlaneState::Flying) {
+                self.sound_events.push((EntitySound::PlaneEngine, plane.position));
+            }
+        }
+
         // Plane cleanup only (planes controlled by player in main.rs)
         self.plane_spawn_timer -= dt;
         if self.plane_spawn_timer <= 0.0 {
@@ -2342,6 +3889,17 @@ impl EntityManager {
                 let world_z = chunk_z * 16 + 8;
 
                 if self.is_village_location(world, world_x as f64, world_z as f64) {
+                    // Buildings near the chunk give villagers a job, a house and somewhere to meet
+                    let mut sites = VillageSites::default();
+                    for (sx, sz) in [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)] {
+                        let found = world.find_village_sites(chunk_x + sx, chunk_z + sz);
+                        sites.workstations.extend(found.workstations);
+                        sites.doors.extend(found.doors);
+                        sites.meeting_points.extend(found.meeting_points);
+                    }
+                    let claimed: Vec<(i32, i32, i32)> = self.villagers.iter().filter_map(|v| v.workstation).collect();
+                    sites.workstations.retain(|(pos, _)| !claimed.contains(pos));
+
                     // Spawn 1-2 villagers per chunk, spread out across the chunk
                     let count = self.rng.gen_range(1..=2);
                     for _ in 0..count {
@@ -2353,7 +3911,21 @@ impl EntityManager {
 
                         // Find spawn position at this random location
                         if let Some(spawn_pos) = self.find_spawn_position(world, try_x, try_z) {
-                            let villager = Villager::new(self.next_id, spawn_pos, chunk_key);
+                            let mut villager = Villager::new(self.next_id, spawn_pos, chunk_key);
+                            if let Some((pos, block)) = sites.workstations.pop() {
+                                if let Some(profession) = VillagerProfession::from_workstation(block) {
+                                    villager.assign_workstation(pos, profession);
+                                }
+                            }
+                            let distance_to = |&(x, _, z): &(i32, i32, i32)| {
+                                (x as f32 + 0.5 - spawn_pos.x).powi(2) + (z as f32 + 0.5 - spawn_pos.z).powi(2)
+                            };
+                            villager.home = sites.doors.iter()
+                                .min_by(|a, b| distance_to(a).total_cmp(&distance_to(b)))
+                                .map(|&door| VillagerHome::from_door(world, door));
+                            villager.meeting_point = sites.meeting_points.iter()
+                                .min_by(|a, b| distance_to(a).total_cmp(&distance_to(b)))
+                                .copied();
                             self.next_id += 1;
                             self.villagers.push(villager);
 
@@ -2409,6 +3981,24 @@ impl EntityManager {
         &self.villagers
     }
 
+    pub fn get_villager(&self, id: u32) -> Option<&Villager> {
+        self.villagers.iter().find(|v| v.id == id)
+    }
+
+    pub fn get_villager_mut(&mut self, id: u32) -> Option<&mut Villager> {
+        self.villagers.iter_mut().find(|v| v.id == id)
+    }
+
+    /// Closest villager with something to sell, for opening the trading screen
+    pub fn get_closest_trader(&self, pos: Point3<f32>, max_range: f32) -> Option<u32> {
+        self.villagers.iter()
+            .filter(|v| !v.trades.is_empty())
+            .map(|v| (v.id, (v.position - pos).magnitude()))
+            .filter(|(_, dist)| *dist < max_range)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id)
+    }
+
     pub fn get_dropped_items(&self) -> &[DroppedItem] {
         &self.dropped_items
     }
@@ -2715,9 +4305,140 @@ impl EntityManager {
             let dist_sq = (a.position.x - player_pos.x).powi(2)
                 + (a.position.z - player_pos.z).powi(2);
             dist_sq < 80.0 * 80.0 // Keep within 80 blocks
+                || a.tamed || a.leashed  // Pets and leashed animals never despawn
         });
     }
 
+    /// Use the held item (or an empty hand) on an animal - see AnimalInteraction for what to do with the item
+    pub fn interact_with_animal(&mut self, animal_id: u32, item: Option<BlockType>) -> AnimalInteraction {
+        let Some(animal) = self.animals.iter_mut().find(|a| a.id == animal_id) else {
+            return AnimalInteraction::None;
+        };
+        let result = animal.interact(item, &mut self.rng);
+        if matches!(result, AnimalInteraction::Fed | AnimalInteraction::Tamed) {
+            self.sound_events.push((EntitySound::AnimalIdle(animal.animal_type), animal.position));
+        }
+        result
+    }
+
+    /// Breeding, pets following and defending the player, and leash pulls
+    fn update_animal_behaviors(&mut self, player_pos: Point3<f32>) {
+        // Mobs going for the player within range of their pets
+        let threats: Vec<(u32, Point3<f32>)> = self.hostile_mobs.iter()
+            .filter(|mob| matches!(mob.state, HostileMobState::Chasing | HostileMobState::Attacking | HostileMobState::Fusing))
+            .filter(|mob| (mob.position - player_pos).magnitude() < PET_DEFEND_RANGE)
+            .map(|mob| (mob.id, mob.position))
+            .collect();
+
+        let mut pet_attacks: Vec<(u32, f32, Vector3<f32>)> = Vec::new();
+        let mut snapped_leads: Vec<Point3<f32>> = Vec::new();
+        let mut births: Vec<(AnimalType, Point3<f32>)> = Vec::new();
+
+        for i in 0..self.animals.len() {
+            let animal = &self.animals[i];
+            let to_player = player_pos - animal.position;
+            let player_dist = Vector3::new(to_player.x, 0.0, to_player.z).magnitude();
+
+            // Leashes: pulled along past the lead's length, snapped if dragged too far
+            if animal.leashed {
+                let animal = &mut self.animals[i];
+                if player_dist > LEASH_BREAK_DISTANCE {
+                    animal.leashed = false;
+                    snapped_leads.push(animal.position);
+                } else if player_dist > LEASH_LENGTH {
+                    animal.steer_toward(player_pos, player_dist > LEASH_LENGTH * 2.0);
+                    continue;
+                } else {
+                    animal.hold_still();
+                    continue;
+                }
+            }
+
+            let animal = &self.animals[i];
+            if animal.tamed {
+                let animal = &mut self.animals[i];
+                if animal.sitting {
+                    animal.hold_still();
+                    continue;
+                }
+
+                // Defend the player: go for the closest threat
+                let target = threats.iter()
+                    .map(|&(id, pos)| (id, pos, (pos - animal.position).magnitude()))
+                    .min_by(|a, b| a.2.total_cmp(&b.2));
+                if let Some((mob_id, mob_pos, dist)) = target {
+                    animal.steer_toward(mob_pos, true);
+                    if dist < PET_ATTACK_RANGE && animal.attack_cooldown <= 0.0 {
+                        animal.attack_cooldown = PET_ATTACK_COOLDOWN;
+                        let knockback = (mob_pos - animal.position).normalize() * 4.0;
+                        pet_attacks.push((mob_id, animal.animal_type.pet_damage(), knockback));
+                    }
+                    continue;
+                }
+
+                if player_dist > PET_TELEPORT_DISTANCE {
+                    animal.position = Point3::new(player_pos.x, player_pos.y, player_pos.z);
+                    animal.velocity = Vector3::new(0.0, 0.0, 0.0);
+                } else if player_dist > PET_FOLLOW_DISTANCE {
+                    animal.steer_toward(player_pos, player_dist > PET_FOLLOW_DISTANCE * 2.0);
+                    continue;
+                }
+            }
+
+            // In love: find the nearest willing mate of the same kind
+            let animal = &self.animals[i];
+            if animal.love_timer > 0.0 {
+                let mate = self.animals.iter().enumerate()
+                    .filter(|&(j, other)| j != i && other.animal_type == animal.animal_type && other.love_timer > 0.0)
+                    .map(|(j, other)| (j, (other.position - animal.position).magnitude()))
+                    .filter(|&(_, dist)| dist < BREED_SEARCH_RANGE)
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+                if let Some((j, dist)) = mate {
+                    let mate_pos = self.animals[j].position;
+                    if dist < BREED_DISTANCE {
+                        let animal_type = animal.animal_type;
+                        let birth_pos = Point3::new(
+                            (animal.position.x + mate_pos.x) / 2.0,
+                            animal.position.y.max(mate_pos.y),
+                            (animal.position.z + mate_pos.z) / 2.0,
+                        );
+                        for parent in [i, j] {
+                            self.animals[parent].love_timer = 0.0;
+                            self.animals[parent].breed_cooldown = BREED_COOLDOWN;
+                        }
+                        births.push((animal_type, birth_pos));
+                    } else {
+                        self.animals[i].steer_toward(mate_pos, false);
+                        continue;
+                    }
+                }
+            }
+
+            // Nothing to do - back to wandering
+            let animal = &mut self.animals[i];
+            if animal.directed {
+                animal.hold_still();
+                animal.directed = false;
+            }
+        }
+
+        for (mob_id, damage, knockback) in pet_attacks {
+            self.damage_hostile_mob(mob_id, damage, Some(knockback));
+        }
+        for position in snapped_leads {
+            self.spawn_dropped_item(position, BlockType::Lead);
+        }
+        for (animal_type, position) in births {
+            if self.animals.len() >= MAX_ANIMALS {
+                break;
+            }
+            let id = self.next_id;
+            self.next_id += 1;
+            self.animals.push(Animal::new_baby(id, animal_type, position));
+            self.sound_events.push((EntitySound::AnimalIdle(animal_type), position));
+        }
+    }
+
     fn try_spawn_hostile_mobs(&mut self, world: &World, player_pos: Point3<f32>) {
         if self.hostile_mobs.len() >= MAX_HOSTILE_MOBS {
             return;
@@ -2842,8 +4563,8 @@ impl EntityManager {
         });
     }
 
-    /// Check for hostile mob attacks on player, returns list of (damage, knockback direction)
-    pub fn check_hostile_attacks(&mut self, player_pos: Point3<f32>) -> Vec<(f32, Vector3<f32>)> {
+    /// Melee hits landing on the player: (damage, knockback, effect left by the hit)
+    pub fn check_hostile_attacks(&mut self, player_pos: Point3<f32>) -> Vec<(f32, Vector3<f32>, Option<TimedEffect>)> {
         let mut attacks = Vec::new();
 
         for mob in &mut self.hostile_mobs {
@@ -2854,12 +4575,13 @@ impl EntityManager {
 
                 if distance < mob.mob_type.attack_range() {
                     let damage = mob.perform_attack();
+                    self.sound_events.push((EntitySound::MobAttack(mob.mob_type), mob.position));
                     let knockback_dir = Vector3::new(
                         (player_pos.x - mob.position.x).signum() * 8.0,
                         2.0,
                         (player_pos.z - mob.position.z).signum() * 8.0,
                     );
-                    attacks.push((damage, knockback_dir));
+                    attacks.push((damage, knockback_dir, mob.mob_type.attack_effect()));
                 }
             }
         }
@@ -2871,6 +4593,8 @@ impl EntityManager {
     pub fn damage_hostile_mob(&mut self, mob_id: u32, damage: f32, knockback: Option<Vector3<f32>>) -> bool {
         if let Some(mob) = self.hostile_mobs.iter_mut().find(|m| m.id == mob_id) {
             let survived = mob.take_damage(damage, knockback);
+            let sound = if survived { EntitySound::MobHurt(mob.mob_type) } else { EntitySound::MobDeath(mob.mob_type) };
+            self.sound_events.push((sound, mob.position));
             !survived // Return true if mob died
         } else {
             false
@@ -2900,30 +4624,88 @@ impl EntityManager {
         &self.hostile_mobs
     }
 
+    /// Whether any nearby hostile mob is chasing, attacking or about to explode on the player
+    pub fn is_player_in_combat(&self, player_pos: Point3<f32>) -> bool {
+        self.hostile_mobs.iter().any(|mob| {
+            matches!(mob.state, HostileMobState::Chasing | HostileMobState::Attacking | HostileMobState::Fusing)
+                && (mob.position - player_pos).magnitude2() < COMBAT_RANGE * COMBAT_RANGE
+        })
+    }
+
     pub fn get_projectiles(&self) -> &[Projectile] {
         &self.projectiles
     }
 
-    /// Check projectile collisions with player, returns list of damage amounts
-    pub fn check_projectile_player_collisions(&mut self, player_pos: Point3<f32>) -> Vec<f32> {
-        let mut damages = Vec::new();
-        let player_radius = 0.4;  // Player hitbox radius
+    pub fn check_projectile_player_collisions(&mut self, player_pos: Point3<f32>) -> Vec<(f32, Vector3<f32>)> {
+        let mut hits = Vec::new();
+        // Player hitbox: 0.6 wide, from the feet (1.6 below the eyes) to just above the head
+        let player_top = Point3::new(player_pos.x, player_pos.y + 0.2, player_pos.z);
 
         self.projectiles.retain(|proj| {
-            let dx = proj.position.x - player_pos.x;
-            let dy = proj.position.y - player_pos.y;
-            let dz = proj.position.z - player_pos.z;
-            let dist_sq = dx * dx + dy * dy + dz * dz;
-
-            if dist_sq < player_radius * player_radius {
-                damages.push(proj.damage);
+            if proj.shooter != ArrowShooter::Player && proj.hits_body(player_top, 0.6, 1.8) {
+                hits.push((proj.damage, proj.knockback()));
+                self.sound_events.push((EntitySound::ArrowHit, proj.position));
                 false  // Remove projectile
             } else {
                 true   // Keep projectile
             }
         });
 
-        damages
+        hits
+    }
+
+    /// Flying arrows hit any mob, animal or villager in their path (except whoever shot them)
+    fn check_projectile_entity_hits(&mut self) {
+        let mut index = 0;
+        while index < self.projectiles.len() {
+            let proj = &self.projectiles[index];
+            let mob_hit = self.hostile_mobs.iter()
+                .find(|m| proj.shooter != ArrowShooter::Mob(m.id) && !m.is_dead() && {
+                    let (width, height) = m.mob_type.dimensions();
+                    proj.hits_body(m.position, width, height)
+                })
+                .map(|m| m.id);
+            let animal_hit = self.animals.iter()
+                .find(|a| {
+                    let (width, height) = a.dimensions();
+                    proj.hits_body(a.position, width, height)
+                })
+                .map(|a| a.id);
+            let villager_hit = self.villagers.iter()
+                .find(|v| proj.hits_body(v.position, VILLAGER_WIDTH, VILLAGER_HEIGHT))
+                .map(|v| v.id);
+            // Planes sit with their position at the bottom of the fuselage
+            let plane_hit = self.planes.iter()
+                .find(|p| !p.is_crashed() && proj.hits_body(p.position + Vector3::new(0.0, PLANE_HEIGHT, 0.0), PLANE_LENGTH, PLANE_HEIGHT))
+                .map(|p| p.id);
+
+            if mob_hit.is_none() && animal_hit.is_none() && villager_hit.is_none() && plane_hit.is_none() {
+                index += 1;
+                continue;
+            }
+
+            let proj = self.projectiles.remove(index);
+            let knockback = proj.knockback();
+            self.sound_events.push((EntitySound::ArrowHit, proj.position));
+            if let Some(mob_id) = mob_hit {
+                self.damage_hostile_mob(mob_id, proj.damage, Some(knockback));
+            } else if let Some(animal_id) = animal_hit {
+                if let Some((death_pos, meat_type, qty)) = self.damage_animal(animal_id, proj.damage, Some(knockback)) {
+                    for _ in 0..qty {
+                        self.spawn_dropped_item(death_pos, meat_type);
+                    }
+                }
+            } else if let Some(villager_id) = villager_hit {
+                if let Some(villager) = self.villagers.iter_mut().find(|v| v.id == villager_id) {
+                    villager.take_damage(proj.damage, knockback);
+                }
+            } else if let Some(plane_id) = plane_hit {
+                if let Some(plane) = self.get_plane_mut(plane_id) {
+                    plane.take_damage(proj.damage * PLANE_ARROW_DAMAGE_MULTIPLIER);
+                }
+            }
+        }
+        self.villagers.retain(|v| v.health > 0.0);
     }
 
     /// Get the closest animal to a position within range, returns (animal_id, distance)
@@ -2949,6 +4731,9 @@ impl EntityManager {
     pub fn damage_animal(&mut self, animal_id: u32, damage: f32, knockback: Option<Vector3<f32>>) -> Option<(Point3<f32>, BlockType, u32)> {
         if let Some(index) = self.animals.iter().position(|a| a.id == animal_id) {
             let survived = self.animals[index].take_damage(damage, knockback);
+            let animal = &self.animals[index];
+            let sound = if survived { EntitySound::AnimalHurt(animal.animal_type) } else { EntitySound::AnimalDeath(animal.animal_type) };
+            self.sound_events.push((sound, animal.position));
             if !survived {
                 // Animal died - get meat drop before removing
                 let animal = &self.animals[index];
@@ -3013,6 +4798,162 @@ impl EntityManager {
         self.planes.retain(|p| !p.is_crashed());
     }
 
+    // ========================================================================
+    // Boat and minecart methods
+    // ========================================================================
+
+    /// Set a boat down on the water (or dry land) or a minecart onto a rail along the player's aim.
+    /// Returns true if the vehicle was placed
+    pub fn place_vehicle(&mut self, world: &World, kind: VehicleKind, eye: Point3<f32>, look: Vector3<f32>) -> bool {
+        if self.vehicles.len() >= MAX_VEHICLES {
+            return false;
+        }
+        let yaw = (-look.x).atan2(-look.z).to_degrees();
+
+        let mut t = 0.0;
+        while t < 5.0 {
+            let point = eye + look * t;
+            let (bx, by, bz) = (point.x.floor() as i32, point.y.floor() as i32, point.z.floor() as i32);
+            let spot = match (kind, world.get_block(bx, by, bz)) {
+                (VehicleKind::Boat, Some(BlockType::Water)) => Vehicle::water_surface(world, point)
+                    .map(|surface| Point3::new(point.x, surface - BOAT_DRAFT, point.z)),
+                (VehicleKind::Minecart, Some(BlockType::Rail)) => {
+                    Some(Point3::new(bx as f32 + 0.5, by as f32, bz as f32 + 0.5))
+                }
+                (VehicleKind::Boat, Some(block))
+                    if block.collision_shape() != CollisionShape::None && !Vehicle::is_solid(world, bx, by + 1, bz) => {
+                    Some(Point3::new(point.x, by as f32 + 1.0, point.z))
+                }
+                (_, Some(block)) if block.collision_shape() != CollisionShape::None => return false,
+                _ => None,
+            };
+            if let Some(position) = spot {
+                let id = self.next_id;
+                self.next_id += 1;
+                self.vehicles.push(Vehicle::new(id, kind, position, yaw));
+                return true;
+            }
+            t += 0.1;
+        }
+        false
+    }
+
+    pub fn get_vehicles(&self) -> &[Vehicle] {
+        &self.vehicles
+    }
+
+    pub fn get_vehicle(&self, id: u32) -> Option<&Vehicle> {
+        self.vehicles.iter().find(|v| v.id == id)
+    }
+
+    /// The closest boat or minecart to climb into
+    pub fn find_nearby_vehicle(&self, pos: Point3<f32>, radius: f32) -> Option<(u32, VehicleKind)> {
+        self.vehicles.iter()
+            .map(|v| (v, (v.seat_position() - pos).magnitude()))
+            .filter(|&(_, dist)| dist < radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(v, _)| (v.id, v.kind))
+    }
+
+    /// Knock loose the closest empty vehicle, returning where it was and the item it drops
+    pub fn break_nearby_vehicle(&mut self, pos: Point3<f32>, radius: f32, ridden: Option<u32>) -> Option<(Point3<f32>, BlockType)> {
+        let (id, _) = self.vehicles.iter()
+            .filter(|v| Some(v.id) != ridden)
+            .map(|v| (v.id, (v.seat_position() - pos).magnitude()))
+            .filter(|&(_, dist)| dist < radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        let index = self.vehicles.iter().position(|v| v.id == id)?;
+        let vehicle = self.vehicles.remove(index);
+        Some((vehicle.seat_position(), vehicle.kind.item()))
+    }
+
+    /// Paddle or push the ridden vehicle; empty boats drift and empty minecarts roll downhill
+    pub fn update_vehicles(&mut self, dt: f32, world: &World, rider: Option<(u32, VehicleControls)>) {
+        for vehicle in &mut self.vehicles {
+            let chunk_key = ((vehicle.position.x / 16.0).floor() as i32, (vehicle.position.z / 16.0).floor() as i32);
+            if !world.chunks.contains_key(&chunk_key) {
+                continue;
+            }
+            let controls = rider.as_ref()
+                .filter(|(id, _)| *id == vehicle.id)
+                .map(|(_, controls)| controls);
+            vehicle.update(dt, controls, world);
+        }
+    }
+
+    /// Damage planes caught in an explosion, falling off to nothing at twice the blast radius
+    pub fn damage_planes_in_explosion(&mut self, center: Point3<f32>, radius: f32) {
+        let reach = radius * 2.0;
+        for plane in &mut self.planes {
+            let dist = (plane.position - center).magnitude();
+            if dist < reach {
+                plane.take_damage(PLANE_EXPLOSION_DAMAGE * (1.0 - dist / reach));
+            }
+        }
+    }
+
+    /// Lightning hit the ground at `strike`: mobs, animals and villagers close by are hurt, thrown back
+    /// and set alight, creepers become charged and pigs turn into zombies
+    pub fn strike_lightning(&mut self, strike: Point3<f32>) {
+        let struck_mobs: Vec<(u32, Vector3<f32>)> = self.hostile_mobs.iter()
+            .filter(|m| !m.is_dead() && within_lightning_strike(strike, m.position, m.mob_type.dimensions().1))
+            .map(|m| (m.id, lightning_knockback(strike, m.position)))
+            .collect();
+        for (mob_id, knockback) in struck_mobs {
+            if let Some(mob) = self.hostile_mobs.iter_mut().find(|m| m.id == mob_id) {
+                mob.charged |= mob.mob_type == HostileMobType::Creeper;
+                mob.effects.apply(StatusEffectType::Fire, LIGHTNING_BURN_TIME, 0);
+            }
+            self.damage_hostile_mob(mob_id, LIGHTNING_DAMAGE, Some(knockback));
+        }
+
+        let struck_animals: Vec<(u32, Vector3<f32>)> = self.animals.iter()
+            .filter(|a| within_lightning_strike(strike, a.position, a.dimensions().1))
+            .map(|a| (a.id, lightning_knockback(strike, a.position)))
+            .collect();
+        for (animal_id, knockback) in struck_animals {
+            let Some(index) = self.animals.iter().position(|a| a.id == animal_id) else {
+                continue;
+            };
+            if self.animals[index].animal_type == AnimalType::Pig {
+                // Stand the zombie on the spot the pig's feet were
+                let pig = self.animals.remove(index);
+                if pig.leashed {
+                    self.spawn_dropped_item(pig.position, BlockType::Lead);
+                }
+                let top = pig.position + Vector3::new(0.0, ZOMBIE_HEIGHT - pig.dimensions().1, 0.0);
+                let mut zombie = HostileMob::new(self.next_id, HostileMobType::Zombie, top);
+                self.next_id += 1;
+                zombie.effects.apply(StatusEffectType::Fire, LIGHTNING_BURN_TIME, 0);
+                self.hostile_mobs.push(zombie);
+                self.sound_events.push((EntitySound::AnimalDeath(AnimalType::Pig), pig.position));
+            } else if let Some((death_pos, meat_type, qty)) = self.damage_animal(animal_id, LIGHTNING_DAMAGE, Some(knockback)) {
+                for _ in 0..qty {
+                    self.spawn_dropped_item(death_pos, meat_type);
+                }
+            }
+        }
+
+        for villager in self.villagers.iter_mut() {
+            if within_lightning_strike(strike, villager.position, VILLAGER_HEIGHT) {
+                villager.take_damage(LIGHTNING_DAMAGE, lightning_knockback(strike, villager.position));
+            }
+        }
+        self.villagers.retain(|v| v.health > 0.0);
+    }
+
+    /// Refuel (Coal) or repair (IronIngot) the nearest grounded plane, returns true if the item was used
+    pub fn service_nearby_plane(&mut self, pos: Point3<f32>, radius: f32, item: BlockType) -> bool {
+        let Some(plane_id) = self.find_nearby_plane(pos, radius) else {
+            return false;
+        };
+        match (item, self.get_plane_mut(plane_id)) {
+            (BlockType::Coal, Some(plane)) => plane.refuel(),
+            (BlockType::IronIngot, Some(plane)) => plane.repair(),
+            _ => false,
+        }
+    }
+
     /// Fire a missile from a plane
     pub fn fire_missile(&mut self, plane: &Plane) {
         let yaw_rad = plane.yaw.to_radians();
@@ -3038,6 +4979,7 @@ impl EntityManager {
         let id = self.next_id;
         self.next_id += 1;
         self.missiles.push(Missile::new(id, spawn_pos, direction));
+        self.sound_events.push((EntitySound::MissileLaunch, spawn_pos));
     }
 
     /// Update all missiles, returns list of explosion positions
@@ -3070,17 +5012,16 @@ impl EntityManager {
         );
 
         // Bomb inherits plane's horizontal velocity (so it travels with the plane initially)
-        let yaw_rad = plane.yaw.to_radians();
-        let plane_speed = PLANE_SPEED;  // Use the plane's forward speed
         let initial_velocity = Vector3::new(
-            -yaw_rad.sin() * plane_speed * 0.5,  // Some forward momentum
+            plane.velocity.x * 0.5,  // Some forward momentum
             0.0,  // No initial vertical velocity - just drops
-            -yaw_rad.cos() * plane_speed * 0.5,
+            plane.velocity.z * 0.5,
         );
 
         let id = self.next_id;
         self.next_id += 1;
         self.bombs.push(Bomb::new(id, spawn_pos, initial_velocity));
+        self.sound_events.push((EntitySound::BombDrop, spawn_pos));
     }
 
     /// Update all bombs, returns list of explosion positions
@@ -3228,3 +5169,169 @@ impl EntityManager {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::tests::{flat_world, FLOOR_Y};
+
+    const GROUND_Y: f32 = 64.0;
+    const DT: f32 = 0.05;
+
+    /// A plane in the air heading north (-Z) with the given velocity, engine off
+    fn flying_plane(y: f32, velocity: Vector3<f32>) -> Plane {
+        let mut plane = Plane::new(0, Point3::new(0.0, y, 0.0));
+        plane.state = PlaneState::Flying;
+        plane.velocity = velocity;
+        plane
+    }
+
+    #[test]
+    fn takeoff_needs_a_runway_before_rotating() {
+        let mut plane = Plane::new(0, Point3::new(0.0, GROUND_Y + 1.0, 0.0));
+        let controls = PlaneControls { throttle_up: true, pitch_up: true, ..Default::default() };
+        let start = plane.position;
+
+        plane.step_physics(DT, &controls, GROUND_Y);
+        assert_eq!(plane.state, PlaneState::Grounded, "can't rotate from a standing start");
+
+        let mut steps = 1;
+        while plane.state == PlaneState::Grounded && steps < 1000 {
+            plane.step_physics(DT, &controls, GROUND_Y);
+            steps += 1;
+        }
+        assert_eq!(plane.state, PlaneState::Flying, "full throttle should get airborne eventually");
+        let runway = (plane.position - start).magnitude();
+        assert!(runway > 10.0, "lifted off after only {runway} blocks");
+    }
+
+    #[test]
+    fn stall_cuts_lift() {
+        // Level flight, wings level to the airflow: all lift goes straight up
+        let lift_at = |speed: f32| {
+            let mut plane = flying_plane(100.0, Vector3::new(0.0, 0.0, -speed));
+            plane.step_physics(DT, &PlaneControls::default(), GROUND_Y);
+            plane.velocity.y / DT + PLANE_GRAVITY
+        };
+        let above = lift_at(PLANE_STALL_SPEED + 1.0);
+        let below = lift_at(PLANE_STALL_SPEED - 1.0);
+        let speed_ratio = ((PLANE_STALL_SPEED - 1.0) / (PLANE_STALL_SPEED + 1.0)).powi(2);
+        assert!(above > 0.0);
+        assert!(below < above * speed_ratio * 0.5, "stalled lift {below} vs flying lift {above}");
+    }
+
+    #[test]
+    fn hard_touchdown_crashes() {
+        let mut plane = flying_plane(GROUND_Y + 1.1, Vector3::new(0.0, -(PLANE_MAX_LANDING_SINK_RATE + 4.0), -20.0));
+        plane.step_physics(DT, &PlaneControls::default(), GROUND_Y);
+        assert_eq!(plane.state, PlaneState::Crashed);
+        assert!(plane.is_crashed());
+    }
+
+    #[test]
+    fn gentle_touchdown_lands() {
+        let mut plane = flying_plane(GROUND_Y + 1.03, Vector3::new(0.0, -1.0, -20.0));
+        plane.step_physics(DT, &PlaneControls::default(), GROUND_Y);
+        assert_eq!(plane.state, PlaneState::Grounded);
+        assert_eq!(plane.velocity.y, 0.0);
+        assert!(plane.health > 0.0);
+    }
+
+    /// A minecart sitting on the rail at (x, FLOOR_Y + 1, z), already rolling `heading` at `speed`
+    fn rolling_minecart(x: i32, z: i32, heading: BlockFacing, speed: f32) -> Vehicle {
+        let position = Point3::new(x as f32 + 0.5, (FLOOR_Y + 1) as f32, z as f32 + 0.5);
+        let mut cart = Vehicle::new(0, VehicleKind::Minecart, position, Vehicle::facing_yaw(heading));
+        cart.heading = Some(heading);
+        cart.track_speed = speed;
+        cart
+    }
+
+    fn lay_rails(world: &mut World, y: i32, rails: impl IntoIterator<Item = (i32, i32)>) {
+        for (x, z) in rails {
+            world.set_block(x, y, z, BlockType::Rail);
+        }
+    }
+
+    #[test]
+    fn minecart_turns_at_the_middle_of_a_curve() {
+        let mut world = flat_world(1);
+        // North along x = 5, bending east at z = 5
+        lay_rails(&mut world, FLOOR_Y + 1, (5..=12).map(|z| (5, z)).chain((6..=12).map(|x| (x, 5))));
+        let mut cart = rolling_minecart(5, 11, BlockFacing::North, 4.0);
+
+        let mut steps = 0;
+        while cart.heading == Some(BlockFacing::North) && steps < 200 {
+            cart.update(DT, None, &world);
+            steps += 1;
+        }
+        assert_eq!(cart.heading, Some(BlockFacing::East), "cart should swing onto the east leg");
+        assert!((cart.position.z - 5.5).abs() < 1e-4, "turned off the centre line at z = {}", cart.position.z);
+
+        for _ in 0..10 {
+            cart.update(DT, None, &world);
+        }
+        assert!(cart.position.x > 6.0, "cart should carry on east, got x = {}", cart.position.x);
+        assert!((cart.position.z - 5.5).abs() < 1e-4);
+        assert!(Vehicle::rail_under(&world, cart.position).is_some(), "cart left the track");
+    }
+
+    /// Flat track at x = 5 heading north, up a slope at z = 7 onto a raised track from z = 6 to 1
+    fn hill_track() -> World {
+        let mut world = flat_world(1);
+        let y = FLOOR_Y + 1;
+        lay_rails(&mut world, y, (7..=14).map(|z| (5, z)));
+        for z in 1..=6 {
+            world.set_block(5, y, z, BlockType::Stone);
+        }
+        lay_rails(&mut world, y + 1, (1..=6).map(|z| (5, z)));
+        world
+    }
+
+    #[test]
+    fn fast_minecart_climbs_a_slope() {
+        let world = hill_track();
+        let mut cart = rolling_minecart(5, 13, BlockFacing::North, 8.0);
+        for _ in 0..25 {
+            cart.update(DT, None, &world);
+        }
+        assert_eq!(cart.heading, Some(BlockFacing::North));
+        assert!(cart.position.z < 6.0, "cart should be over the top, at z = {}", cart.position.z);
+        assert_eq!(cart.position.y, (FLOOR_Y + 2) as f32, "cart should ride on the raised track");
+    }
+
+    #[test]
+    fn slow_minecart_rolls_back_down_a_slope() {
+        let world = hill_track();
+        let mut cart = rolling_minecart(5, 10, BlockFacing::North, 3.0);
+        let mut highest = cart.position.y;
+        let mut steps = 0;
+        while cart.heading != Some(BlockFacing::South) && steps < 200 {
+            cart.update(DT, None, &world);
+            highest = highest.max(cart.position.y);
+            steps += 1;
+        }
+        assert_eq!(cart.heading, Some(BlockFacing::South), "cart should roll back");
+        assert!(highest > (FLOOR_Y + 1) as f32, "cart should start up the slope");
+        assert!(highest < (FLOOR_Y + 2) as f32, "cart was too slow to reach the top");
+
+        for _ in 0..20 {
+            cart.update(DT, None, &world);
+        }
+        assert!(cart.position.z > 8.0, "cart should be back on the flat, at z = {}", cart.position.z);
+        assert_eq!(cart.position.y, (FLOOR_Y + 1) as f32);
+    }
+
+    #[test]
+    fn minecart_stops_at_a_solid_block() {
+        let mut world = flat_world(1);
+        lay_rails(&mut world, FLOOR_Y + 1, (6..=12).map(|z| (5, z)));
+        world.set_block(5, FLOOR_Y + 1, 5, BlockType::Stone);
+        let mut cart = rolling_minecart(5, 11, BlockFacing::North, 4.0);
+        for _ in 0..60 {
+            cart.update(DT, None, &world);
+        }
+        assert_eq!(cart.track_speed, 0.0, "buffer should stop the cart");
+        assert!(cart.position.z - MINECART_HALF_WIDTH >= 6.0, "cart ran into the block, at z = {}", cart.position.z);
+        assert!(cart.position.z < 7.0, "cart stopped short of the block, at z = {}", cart.position.z);
+    }
+}
@@ -3,6 +3,8 @@ use winit::{
     event_loop::{ControlFlow, EventLoop},
     window::{Window, WindowBuilder, CursorGrabMode},
 };
+use cgmath::InnerSpace;
+use rand::Rng;
 
 mod world;
 mod camera;
@@ -10,15 +12,16 @@ mod renderer;
 mod ui;
 mod entity;
 mod particle;
+mod effect;
 mod audio;
 
 use world::{World, ItemStack, Tool, ToolType, ToolMaterial};
-use camera::{Camera, HungerAction};
+use camera::{Camera, DamageSource, HungerAction};
 use renderer::Renderer;
 use ui::{Inventory, DebugInfo, PauseMenu, ChestUI, CraftingUI, RecipeRegistry};
-use entity::EntityManager;
-use particle::{ParticleSystem, WeatherState, LightningSystem};
-use audio::{AudioManager, MusicManager};
+use entity::{AnimalInteraction, EntityManager};
+use particle::{ParticleSystem, WeatherState, WeatherType, LightningSystem};
+use audio::{AmbienceContext, AmbienceManager, AudioManager, MusicContext, MusicManager, AMBIENT_SOURCE_RANGE};
 
 #[derive(PartialEq, Clone, Copy)]
 enum LoadingStage {
@@ -59,14 +62,18 @@ fn main() {
     let mut chest_ui = ChestUI::new();
     let mut crafting_ui = CraftingUI::new();
     let mut furnace_ui = ui::FurnaceUI::new();
+    let mut trading_ui = ui::TradingUI::new();
+    let mut bow_draw_time: Option<f32> = None;
+    let mut sign_ui = ui::SignEditUI::new();
     let recipe_registry = RecipeRegistry::new();
     let mut entity_manager = EntityManager::new();
     let mut particle_system = ParticleSystem::new();
     let mut weather_state = WeatherState::new();
     let mut weather_rng = rand::thread_rng();
     let mut lightning_system = LightningSystem::new();
-    let audio_manager = AudioManager::new();
+    let mut audio_manager = AudioManager::new();
     let mut music_manager = MusicManager::new();
+    let mut ambience_manager = AmbienceManager::new();
 
     let mut last_frame = std::time::Instant::now();
     let mut mouse_captured = false;
@@ -75,6 +82,9 @@ fn main() {
     let mut spawn_pos = cgmath::Point3::new(0.0f32, 60.0, 0.0);
     let mut is_loaded = false;
     let mut torch_particle_timer = 0.0f32;
+    let mut ambient_scan_timer = 0.0f32;
+    let mut nearest_lava: Option<f32> = None;
+    let mut nearest_flowing_water: Option<f32> = None;
 
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Poll;
@@ -108,6 +118,18 @@ fn main() {
                                 furnace_ui.close();
                                 mouse_captured = true;
                                 set_cursor_captured(&window, true);
+                            } else if trading_ui.open {
+                                // Close trading UI
+                                trading_ui.close();
+                                mouse_captured = true;
+                                set_cursor_captured(&window, true);
+                            } else if sign_ui.open {
+                                // Finish editing and save the text
+                                if let Some(((x, y, z), lines)) = sign_ui.close() {
+                                    world.set_sign_text(x, y, z, lines);
+                                }
+                                mouse_captured = true;
+                                set_cursor_captured(&window, true);
                             } else if pause_menu.visible {
                                 // Close pause menu and resume game
                                 pause_menu.toggle();
@@ -121,6 +143,12 @@ fn main() {
                             }
                         } else if is_pressed && keycode == VirtualKeyCode::F3 {
                             debug_info.toggle();
+                        } else if is_pressed && keycode == VirtualKeyCode::F5 {
+                            // First person -> third person behind -> third person in front
+                            camera.cycle_camera_mode();
+                        } else if is_pressed && keycode == VirtualKeyCode::F6 {
+                            // Detach a free-flying camera for screenshots
+                            camera.toggle_free_camera();
                         } else if pause_menu.visible {
                             // Handle pause menu navigation
                             if is_pressed {
@@ -135,7 +163,8 @@ fn main() {
                                                 set_cursor_captured(&window, true);
                                             },
                                             "OPTIONS" => {
-                                                // TODO: Options menu not implemented yet
+                                                // Switch between arcade and realistic plane handling
+                                                pause_menu.flight_model = pause_menu.flight_model.toggled();
                                             },
                                             "QUIT" => {
                                                 *control_flow = ControlFlow::Exit;
@@ -146,6 +175,25 @@ fn main() {
                                     _ => {}
                                 }
                             }
+                        } else if sign_ui.open {
+                            // Typed characters arrive through ReceivedCharacter; keys here only move the cursor
+                            if is_pressed {
+                                match keycode {
+                                    VirtualKeyCode::Up => sign_ui.navigate(-1),
+                                    VirtualKeyCode::Down | VirtualKeyCode::Tab => sign_ui.navigate(1),
+                                    VirtualKeyCode::Back => sign_ui.backspace(),
+                                    VirtualKeyCode::Return => {
+                                        if sign_ui.selected_line < 3 {
+                                            sign_ui.navigate(1);
+                                        } else if let Some(((x, y, z), lines)) = sign_ui.close() {
+                                            world.set_sign_text(x, y, z, lines);
+                                            mouse_captured = true;
+                                            set_cursor_captured(&window, true);
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
                         } else if chest_ui.open {
                             // Handle chest UI navigation
                             if is_pressed {
@@ -352,6 +400,48 @@ fn main() {
                                     _ => {}
                                 }
                             }
+                        } else if trading_ui.open {
+                            // Handle trading UI navigation
+                            if is_pressed {
+                                if let Some(villager_id) = trading_ui.villager_id {
+                                    let offer_count = entity_manager.get_villager(villager_id).map_or(0, |v| v.trades.len());
+                                    match keycode {
+                                        VirtualKeyCode::Up | VirtualKeyCode::W => trading_ui.navigate(-1, offer_count),
+                                        VirtualKeyCode::Down | VirtualKeyCode::S => trading_ui.navigate(1, offer_count),
+                                        VirtualKeyCode::Return => {
+                                            // Pay the cost from the inventory and hand over the result
+                                            let offer = entity_manager.get_villager(villager_id)
+                                                .and_then(|v| v.trades.get(trading_ui.selected_offer))
+                                                .filter(|offer| !offer.is_sold_out())
+                                                .cloned();
+                                            if let Some(offer) = offer {
+                                                let (cost_block, cost_qty) = offer.cost;
+                                                if inventory.remove_blocks(cost_block, cost_qty) {
+                                                    // Whatever doesn't fit in the inventory drops at the player's feet
+                                                    match offer.result {
+                                                        ItemStack::Block(block_type, qty) => {
+                                                            for _ in 0..qty {
+                                                                if !inventory.add_block(block_type) {
+                                                                    entity_manager.spawn_dropped_item(camera.position, block_type);
+                                                                }
+                                                            }
+                                                        }
+                                                        ItemStack::Tool(tool) => {
+                                                            if !inventory.add_tool(tool.clone()) {
+                                                                entity_manager.spawn_dropped_tool(camera.position, tool);
+                                                            }
+                                                        }
+                                                    }
+                                                    if let Some(villager) = entity_manager.get_villager_mut(villager_id) {
+                                                        villager.record_trade(trading_ui.selected_offer);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
                         } else {
                             if is_pressed {
                                 match keycode {
@@ -362,8 +452,26 @@ fn main() {
                                     VirtualKeyCode::Key5 => inventory.select_slot(4),
                                     VirtualKeyCode::Key6 => inventory.select_slot(5),
                                     VirtualKeyCode::E => {
-                                        // First check for plane enter/exit
-                                        if camera.is_piloting() {
+                                        let can_draw_bow = inventory.get_selected_tool().is_some_and(|t| t.tool_type == ToolType::Bow)
+                                            && inventory.count_block(world::BlockType::Arrow) > 0;
+                                        // First check for vehicle enter/exit
+                                        if camera.is_riding() {
+                                            // Climb out of the boat or minecart onto the nearest free spot
+                                            let exit_pos = camera.ridden_vehicle_id
+                                                .and_then(|id| entity_manager.get_vehicle(id))
+                                                .map(|vehicle| vehicle.dismount_position(&world));
+                                            if let Some(exit_pos) = exit_pos {
+                                                camera.exit_vehicle(exit_pos);
+                                            }
+                                        } else if !camera.is_piloting() && inventory.equip_selected_armor() {
+                                            // Put on the held armor piece, swapping out the one worn before
+                                            audio_manager.play_block_place(world::BlockType::IronIngot, (camera.position.x.floor() as i32, camera.position.y.floor() as i32, camera.position.z.floor() as i32));
+                                        } else if can_draw_bow && !camera.is_piloting() {
+                                            // Start drawing the bow; it fires when E is released
+                                            if bow_draw_time.is_none() {
+                                                bow_draw_time = Some(0.0);
+                                            }
+                                        } else if camera.is_piloting() {
                                             // Exit plane if grounded
                                             if let Some(plane_id) = camera.piloted_plane_id {
                                                 if let Some(plane) = entity_manager.get_plane_mut(plane_id) {
@@ -374,15 +482,74 @@ fn main() {
                                                 }
                                             }
                                         } else {
-                                            // Not piloting - check for nearby plane to enter
+                                            // Not piloting - coal refuels and iron ingots repair a nearby plane,
+                                            // otherwise climb into it
                                             let nearby_plane = entity_manager.find_nearby_plane(camera.position, 4.0);
-                                            if !camera.try_enter_plane(nearby_plane) {
+                                            let serviced_plane = inventory.get_selected_block()
+                                                .is_some_and(|item| entity_manager.service_nearby_plane(camera.position, 4.0, item));
+                                            if serviced_plane {
+                                                inventory.decrement_selected();
+                                                audio_manager.play_block_place(world::BlockType::IronIngot, (camera.position.x.floor() as i32, camera.position.y.floor() as i32, camera.position.z.floor() as i32));
+                                            } else if !camera.try_enter_plane(nearby_plane)
+                                                && !camera.try_enter_vehicle(entity_manager.find_nearby_vehicle(camera.position, 3.0)) {
                                                 // No plane entered, continue with normal E key actions
+                                                // Animals nearby take feeding, taming, leads and pet commands; if none
+                                                // of that applies, villagers with a profession open their shop
+                                                let animal_interaction = entity_manager.get_closest_animal(camera.position, 3.0)
+                                                    .map(|(animal_id, _)| entity_manager.interact_with_animal(animal_id, inventory.get_selected_block()))
+                                                    .unwrap_or(AnimalInteraction::None);
+                                                let trader = if animal_interaction == AnimalInteraction::None {
+                                                    entity_manager.get_closest_trader(camera.position, entity::TRADE_RANGE)
+                                                } else {
+                                                    None
+                                                };
+                                                if let Some(villager_id) = trader {
+                                                    trading_ui.open_trading(villager_id);
+                                                    mouse_captured = false;
+                                                    set_cursor_captured(&window, false);
+                                                } else if animal_interaction != AnimalInteraction::None {
+                                                    match animal_interaction {
+                                                        AnimalInteraction::Fed | AnimalInteraction::Tamed
+                                                        | AnimalInteraction::TameFailed | AnimalInteraction::Leashed => inventory.decrement_selected(),
+                                                        AnimalInteraction::Unleashed => {
+                                                            inventory.add_block(world::BlockType::Lead);
+                                                        }
+                                                        _ => {}
+                                                    }
                                                 // Check if holding food - eat it
-                                                if let Some(block_type) = inventory.get_selected_block() {
-                                            if let Some((hunger_restore, saturation_restore)) = block_type.food_properties() {
+                                                } else if let Some(block_type) = inventory.get_selected_block() {
+                                            // Seeds, carrots and potatoes plant onto targeted farmland before being eaten;
+                                            // saplings go on top of targeted grass or dirt
+                                            let planted = match targeted_block {
+                                                Some((x, y, z)) if block_type.planted_crop().is_some() => world.plant_crop(x, y, z, block_type),
+                                                Some((x, y, z)) if block_type == world::BlockType::Sapling => world.plant_sapling(x, y + 1, z),
+                                                _ => false,
+                                            };
+                                            if let Some(kind) = entity::VehicleKind::from_item(block_type) {
+                                                // Boats go on water or dry land, minecarts onto rails
+                                                if entity_manager.place_vehicle(&world, kind, camera.position, camera.get_look_direction()) {
+                                                    inventory.decrement_selected();
+                                                    audio_manager.play_block_place(world::BlockType::Planks, (camera.position.x.floor() as i32, camera.position.y.floor() as i32, camera.position.z.floor() as i32));
+                                                }
+                                            } else if planted {
+                                                inventory.decrement_selected();
+                                                if let Some(pos) = targeted_block {
+                                                    audio_manager.play_block_place(world::BlockType::Grass, pos);
+                                                }
+                                            } else if let Some(effect) = block_type.potion_effect() {
+                                                // Drink the potion and keep the empty bottle
+                                                camera.effects.apply_timed(effect);
+                                                inventory.decrement_selected();
+                                                inventory.add_block(world::BlockType::GlassBottle);
+                                            } else if let Some((hunger_restore, saturation_restore)) = block_type.food_properties() {
                                                 if camera.eat_food(hunger_restore, saturation_restore) {
                                                     inventory.decrement_selected();
+                                                    // Some raw food can make the player sick
+                                                    if let Some((effect, chance)) = block_type.food_effect() {
+                                                        if rand::thread_rng().gen_bool(chance) {
+                                                            camera.effects.apply_timed(effect);
+                                                        }
+                                                    }
                                                 }
                                                 // Don't process further if we tried to eat
                                             } else if let Some((x, y, z)) = targeted_block {
@@ -398,25 +565,41 @@ fn main() {
                                                     chest_ui.open_chest((x, y, z));
                                                     mouse_captured = false;
                                                     set_cursor_captured(&window, false);
+                                                } else if let Some(sign) = world.get_sign_data(x, y, z) {
+                                                    // Edit an existing sign
+                                                    sign_ui.open_sign((x, y, z), sign.lines.clone());
+                                                    mouse_captured = false;
+                                                    set_cursor_captured(&window, false);
                                                 } else if target_block == Some(world::BlockType::DoorBottom)
                                                        || target_block == Some(world::BlockType::DoorTop) {
                                                     // Toggle door open/closed
                                                     if let Some(now_open) = world.toggle_door(x, y, z) {
-                                                        if let Some(ref audio) = audio_manager {
-                                                            if now_open {
-                                                                audio.play_block_break(world::BlockType::Planks);
-                                                            } else {
-                                                                audio.play_block_place(world::BlockType::Planks);
-                                                            }
+                                                        if now_open {
+                                                            audio_manager.play_block_break(world::BlockType::Planks, (x, y, z));
+                                                        } else {
+                                                            audio_manager.play_block_place(world::BlockType::Planks, (x, y, z));
                                                         }
                                                     }
                                                 } else if target_block == Some(world::BlockType::WoodTrapdoor)
                                                        || target_block == Some(world::BlockType::IronTrapdoor) {
                                                     // Toggle trapdoor open/closed
                                                     if world.toggle_trapdoor(x, y, z) {
-                                                        if let Some(ref audio) = audio_manager {
-                                                            audio.play_block_place(world::BlockType::Planks);
-                                                        }
+                                                        audio_manager.play_block_place(world::BlockType::Planks, (x, y, z));
+                                                    }
+                                                } else if target_block == Some(world::BlockType::Lever) {
+                                                    // Flip lever
+                                                    if world.toggle_lever(x, y, z).is_some() {
+                                                        audio_manager.play_block_place(world::BlockType::Stone, (x, y, z));
+                                                    }
+                                                } else if target_block == Some(world::BlockType::StoneButton) {
+                                                    // Press button (releases itself after a short pulse)
+                                                    if world.press_button(x, y, z) {
+                                                        audio_manager.play_block_place(world::BlockType::Stone, (x, y, z));
+                                                    }
+                                                } else if target_block == Some(world::BlockType::Repeater) {
+                                                    // Cycle repeater delay
+                                                    if world.cycle_repeater_delay(x, y, z).is_some() {
+                                                        audio_manager.play_block_place(world::BlockType::Stone, (x, y, z));
                                                     }
                                                 } else if target_block == Some(world::BlockType::Bed) {
                                                     // Try to sleep (only at night)
@@ -453,9 +636,7 @@ fn main() {
                                                                 let (hx, hy, hz) = hit_pos;
                                                                 world.set_block(hx, hy, hz, full_block);
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(full_block);
-                                                                }
+                                                                audio_manager.play_block_place(full_block, (hx, hy, hz));
                                                             }
                                                         } else if hit_block.is_top_slab() && block_type.to_top_slab() == hit_block.to_top_slab() {
                                                             // Combine into full block
@@ -463,9 +644,7 @@ fn main() {
                                                                 let (hx, hy, hz) = hit_pos;
                                                                 world.set_block(hx, hy, hz, full_block);
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(full_block);
-                                                                }
+                                                                audio_manager.play_block_place(full_block, (hx, hy, hz));
                                                             }
                                                         } else {
                                                             // Place the appropriate slab variant
@@ -477,9 +656,7 @@ fn main() {
 
                                                             if world.place_block(x, y, z, slab_type) {
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(slab_type);
-                                                                }
+                                                                audio_manager.play_block_place(slab_type, (x, y, z));
                                                             }
                                                         }
                                                     }
@@ -489,9 +666,19 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_stairs(x, y, z, block_type, facing, upside_down) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                        }
+                                                    }
+                                                } else if block_type.is_sign() {
+                                                    // Signs stand on top of blocks facing the player or hang on walls, then open for editing
+                                                    if let Some((pos, face)) = camera.get_block_placement_with_face(&world, 5.0) {
+                                                        let (x, y, z) = pos;
+                                                        if world.place_sign(x, y, z, face, camera.get_block_facing()) {
+                                                            inventory.decrement_selected();
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                            sign_ui.open_sign(pos, Default::default());
+                                                            mouse_captured = false;
+                                                            set_cursor_captured(&window, false);
                                                         }
                                                     }
                                                 } else if block_type == world::BlockType::Ladder {
@@ -500,9 +687,7 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_ladder(x, y, z, face) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else if block_type.is_trapdoor() {
@@ -512,9 +697,17 @@ fn main() {
                                                         let facing = camera.get_block_facing();
                                                         if world.place_trapdoor(x, y, z, block_type, facing, is_top) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                        }
+                                                    }
+                                                } else if block_type.is_redstone_component() || block_type.is_redstone_lamp() {
+                                                    // Redstone components attach to the clicked face; repeaters output away from the player
+                                                    if let Some((pos, face)) = camera.get_block_placement_with_face(&world, 5.0) {
+                                                        let (x, y, z) = pos;
+                                                        let facing = camera.get_block_facing().opposite();
+                                                        if world.place_redstone_component(x, y, z, block_type, face, facing) {
+                                                            inventory.decrement_selected();
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else if block_type == world::BlockType::Torch {
@@ -523,9 +716,7 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_torch(x, y, z, face) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else {
@@ -534,9 +725,7 @@ fn main() {
                                                         let (x, y, z) = placement_pos;
                                                         if world.place_block(x, y, z, block_type) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 }
@@ -553,18 +742,14 @@ fn main() {
                                                                 let (hx, hy, hz) = hit_pos;
                                                                 world.set_block(hx, hy, hz, full_block);
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(full_block);
-                                                                }
+                                                                audio_manager.play_block_place(full_block, (hx, hy, hz));
                                                             }
                                                         } else if hit_block.is_top_slab() && block_type.to_top_slab() == hit_block.to_top_slab() {
                                                             if let Some(full_block) = hit_block.slab_to_full_block() {
                                                                 let (hx, hy, hz) = hit_pos;
                                                                 world.set_block(hx, hy, hz, full_block);
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(full_block);
-                                                                }
+                                                                audio_manager.play_block_place(full_block, (hx, hy, hz));
                                                             }
                                                         } else {
                                                             let slab_type = if is_top {
@@ -574,9 +759,7 @@ fn main() {
                                                             };
                                                             if world.place_block(x, y, z, slab_type) {
                                                                 inventory.decrement_selected();
-                                                                if let Some(ref audio) = audio_manager {
-                                                                    audio.play_block_place(slab_type);
-                                                                }
+                                                                audio_manager.play_block_place(slab_type, (x, y, z));
                                                             }
                                                         }
                                                     }
@@ -586,9 +769,18 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_stairs(x, y, z, block_type, facing, upside_down) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                        }
+                                                    }
+                                                } else if block_type.is_sign() {
+                                                    if let Some((pos, face)) = camera.get_block_placement_with_face(&world, 5.0) {
+                                                        let (x, y, z) = pos;
+                                                        if world.place_sign(x, y, z, face, camera.get_block_facing()) {
+                                                            inventory.decrement_selected();
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                            sign_ui.open_sign(pos, Default::default());
+                                                            mouse_captured = false;
+                                                            set_cursor_captured(&window, false);
                                                         }
                                                     }
                                                 } else if block_type == world::BlockType::Ladder {
@@ -596,9 +788,7 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_ladder(x, y, z, face) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else if block_type.is_trapdoor() {
@@ -608,9 +798,16 @@ fn main() {
                                                         let facing = camera.get_block_facing();
                                                         if world.place_trapdoor(x, y, z, block_type, facing, is_top) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
+                                                        }
+                                                    }
+                                                } else if block_type.is_redstone_component() || block_type.is_redstone_lamp() {
+                                                    if let Some((pos, face)) = camera.get_block_placement_with_face(&world, 5.0) {
+                                                        let (x, y, z) = pos;
+                                                        let facing = camera.get_block_facing().opposite();
+                                                        if world.place_redstone_component(x, y, z, block_type, face, facing) {
+                                                            inventory.decrement_selected();
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else if block_type == world::BlockType::Torch {
@@ -618,9 +815,7 @@ fn main() {
                                                         let (x, y, z) = pos;
                                                         if world.place_torch(x, y, z, face) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 } else {
@@ -628,19 +823,58 @@ fn main() {
                                                         let (x, y, z) = placement_pos;
                                                         if world.place_block(x, y, z, block_type) {
                                                             inventory.decrement_selected();
-                                                            if let Some(ref audio) = audio_manager {
-                                                                audio.play_block_place(block_type);
-                                                            }
+                                                            audio_manager.play_block_place(block_type, (x, y, z));
                                                         }
                                                     }
                                                 }
                                             }
                                         } else if let Some((x, y, z)) = targeted_block {
-                                            // No item selected but targeting a chest - open it
-                                            if world.get_block(x, y, z) == Some(world::BlockType::Chest) {
-                                                chest_ui.open_chest((x, y, z));
-                                                mouse_captured = false;
-                                                set_cursor_captured(&window, false);
+                                            // No item selected - open chests, use levers/buttons by hand, till soil with a hoe
+                                            let holding_hoe = inventory.get_selected_tool().is_some_and(|t| t.tool_type == ToolType::Hoe);
+                                            let holding_flint_and_steel = inventory.get_selected_tool().is_some_and(|t| t.tool_type == ToolType::FlintAndSteel);
+                                            match world.get_block(x, y, z) {
+                                                Some(world::BlockType::Grass | world::BlockType::Dirt) if holding_hoe && world.till_block(x, y, z) => {
+                                                    audio_manager.play_block_place(world::BlockType::Dirt, (x, y, z));
+                                                    if let Some(tool) = inventory.get_selected_tool_mut() {
+                                                        tool.durability = tool.durability.saturating_sub(1);
+                                                        if tool.durability == 0 {
+                                                            inventory.slots[inventory.selected_slot] = None;
+                                                        }
+                                                    }
+                                                }
+                                                Some(world::BlockType::Chest) => {
+                                                    chest_ui.open_chest((x, y, z));
+                                                    mouse_captured = false;
+                                                    set_cursor_captured(&window, false);
+                                                }
+                                                Some(world::BlockType::SignPost | world::BlockType::WallSign) => {
+                                                    if let Some(sign) = world.get_sign_data(x, y, z) {
+                                                        sign_ui.open_sign((x, y, z), sign.lines.clone());
+                                                        mouse_captured = false;
+                                                        set_cursor_captured(&window, false);
+                                                    }
+                                                }
+                                                Some(world::BlockType::Lever) if world.toggle_lever(x, y, z).is_some() => {
+                                                    audio_manager.play_block_place(world::BlockType::Stone, (x, y, z));
+                                                }
+                                                Some(world::BlockType::StoneButton) if world.press_button(x, y, z) => {
+                                                    audio_manager.play_block_place(world::BlockType::Stone, (x, y, z));
+                                                }
+                                                _ if holding_flint_and_steel => {
+                                                    // Strike a fire on the face being looked at
+                                                    let lit = camera.get_block_placement_position(&world, 5.0)
+                                                        .is_some_and(|(fx, fy, fz)| world.ignite(fx, fy, fz));
+                                                    if lit {
+                                                        audio_manager.play_block_place(world::BlockType::Gravel, (x, y, z));
+                                                        if let Some(tool) = inventory.get_selected_tool_mut() {
+                                                            tool.durability = tool.durability.saturating_sub(1);
+                                                            if tool.durability == 0 {
+                                                                inventory.slots[inventory.selected_slot] = None;
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                                _ => {}
                                             }
                                         }
                                             } // close "No plane entered" if
@@ -654,10 +888,19 @@ fn main() {
                                         // Give player a chest for testing
                                         inventory.add_block(world::BlockType::Chest);
                                     },
+                                    VirtualKeyCode::Y => {
+                                        // Give player redstone wire for testing
+                                        inventory.add_block(world::BlockType::RedstoneWire);
+                                    },
                                     VirtualKeyCode::G => {
                                         // Give player a diamond pickaxe for testing
                                         inventory.add_tool(Tool::new(ToolType::Pickaxe, ToolMaterial::Diamond));
                                     },
+                                    VirtualKeyCode::H => {
+                                        // Give player a diamond hoe and seeds for testing
+                                        inventory.add_tool(Tool::new(ToolType::Hoe, ToolMaterial::Diamond));
+                                        inventory.add_block(world::BlockType::Seeds);
+                                    },
                                     VirtualKeyCode::B => {
                                         // Give player a diamond sword for testing
                                         inventory.add_tool(Tool::new(ToolType::Sword, ToolMaterial::Diamond));
@@ -723,6 +966,11 @@ fn main() {
                                                     }
                                                 }
                                             }
+                                            for piece in inventory.armor.iter_mut() {
+                                                if let Some(tool) = piece.take() {
+                                                    entity_manager.spawn_dropped_tool(death_pos, tool);
+                                                }
+                                            }
                                             camera.respawn();
                                         } else {
                                             // Try to attack a hostile mob first (within 4 blocks)
@@ -792,11 +1040,22 @@ fn main() {
                                                 }
                                             }
 
+                                            // Hitting an empty boat or minecart knocks it loose as an item
+                                            if !attacked_something {
+                                                if let Some((pos, item)) = entity_manager.break_nearby_vehicle(camera.position, 3.0, camera.ridden_vehicle_id) {
+                                                    entity_manager.spawn_dropped_item(pos, item);
+                                                    audio_manager.play_block_break(world::BlockType::Planks, (pos.x.floor() as i32, pos.y.floor() as i32, pos.z.floor() as i32));
+                                                    renderer.start_arm_swing();
+                                                    attacked_something = true;
+                                                }
+                                            }
+
                                             // If nothing attacked, try breaking a block
                                             if !attacked_something {
                                                 if let Some((x, y, z)) = targeted_block {
-                                                    // Get block type for particles before damaging
+                                                    // Get block type for particles (and crop stage for drops) before damaging
                                                     let block_type = world.get_block(x, y, z);
+                                                    let crop_stage = world.get_crop_stage(x, y, z);
 
                                                     // Get the currently held tool (if any)
                                                     let tool_ref = inventory.get_selected_tool();
@@ -806,11 +1065,11 @@ fn main() {
                                                         // Block was fully destroyed and can be harvested
                                                         let block_center = cgmath::Point3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5);
                                                         particle_system.spawn_block_break(block_center, dropped_block);
-                                                        // Spawn dropped item
-                                                        entity_manager.spawn_dropped_item(block_center, dropped_block);
-                                                        if let Some(ref audio) = audio_manager {
-                                                            audio.play_block_break(dropped_block);
+                                                        // Spawn dropped items (grass may drop seeds, crops drop produce)
+                                                        for drop in dropped_block.harvest_drops(crop_stage) {
+                                                            entity_manager.spawn_dropped_item(block_center, drop);
                                                         }
+                                                        audio_manager.play_block_break(dropped_block, (x, y, z));
                                                         // Reduce tool durability if a tool was used
                                                         if let Some(tool) = inventory.get_selected_tool_mut() {
                                                             tool.durability = tool.durability.saturating_sub(1);
@@ -825,9 +1084,7 @@ fn main() {
                                                             cgmath::Point3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5),
                                                             bt
                                                         );
-                                                        if let Some(ref audio) = audio_manager {
-                                                            audio.play_block_break(bt);
-                                                        }
+                                                        audio_manager.play_block_break(bt, (x, y, z));
                                                     }
                                                     renderer.start_arm_swing();
                                                 }
@@ -836,11 +1093,30 @@ fn main() {
                                     },
                                     _ => {}
                                 }
+                            } else if keycode == VirtualKeyCode::E {
+                                // Releasing E looses a drawn bow
+                                if let Some(draw_time) = bow_draw_time.take() {
+                                    let strength = entity::bow_draw_strength(draw_time);
+                                    let holding_bow = inventory.get_selected_tool().is_some_and(|t| t.tool_type == ToolType::Bow);
+                                    if holding_bow && strength >= entity::BOW_MIN_DRAW && inventory.remove_blocks(world::BlockType::Arrow, 1) {
+                                        let direction = camera.get_look_direction();
+                                        entity_manager.shoot_arrow(camera.position + direction * 0.5, direction, strength);
+                                        if let Some(tool) = inventory.get_selected_tool_mut() {
+                                            tool.durability = tool.durability.saturating_sub(1);
+                                            if tool.durability == 0 {
+                                                inventory.slots[inventory.selected_slot] = None;
+                                            }
+                                        }
+                                    }
+                                }
                             }
                             camera.process_keyboard(keycode, is_pressed);
                         }
                     }
                 }
+                WindowEvent::ReceivedCharacter(c) if sign_ui.open => {
+                    sign_ui.type_char(c);
+                }
                 WindowEvent::Resized(physical_size) => {
                     renderer.resize(physical_size);
                     camera.resize(&renderer.config);
@@ -863,13 +1139,66 @@ fn main() {
                     world.process_water_updates(50);
                     // Update furnace smelting
                     world.update_furnaces(dt);
-                    // Update camera/player (skip normal movement if piloting)
-                    if !camera.is_piloting() {
+                    // Random block ticks: crops, grass spread, leaf decay, ice melt, snowfall
+                    world.update_random_ticks(dt, weather_state.is_precipitating());
+                    world.update_fire(dt, weather_state.is_raining());
+                    // Leaves around felled trees fall apart; spawn their sapling and apple drops
+                    world.update_leaf_decay(dt);
+                    for (position, drop) in world.take_pending_drops() {
+                        entity_manager.spawn_dropped_item(position, drop);
+                    }
+                    // Press pressure plates under the player and mobs, then advance circuits
+                    let mut plate_feet = vec![camera.feet_position()];
+                    // Entity positions are the top of the body, so drop each by its height
+                    plate_feet.extend(entity_manager.villagers.iter()
+                        .map(|v| v.position - cgmath::Vector3::new(0.0, entity::VILLAGER_HEIGHT, 0.0)));
+                    plate_feet.extend(entity_manager.animals.iter()
+                        .map(|a| a.position - cgmath::Vector3::new(0.0, a.dimensions().1, 0.0)));
+                    plate_feet.extend(entity_manager.hostile_mobs.iter()
+                        .map(|m| m.position - cgmath::Vector3::new(0.0, m.mob_type.dimensions().1, 0.0)));
+                    world.update_pressure_plates(&plate_feet);
+                    world.update_redstone(dt);
+                    // Worn armor decides how much of each hit the player takes
+                    camera.armor_points = inventory.armor_points();
+                    // Update camera/player (skip normal movement if piloting or riding)
+                    if !camera.is_piloting() && !camera.is_riding() {
                         camera.update(dt, &world);
                     }
                     camera.update_survival(dt, &world);
                     entity_manager.update(dt, &world, camera.position, renderer.get_time_of_day());
 
+                    // Keep pulling a drawn bow back; switching away from it lets the string go slack
+                    if let Some(draw_time) = bow_draw_time.as_mut() {
+                        *draw_time += dt;
+                        if !inventory.get_selected_tool().is_some_and(|t| t.tool_type == ToolType::Bow) {
+                            bow_draw_time = None;
+                        }
+                    }
+
+                    // Villagers opening and shutting their house doors
+                    for ((x, y, z), open) in entity_manager.take_door_requests() {
+                        if world.is_door_open(x, y, z) != open {
+                            if let Some(now_open) = world.toggle_door(x, y, z) {
+                                if now_open {
+                                    audio_manager.play_block_break(world::BlockType::Planks, (x, y, z));
+                                } else {
+                                    audio_manager.play_block_place(world::BlockType::Planks, (x, y, z));
+                                }
+                            }
+                        }
+                    }
+
+                    // Close the shop if the villager despawned or wandered off
+                    if let Some(villager_id) = trading_ui.villager_id {
+                        let in_range = entity_manager.get_villager(villager_id)
+                            .is_some_and(|v| (v.position - camera.position).magnitude() < entity::TRADE_RANGE * 2.0);
+                        if !in_range {
+                            trading_ui.close();
+                            mouse_captured = true;
+                            set_cursor_captured(&window, true);
+                        }
+                    }
+
                     // Handle plane piloting
                     if camera.is_piloting() {
                         if let Some(plane_id) = camera.piloted_plane_id {
@@ -880,20 +1209,20 @@ fn main() {
                                 Some((true, pos)) => {
                                     // Plane crashed - eject player
                                     camera.exit_plane(pos);
-                                    camera.take_damage(30.0, None);
+                                    camera.take_damage(30.0, DamageSource::Fall, None);
                                 }
                                 Some((false, _)) => {
                                     // Plane is fine - fly it
                                     if let Some(plane) = entity_manager.get_plane_mut(plane_id) {
-                                        plane.fly(
-                                            dt,
-                                            camera.throttle_up,
-                                            camera.moving_left,
-                                            camera.moving_right,
-                                            camera.pitch_up,
-                                            camera.pitch_down,
-                                            &world,
-                                        );
+                                        let controls = entity::PlaneControls {
+                                            throttle_up: camera.throttle_up,
+                                            throttle_down: camera.throttle_down,
+                                            turn_left: camera.moving_left,
+                                            turn_right: camera.moving_right,
+                                            pitch_up: camera.pitch_up,
+                                            pitch_down: camera.pitch_down,
+                                        };
+                                        plane.fly(dt, &controls, pause_menu.flight_model, &world);
                                         camera.update_flight_view(plane.yaw, plane.pitch, plane.roll, plane.position);
                                     }
                                 }
@@ -905,6 +1234,23 @@ fn main() {
                         }
                     }
 
+                    // Paddle the ridden boat or push the ridden minecart, then sit the player in it
+                    let rider = camera.ridden_vehicle_id.map(|id| (id, camera.vehicle_controls()));
+                    entity_manager.update_vehicles(dt, &world, rider);
+                    if let Some(vehicle_id) = camera.ridden_vehicle_id {
+                        match entity_manager.get_vehicle(vehicle_id) {
+                            Some(vehicle) => camera.update_riding_view(vehicle.seat_position(), vehicle.yaw),
+                            None => {
+                                // Vehicle is gone - stand up where the seat was
+                                let feet = cgmath::Point3::new(camera.position.x, camera.position.y - 1.0, camera.position.z);
+                                camera.exit_vehicle(feet);
+                            }
+                        }
+                    }
+
+                    // Place the third-person or free camera once the player has moved
+                    camera.update_camera_mode(dt, &world);
+
                     // Clean up crashed planes
                     entity_manager.cleanup_crashed_planes();
 
@@ -912,51 +1258,63 @@ fn main() {
                     let explosions = entity_manager.update_missiles(dt, &world);
                     for (ex, ey, ez) in explosions {
                         world.explode(ex, ey, ez, crate::entity::EXPLOSION_RADIUS);
+                        entity_manager.damage_planes_in_explosion(cgmath::Point3::new(ex as f32 + 0.5, ey as f32 + 0.5, ez as f32 + 0.5), crate::entity::EXPLOSION_RADIUS as f32);
+                        audio_manager.play_explosion(cgmath::Point3::new(ex as f32 + 0.5, ey as f32 + 0.5, ez as f32 + 0.5));
                     }
 
                     // Update bombs and handle explosions (bigger than missiles)
                     let bomb_explosions = entity_manager.update_bombs(dt, &world);
                     for (ex, ey, ez) in bomb_explosions {
                         world.explode(ex, ey, ez, crate::entity::BOMB_EXPLOSION_RADIUS);
+                        entity_manager.damage_planes_in_explosion(cgmath::Point3::new(ex as f32 + 0.5, ey as f32 + 0.5, ez as f32 + 0.5), crate::entity::BOMB_EXPLOSION_RADIUS as f32);
+                        audio_manager.play_explosion(cgmath::Point3::new(ex as f32 + 0.5, ey as f32 + 0.5, ez as f32 + 0.5));
                     }
 
                     // Check for hostile mob attacks on player
                     if !camera.is_dead {
-                        for (damage, knockback) in entity_manager.check_hostile_attacks(camera.position) {
-                            camera.take_damage(damage, Some(knockback));
+                        for (damage, knockback, effect) in entity_manager.check_hostile_attacks(camera.position) {
+                            if camera.take_damage(damage, DamageSource::Mob, Some(knockback)) {
+                                // Spider bites leave poison behind
+                                if let Some(effect) = effect {
+                                    camera.effects.apply_timed(effect);
+                                }
+                            }
                         }
                         // Check for projectile (arrow) hits on player
-                        for damage in entity_manager.check_projectile_player_collisions(camera.position) {
-                            camera.take_damage(damage, None);
+                        for (damage, knockback) in entity_manager.check_projectile_player_collisions(camera.position) {
+                            camera.take_damage(damage, DamageSource::Arrow, Some(knockback));
                         }
                     }
 
                     // Handle creeper explosions
-                    let exploding_creepers: Vec<_> = entity_manager.get_hostile_mobs().iter()
-                        .filter(|m| m.mob_type == entity::HostileMobType::Creeper && m.is_dead())
-                        .map(|m| m.position)
-                        .collect();
-                    for explosion_pos in exploding_creepers {
+                    for (explosion_pos, radius) in entity_manager.take_creeper_explosions() {
                         // Create explosion in the world
-                        let _destroyed = world.create_explosion(explosion_pos, entity::CREEPER_EXPLOSION_RADIUS);
+                        let _destroyed = world.create_explosion(explosion_pos, radius);
+                        entity_manager.damage_planes_in_explosion(explosion_pos, radius);
+                        audio_manager.play_explosion(explosion_pos);
 
-                        // Damage player if within explosion radius
+                        // Damage player if within explosion radius (charged creepers hit harder)
                         let dist = ((camera.position.x - explosion_pos.x).powi(2)
                             + (camera.position.y - explosion_pos.y).powi(2)
                             + (camera.position.z - explosion_pos.z).powi(2)).sqrt();
-                        if dist < entity::CREEPER_EXPLOSION_RADIUS * 2.0 {
+                        if dist < radius * 2.0 {
                             // Damage falls off with distance
-                            let damage_factor = 1.0 - (dist / (entity::CREEPER_EXPLOSION_RADIUS * 2.0));
-                            let damage = entity::CREEPER_EXPLOSION_DAMAGE * damage_factor;
+                            let damage_factor = 1.0 - (dist / (radius * 2.0));
+                            let damage = entity::CREEPER_EXPLOSION_DAMAGE * (radius / entity::CREEPER_EXPLOSION_RADIUS) * damage_factor;
                             let knockback = cgmath::Vector3::new(
                                 (camera.position.x - explosion_pos.x).signum() * 12.0,
                                 8.0,
                                 (camera.position.z - explosion_pos.z).signum() * 12.0,
                             );
-                            camera.take_damage(damage, Some(knockback));
+                            camera.take_damage(damage, DamageSource::Explosion, Some(knockback));
                         }
                     }
 
+                    // Hits soaked up by armor this frame wear the worn pieces down
+                    for damage in camera.take_armor_hits() {
+                        inventory.wear_armor(damage);
+                    }
+
                     // Collect nearby dropped items
                     for item in entity_manager.collect_nearby_items(camera.position) {
                         inventory.add_item(item);
@@ -966,10 +1324,24 @@ fn main() {
                     particle_system.spawn_weather(camera.position, &weather_state, dt);
 
                     // Update lightning system and play thunder sounds
-                    if let Some(thunder_volume) = lightning_system.update(dt, camera.position, &weather_state, &mut weather_rng) {
-                        if let Some(ref audio) = audio_manager {
-                            audio.play_thunder(thunder_volume);
+                    if let Some(thunder_volume) = lightning_system.update(dt, camera.position, &world, &weather_state, &mut weather_rng) {
+                        audio_manager.play_thunder(thunder_volume);
+                    }
+                    // Bolts set fire to whatever they hit (rods carry it safely to the ground)
+                    // and hurt anything standing close by
+                    for (x, y, z) in lightning_system.take_strikes() {
+                        if world.get_block(x, y, z) != Some(world::BlockType::LightningRod) {
+                            world.ignite(x, y + 1, z);
+                        }
+                        let strike_pos = cgmath::Point3::new(x as f32 + 0.5, y as f32 + 1.0, z as f32 + 0.5);
+                        let player_height = camera.position.y - camera.feet_position().y;
+                        if !camera.is_dead && entity::within_lightning_strike(strike_pos, camera.position, player_height) {
+                            let knockback = entity::lightning_knockback(strike_pos, camera.position);
+                            if camera.take_damage(entity::LIGHTNING_DAMAGE, DamageSource::Lightning, Some(knockback)) {
+                                camera.effects.apply(effect::StatusEffectType::Fire, entity::LIGHTNING_BURN_TIME, 0);
+                            }
                         }
+                        entity_manager.strike_lightning(strike_pos);
                     }
 
                     // Spawn torch flame particles (throttled to every ~0.1 seconds)
@@ -988,22 +1360,80 @@ fn main() {
                             .map(|(x, y, z)| cgmath::Point3::new(*x as f32 + 0.5, *y as f32 + 0.5, *z as f32 + 0.5))
                             .collect();
                         particle_system.spawn_torch_flames(&torch_positions);
+
+                        // Fire blocks within the same range
+                        for &(x, y, z) in world.fire_ages.keys() {
+                            let fire_pos = cgmath::Point3::new(x as f32 + 0.5, y as f32, z as f32 + 0.5);
+                            if (fire_pos - camera.position).magnitude2() < 900.0 {
+                                particle_system.spawn_burning(fire_pos, 0.8, 0.8);
+                            }
+                        }
+
+                        // Badly damaged planes trail smoke, thicker the closer they are to falling apart
+                        for plane in entity_manager.get_planes().iter().filter(|p| p.is_smoking()) {
+                            let smoke_pos = plane.position + cgmath::Vector3::new(0.0, 1.0, 0.0);
+                            particle_system.spawn_smoke(smoke_pos, 1.0 - plane.health / (entity::PLANE_MAX_HEALTH * 0.5));
+                        }
+
+                        // Burning player and mobs give off flames
+                        if camera.effects.has(effect::StatusEffectType::Fire) {
+                            particle_system.spawn_burning(camera.feet_position(), 0.6, camera.movement_state.eye_height());
+                        }
+                        for mob in entity_manager.get_hostile_mobs().iter().filter(|m| m.effects.has(effect::StatusEffectType::Fire)) {
+                            let (width, height) = mob.mob_type.dimensions();
+                            particle_system.spawn_burning(mob.position - cgmath::Vector3::new(0.0, height, 0.0), width, height);
+                        }
                     }
 
                     particle_system.update(dt);
 
-                    // Update background music based on time and underwater state
-                    if let Some(ref mut music) = music_manager {
-                        let is_underwater = camera.is_underwater(&world);
-                        music.update(dt, renderer.get_time_of_day(), is_underwater);
+                    // Sounds are heard from the camera's position and facing
+                    audio_manager.set_listener(camera.position, camera.yaw, camera.is_underwater(&world));
+
+                    // Mob, animal, arrow and plane sounds from this frame
+                    for (sound, position) in entity_manager.take_sound_events() {
+                        audio_manager.play_entity_sound(sound, position);
                     }
 
+                    // Update background music for the player's surroundings and situation
+                    let (px, py, pz) = (camera.position.x.floor() as i32, camera.position.y.floor() as i32, camera.position.z.floor() as i32);
+                    let biome = world.get_biome(camera.position.x as f64, camera.position.z as f64);
+                    music_manager.update(dt, &MusicContext {
+                        time_of_day: renderer.get_time_of_day(),
+                        biome,
+                        underwater: camera.is_underwater(&world),
+                        underground: world.is_underground(px, py, pz),
+                        in_combat: entity_manager.is_player_in_combat(camera.position),
+                        thunderstorm: weather_state.weather_type == WeatherType::Thunderstorm,
+                        piloting: camera.is_piloting(),
+                    });
+
+                    // Ambient beds - lava and flowing water need a block scan, so only look a few times a second
+                    ambient_scan_timer += dt;
+                    if ambient_scan_timer >= 0.5 {
+                        ambient_scan_timer = 0.0;
+                        nearest_lava = world.nearest_block_distance(camera.position, AMBIENT_SOURCE_RANGE as i32, world::BlockType::Lava);
+                        nearest_flowing_water = world.nearest_flowing_water_distance(camera.position, AMBIENT_SOURCE_RANGE);
+                    }
+                    let raining = matches!(weather_state.weather_type, WeatherType::Rain | WeatherType::Thunderstorm);
+                    ambience_manager.update(dt, &AmbienceContext {
+                        time_of_day: renderer.get_time_of_day(),
+                        biome,
+                        altitude: camera.position.y,
+                        rain_intensity: if raining { weather_state.intensity } else { 0.0 },
+                        sheltered: world.is_sheltered(px, py, pz),
+                        underground: world.is_underground(px, py, pz),
+                        underwater: camera.is_underwater(&world),
+                        lava_distance: nearest_lava,
+                        flowing_water_distance: nearest_flowing_water,
+                    });
+
                     targeted_block = camera.get_targeted_block(&world, 5.0);
 
                     // Update block preview for placement visualization
-                    let preview_pos = match (pause_menu.visible || chest_ui.open, inventory.get_selected_block()) {
+                    let preview_pos = match (pause_menu.visible || chest_ui.open || sign_ui.open, inventory.get_selected_block()) {
                         (true, _) | (_, None) => None,
-                        (_, Some(world::BlockType::Torch)) => {
+                        (_, Some(world::BlockType::Torch | world::BlockType::SignPost)) => {
                             camera.get_block_placement_with_face(&world, 5.0).map(|(pos, _)| pos)
                         }
                         (_, Some(_)) => camera.get_block_placement_position(&world, 5.0),
@@ -1011,21 +1441,17 @@ fn main() {
                     renderer.update_block_preview(preview_pos, inventory.get_selected_block());
 
                     // Handle sound and particle events
-                    if let Some(ref audio) = audio_manager {
-                        if let Some(block_type) = camera.get_footstep_event() {
-                            audio.play_footstep(block_type);
-                        }
-                        if camera.check_jump_event() {
-                            audio.play_jump();
-                        }
-                        if camera.check_land_event() {
-                            audio.play_land();
-                        }
+                    if let Some(block_type) = camera.get_footstep_event() {
+                        audio_manager.play_footstep(block_type);
+                    }
+                    if camera.check_jump_event() {
+                        audio_manager.play_jump();
+                    }
+                    if camera.check_land_event() {
+                        audio_manager.play_land();
                     }
                     if camera.check_water_enter_event() {
-                        if let Some(ref audio) = audio_manager {
-                            audio.play_splash();
-                        }
+                        audio_manager.play_splash();
                         particle_system.spawn_water_splash(camera.position);
                     }
                 } else if is_loaded {
@@ -1037,7 +1463,7 @@ fn main() {
             Event::RedrawRequested(_) => {
                 if is_loaded {
                     let is_underwater = camera.is_underwater(&world);
-                    renderer.render(&camera, &mut world, &inventory, targeted_block, &entity_manager, &particle_system, is_underwater, &debug_info, &pause_menu, &chest_ui, &crafting_ui, &furnace_ui, &recipe_registry, &lightning_system, &weather_state);
+                    renderer.render(&camera, &mut world, &inventory, targeted_block, &entity_manager, &particle_system, is_underwater, &debug_info, &pause_menu, &chest_ui, &crafting_ui, &furnace_ui, &trading_ui, &sign_ui, &recipe_registry, &lightning_system, &weather_state);
                 } else {
                     // Process loading stages
                     let (progress, message) = match loading_stage {
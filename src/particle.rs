@@ -1,10 +1,11 @@
 use cgmath::{Point3, Vector3};
 use rand::prelude::*;
-use crate::world::BlockType;
+use crate::world::{BlockType, World};
 
 const MAX_PARTICLES: usize = 2000;
 const GRAVITY: f32 = -15.0;
 const SNOW_GRAVITY: f32 = -2.0;
+const SMOKE_BUOYANCY: f32 = 1.5;  // Smoke drifts upward
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum WeatherType {
@@ -99,6 +100,7 @@ pub struct LightningSystem {
     pub sky_flash: f32,  // 0.0 to 1.0, for screen flash effect
     pub pending_thunder: Vec<(f32, f32)>,  // (delay_remaining, volume)
     strike_cooldown: f32,
+    strikes: Vec<(i32, i32, i32)>,  // Blocks new bolts hit, drained by the game loop
 }
 
 impl LightningSystem {
@@ -108,10 +110,11 @@ impl LightningSystem {
             sky_flash: 0.0,
             pending_thunder: Vec::new(),
             strike_cooldown: 0.0,
+            strikes: Vec::new(),
         }
     }
 
-    pub fn update(&mut self, dt: f32, camera_pos: Point3<f32>, weather: &WeatherState, rng: &mut ThreadRng) -> Option<f32> {
+    pub fn update(&mut self, dt: f32, camera_pos: Point3<f32>, world: &World, weather: &WeatherState, rng: &mut ThreadRng) -> Option<f32> {
         // Update existing bolts
         self.bolts.retain_mut(|bolt| {
             bolt.age += dt;
@@ -140,34 +143,36 @@ impl LightningSystem {
         if weather.weather_type == WeatherType::Thunderstorm && self.strike_cooldown <= 0.0 {
             // Random chance for lightning strike
             if rng.gen_bool(0.02 * weather.intensity as f64) {
-                self.spawn_lightning(camera_pos, rng);
+                self.spawn_lightning(camera_pos, world, rng);
             }
         }
 
         thunder_to_play
     }
 
-    pub fn spawn_lightning(&mut self, camera_pos: Point3<f32>, rng: &mut ThreadRng) {
+    pub fn spawn_lightning(&mut self, camera_pos: Point3<f32>, world: &World, rng: &mut ThreadRng) {
         // Strike within 100 blocks of player
         let offset_x = rng.gen_range(-100.0..100.0);
         let offset_z = rng.gen_range(-100.0..100.0);
+        let (x, z) = (camera_pos.x + offset_x, camera_pos.z + offset_z);
 
-        let start = Point3::new(
-            camera_pos.x + offset_x,
-            camera_pos.y + 80.0,  // Start high in sky
-            camera_pos.z + offset_z,
-        );
+        // Bolts come down on the tallest thing around; over unloaded ground they stay in the sky
+        let target = world.lightning_target(x.floor() as i32, z.floor() as i32, rng);
+        let end = match target {
+            Some((tx, ty, tz)) => Point3::new(tx as f32 + 0.5, ty as f32 + 1.0, tz as f32 + 0.5),
+            None => Point3::new(x, camera_pos.y - 20.0, z),
+        };
 
-        let end = Point3::new(
-            start.x + rng.gen_range(-10.0..10.0),
-            camera_pos.y - 20.0,  // Strike ground
-            start.z + rng.gen_range(-10.0..10.0),
+        let start = Point3::new(
+            end.x + rng.gen_range(-10.0..10.0),
+            end.y.max(camera_pos.y) + 80.0,  // Start high in sky
+            end.z + rng.gen_range(-10.0..10.0),
         );
 
         let bolt = LightningBolt::new(start, end, rng);
 
         // Calculate distance for thunder delay (speed of sound ~343 m/s)
-        let distance = ((offset_x * offset_x + offset_z * offset_z).sqrt()).abs();
+        let distance = ((end.x - camera_pos.x).powi(2) + (end.z - camera_pos.z).powi(2)).sqrt();
         let thunder_delay = distance / 343.0;  // Delay in seconds
         let thunder_volume = (1.0 - distance / 150.0).max(0.2);  // Louder when closer
 
@@ -175,8 +180,14 @@ impl LightningSystem {
         self.sky_flash = 1.0;
         self.strike_cooldown = rng.gen_range(2.0..8.0);  // Cooldown between strikes
 
+        self.strikes.extend(target);
         self.bolts.push(bolt);
     }
+
+    /// Blocks struck since the last call
+    pub fn take_strikes(&mut self) -> Vec<(i32, i32, i32)> {
+        std::mem::take(&mut self.strikes)
+    }
 }
 
 pub struct WeatherState {
@@ -215,6 +226,16 @@ impl WeatherState {
             self.transition_timer = rng.gen_range(60.0..300.0);
         }
     }
+
+    /// Whether anything is falling from the sky (rain, snow or a storm)
+    pub fn is_precipitating(&self) -> bool {
+        self.weather_type != WeatherType::Clear && self.intensity >= 0.01
+    }
+
+    /// Whether rain is falling (enough to put out fires)
+    pub fn is_raining(&self) -> bool {
+        matches!(self.weather_type, WeatherType::Rain | WeatherType::Thunderstorm) && self.intensity >= 0.01
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -222,6 +243,7 @@ pub enum ParticleType {
     Normal,
     Rain,
     Snow,
+    Smoke,
 }
 
 #[derive(Clone)]
@@ -483,6 +505,34 @@ impl ParticleSystem {
         }
     }
 
+    /// Spawn a puff of smoke from a damaged plane; `density` (0.0-1.0) makes it thicker and darker
+    pub fn spawn_smoke(&mut self, pos: Point3<f32>, density: f32) {
+        let count = 1 + (density * 3.0) as usize;
+        let shade = 0.45 - density * 0.3;
+
+        for _ in 0..count {
+            if self.particles.len() >= MAX_PARTICLES {
+                return;
+            }
+
+            let offset = self.random_vector(-0.3, 0.3);
+            let velocity = Vector3::new(
+                self.rng.gen_range(-0.4..0.4),
+                self.rng.gen_range(0.3..1.0),
+                self.rng.gen_range(-0.4..0.4),
+            );
+
+            self.particles.push(Particle::new_weather(
+                pos + offset,
+                velocity,
+                [shade, shade, shade, 0.7],
+                self.rng.gen_range(1.5..2.5),
+                self.rng.gen_range(0.2..0.35),
+                ParticleType::Smoke,
+            ));
+        }
+    }
+
     /// Spawn flame particles for torches
     pub fn spawn_torch_flames(&mut self, torch_positions: &[Point3<f32>]) {
         for pos in torch_positions {
@@ -523,6 +573,41 @@ impl ParticleSystem {
         }
     }
 
+    /// Flames licking up the body of a burning player or mob
+    pub fn spawn_burning(&mut self, feet_pos: Point3<f32>, width: f32, height: f32) {
+        for _ in 0..3 {
+            if self.particles.len() >= MAX_PARTICLES {
+                return;
+            }
+
+            let half = width * 0.5;
+            let spawn_pos = Point3::new(
+                feet_pos.x + self.rng.gen_range(-half..half),
+                feet_pos.y + self.rng.gen_range(0.0..height),
+                feet_pos.z + self.rng.gen_range(-half..half),
+            );
+            let velocity = Vector3::new(
+                self.rng.gen_range(-0.2..0.2),
+                self.rng.gen_range(0.8..1.6),
+                self.rng.gen_range(-0.2..0.2),
+            );
+            let color = [
+                1.0,
+                self.rng.gen_range(0.35..0.7),
+                self.rng.gen_range(0.0..0.15),
+                0.9,
+            ];
+
+            self.particles.push(Particle::new(
+                spawn_pos,
+                velocity,
+                color,
+                self.rng.gen_range(0.25..0.5),
+                self.rng.gen_range(0.05..0.1),
+            ));
+        }
+    }
+
     pub fn update(&mut self, dt: f32) {
         for particle in &mut self.particles {
             // Apply appropriate gravity based on particle type
@@ -530,6 +615,7 @@ impl ParticleSystem {
                 ParticleType::Snow => SNOW_GRAVITY,
                 ParticleType::Rain => GRAVITY * 0.5,  // Rain has constant velocity mostly
                 ParticleType::Normal => GRAVITY,
+                ParticleType::Smoke => SMOKE_BUOYANCY,
             };
             particle.velocity.y += gravity * dt;
 
@@ -547,7 +633,7 @@ impl ParticleSystem {
             // Ground collision - weather particles just die, others bounce
             if particle.position.y < 0.0 {
                 match particle.particle_type {
-                    ParticleType::Rain | ParticleType::Snow => {
+                    ParticleType::Rain | ParticleType::Snow | ParticleType::Smoke => {
                         particle.age = particle.lifetime;  // Kill weather particles on ground
                     }
                     ParticleType::Normal => {
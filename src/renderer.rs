@@ -9,7 +9,7 @@ use cgmath::{Matrix4, Deg, Vector3, Vector4, InnerSpace, Matrix, Point3};
 use rayon::prelude::*;
 use rand::Rng;
 
-use crate::camera::Camera;
+use crate::camera::{Camera, MovementState};
 use crate::world::{World, BlockType, TorchFace, ItemStack, Tool, ToolType, ToolMaterial};
 use crate::ui::{Inventory, UIRenderer, DebugInfo, PauseMenu, ChestUI, CraftingUI, RecipeRegistry};
 use crate::entity::{EntityManager, Villager, VillagerState, VILLAGER_HEIGHT};
@@ -208,6 +208,56 @@ struct BloomUniform {
 }
 
 const MAX_POINT_LIGHTS: usize = 32;
+/// Thickness of sign boards in blocks
+const SIGN_BOARD_THICKNESS: f32 = 0.08;
+/// Sign text is only drawn within this many blocks of the camera
+const SIGN_TEXT_DISTANCE: f32 = 16.0;
+/// Player models that fit in the player mesh buffers (the local player plus others sharing the world)
+const MAX_PLAYER_MODELS: usize = 8;
+/// Size of one skin pixel in blocks: a 32-pixel-tall model stands 1.8 blocks
+const PLAYER_PIXEL: f32 = 1.8 / 32.0;
+/// Shader ID for faces textured from the player skin
+const PLAYER_SKIN_SHADER_ID: f32 = 70.0;
+/// Boxes in one player model: six body parts plus the armor plates drawn over them
+const PLAYER_MODEL_BOXES: usize = 16;
+
+/// Everything needed to pose one player model
+pub struct PlayerPose {
+    pub feet: Point3<f32>,   // Bottom of the body (the seat when sitting)
+    pub yaw: f32,            // Facing, in camera degrees
+    pub head_pitch: f32,     // Degrees, positive looks up
+    pub walk_phase: f32,     // Radians into the walk cycle
+    pub walk_amount: f32,    // 0 standing, 1 walking, above 1 sprinting
+    pub arm_swing: f32,      // 1 at the start of a swing, falling to 0
+    pub swimming: bool,
+    pub crawling: bool,
+    pub sneaking: bool,
+    pub sitting: bool,
+    pub death: f32,          // 0 alive, 1 fallen over
+    pub armor: [Option<ToolMaterial>; 4],  // Worn helmet, chestplate, leggings, boots
+}
+
+impl PlayerPose {
+    /// Pose the local player from their camera, dressed in the armor they wear
+    pub fn from_camera(camera: &Camera, arm_swing: f32, armor: &[Option<Tool>; 4]) -> Self {
+        let speed = camera.horizontal_speed();
+        Self {
+            feet: camera.feet_position(),
+            yaw: camera.yaw,
+            head_pitch: camera.pitch,
+            // Steps come quicker the faster the player moves
+            walk_phase: camera.animation_time() * (6.0 + speed * 0.6),
+            walk_amount: (speed / 4.3).min(1.5),
+            arm_swing,
+            swimming: camera.movement_state == MovementState::Swimming,
+            crawling: camera.movement_state == MovementState::Crawling,
+            sneaking: camera.movement_state == MovementState::Sneaking,
+            sitting: camera.is_riding(),
+            death: camera.death_progress(),
+            armor: armor.each_ref().map(|piece| piece.as_ref().map(|tool| tool.material)),
+        }
+    }
+}
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
@@ -299,6 +349,10 @@ pub struct Renderer {
     villager_vertex_buffer: wgpu::Buffer,
     villager_index_buffer: wgpu::Buffer,
     villager_index_count: u32,
+    // Player body (third-person and free camera)
+    player_vertex_buffer: wgpu::Buffer,
+    player_index_buffer: wgpu::Buffer,
+    player_index_count: u32,
     // Particle rendering
     particle_pipeline: wgpu::RenderPipeline,
     particle_vertex_buffer: wgpu::Buffer,
@@ -329,6 +383,10 @@ pub struct Renderer {
     plane_vertex_buffer: wgpu::Buffer,
     plane_index_buffer: wgpu::Buffer,
     plane_index_count: u32,
+    // Boat and minecart rendering
+    vehicle_vertex_buffer: wgpu::Buffer,
+    vehicle_index_buffer: wgpu::Buffer,
+    vehicle_index_count: u32,
     // Missile rendering
     missile_vertex_buffer: wgpu::Buffer,
     missile_index_buffer: wgpu::Buffer,
@@ -427,6 +485,115 @@ impl Renderer {
         texture
     }
 
+    /// Load a 64x64 player skin in the standard layout, falling back to a generated default.
+    /// Legacy 64x32 skins are widened by copying the right arm and leg onto the left.
+    fn load_player_skin(device: &wgpu::Device, queue: &wgpu::Queue, path: &str) -> wgpu::Texture {
+        const SKIN_SIZE: u32 = 64;
+        let mut skin = match image::open(path) {
+            Ok(img) if img.dimensions() == (SKIN_SIZE, SKIN_SIZE) => img.to_rgba8(),
+            Ok(img) if img.dimensions() == (SKIN_SIZE, SKIN_SIZE / 2) => {
+                let mut wide = image::RgbaImage::new(SKIN_SIZE, SKIN_SIZE);
+                image::imageops::replace(&mut wide, &img.to_rgba8(), 0, 0);
+                let right_leg = image::imageops::crop_imm(&wide, 0, 16, 16, 16).to_image();
+                let right_arm = image::imageops::crop_imm(&wide, 40, 16, 16, 16).to_image();
+                image::imageops::replace(&mut wide, &right_leg, 16, 48);
+                image::imageops::replace(&mut wide, &right_arm, 32, 48);
+                wide
+            }
+            _ => Self::generate_default_skin(),
+        };
+        // Nothing is drawn over the skin, so see-through pixels just show their colour
+        for pixel in skin.pixels_mut() {
+            pixel[3] = 255;
+        }
+
+        let size = wgpu::Extent3d {
+            width: SKIN_SIZE,
+            height: SKIN_SIZE,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Player Skin"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &skin,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * SKIN_SIZE),
+                rows_per_image: Some(SKIN_SIZE),
+            },
+            size,
+        );
+
+        texture
+    }
+
+    /// Default skin: brown hair, teal shirt with short sleeves, blue trousers and grey shoes
+    fn generate_default_skin() -> image::RgbaImage {
+        let skin = image::Rgba([196u8, 150, 110, 255]);
+        let hair = image::Rgba([70u8, 45, 25, 255]);
+        let shirt = image::Rgba([0u8, 160, 160, 255]);
+        let trousers = image::Rgba([60u8, 60, 150, 255]);
+        let shoes = image::Rgba([90u8, 90, 90, 255]);
+        let eye_white = image::Rgba([240u8, 240, 240, 255]);
+        let eye = image::Rgba([60u8, 80, 170, 255]);
+        let mouth = image::Rgba([150u8, 95, 70, 255]);
+
+        let mut img = image::RgbaImage::new(64, 64);
+        let fill = |img: &mut image::RgbaImage, x: u32, y: u32, w: u32, h: u32, color: image::Rgba<u8>| {
+            for py in y..y + h {
+                for px in x..x + w {
+                    img.put_pixel(px, py, color);
+                }
+            }
+        };
+
+        // Head (8x8x8 at 0,0): hair on top, at the back and across the brow
+        fill(&mut img, 0, 0, 32, 16, skin);
+        fill(&mut img, 8, 0, 8, 8, hair);
+        fill(&mut img, 0, 8, 32, 2, hair);
+        fill(&mut img, 24, 8, 8, 8, hair);
+        fill(&mut img, 0, 10, 2, 2, hair);
+        fill(&mut img, 22, 10, 2, 2, hair);
+        img.put_pixel(9, 12, eye_white);
+        img.put_pixel(10, 12, eye);
+        img.put_pixel(13, 12, eye);
+        img.put_pixel(14, 12, eye_white);
+        fill(&mut img, 11, 14, 2, 1, mouth);
+
+        // Body (8x12x4 at 16,16)
+        fill(&mut img, 16, 16, 24, 16, shirt);
+
+        // Arms (4x12x4 at 40,16 and 32,48): sleeve over the shoulder, bare below
+        for (u, v) in [(40, 16), (32, 48)] {
+            fill(&mut img, u, v, 16, 16, skin);
+            fill(&mut img, u + 4, v, 4, 4, shirt);
+            fill(&mut img, u, v + 4, 16, 4, shirt);
+        }
+
+        // Legs (4x12x4 at 0,16 and 16,48): trousers with shoes at the bottom
+        for (u, v) in [(0, 16), (16, 48)] {
+            fill(&mut img, u, v, 16, 16, trousers);
+            fill(&mut img, u + 8, v, 4, 4, shoes);
+            fill(&mut img, u, v + 14, 16, 2, shoes);
+        }
+
+        img
+    }
+
     /// Generate a 256x256 UI atlas texture with:
     /// - Rows 0-3 (Y=0-63): UI elements (slots, 9-slice panels) in 16x16 cells
     /// - Rows 4-15 (Y=64-255): Bitmap font (8x8 glyphs in 16x16 cells)
@@ -1013,6 +1180,17 @@ impl Renderer {
                     ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                     count: None,
                 },
+                // Player skin texture
+                wgpu::BindGroupLayoutEntry {
+                    binding: 13,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    },
+                    count: None,
+                },
             ],
             label: Some("texture_bind_group_layout"),
         });
@@ -1719,6 +1897,8 @@ impl Renderer {
         let torch_texture = Self::load_texture(&device, &queue, "src/textures/torch.png")
             .unwrap_or_else(|_| Self::create_fallback_texture(&device, &queue, [255, 180, 100, 255]));
 
+        let skin_texture = Self::load_player_skin(&device, &queue, "src/textures/player_skin.png");
+
         // Generate UI atlas programmatically
         let ui_atlas_texture = Self::generate_ui_atlas(&device, &queue);
 
@@ -1734,6 +1914,7 @@ impl Renderer {
         let snow_view = snow_texture.create_view(&wgpu::TextureViewDescriptor::default());
         let torch_view = torch_texture.create_view(&wgpu::TextureViewDescriptor::default());
         let ui_atlas_view = ui_atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let skin_view = skin_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
         // Create sampler
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -1802,6 +1983,10 @@ impl Renderer {
                     binding: 12,
                     resource: wgpu::BindingResource::Sampler(&sampler), // Reuse nearest-neighbor sampler
                 },
+                wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: wgpu::BindingResource::TextureView(&skin_view),
+                },
             ],
             label: Some("texture_bind_group"),
         });
@@ -2160,6 +2345,21 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        // Player model buffers (head, body, two arms, two legs and armor per player)
+        let max_player_vertices = MAX_PLAYER_MODELS * PLAYER_MODEL_BOXES * 24;
+        let player_vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Player Vertex Buffer"),
+            size: (max_player_vertices * std::mem::size_of::<Vertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let player_index_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Player Index Buffer"),
+            size: (max_player_vertices * 6 / 4 * std::mem::size_of::<u16>()) as u64,
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Particle system setup
         let particle_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             entries: &[
@@ -2304,6 +2504,21 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        // Boat and minecart buffers (50 vehicles max, each with up to 10 parts * 24 vertices)
+        let vehicle_vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Vehicle Vertex Buffer"),
+            size: (crate::entity::MAX_VEHICLES * 10 * 24 * std::mem::size_of::<Vertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let vehicle_index_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Vehicle Index Buffer"),
+            size: (crate::entity::MAX_VEHICLES * 10 * 36 * std::mem::size_of::<u16>()) as u64,
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Missile buffers (50 missiles max, simple elongated cube)
         let missile_vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Missile Vertex Buffer"),
@@ -2473,6 +2688,9 @@ impl Renderer {
             villager_vertex_buffer,
             villager_index_buffer,
             villager_index_count: 0,
+            player_vertex_buffer,
+            player_index_buffer,
+            player_index_count: 0,
             // Particle rendering
             particle_pipeline,
             particle_vertex_buffer,
@@ -2503,6 +2721,10 @@ impl Renderer {
             plane_vertex_buffer,
             plane_index_buffer,
             plane_index_count: 0,
+            // Boats and minecarts
+            vehicle_vertex_buffer,
+            vehicle_index_buffer,
+            vehicle_index_count: 0,
             // Missiles
             missile_vertex_buffer,
             missile_index_buffer,
@@ -2525,7 +2747,7 @@ impl Renderer {
         let cam_y = camera_pos.y as i32;
         let cam_z = camera_pos.z as i32;
 
-        // Search nearby blocks for torches and lava
+        // Search nearby blocks for torches, fire and lava
         for x in (cam_x - search_radius)..(cam_x + search_radius) {
             for z in (cam_z - search_radius)..(cam_z + search_radius) {
                 for y in (cam_y - 20).max(0)..(cam_y + 20).min(World::CHUNK_HEIGHT as i32) {
@@ -2537,12 +2759,30 @@ impl Renderer {
                                 color: [1.0, 0.8, 0.4],  // Warm orange glow
                                 intensity: 1.5,
                             }),
+                            BlockType::Fire => Some(PointLight {
+                                position: [x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5],
+                                radius: 13.0,
+                                color: [1.0, 0.6, 0.2],  // Flickering orange
+                                intensity: 1.8,
+                            }),
                             BlockType::Lava => Some(PointLight {
                                 position: [x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5],
                                 radius: 15.0,  // Larger than torch
                                 color: [1.0, 0.4, 0.1],  // Orange-red glow
                                 intensity: 2.0,  // Brighter than torch
                             }),
+                            BlockType::RedstoneLampLit => Some(PointLight {
+                                position: [x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5],
+                                radius: 14.0,
+                                color: [1.0, 0.85, 0.55],  // Warm lamp light
+                                intensity: 1.8,
+                            }),
+                            BlockType::RedstoneTorch if world.get_redstone_data(x, y, z).is_some_and(|d| d.powered) => Some(PointLight {
+                                position: [x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5],
+                                radius: 6.0,   // Dim compared to a real torch
+                                color: [1.0, 0.2, 0.1],  // Red glow
+                                intensity: 0.8,
+                            }),
                             _ => None,
                         };
 
@@ -2720,7 +2960,7 @@ impl Renderer {
         self.time_of_day = time.fract();
     }
 
-    pub fn render(&mut self, camera: &Camera, world: &mut World, inventory: &Inventory, targeted_block: Option<(i32, i32, i32)>, entity_manager: &EntityManager, particle_system: &ParticleSystem, underwater: bool, debug_info: &DebugInfo, pause_menu: &PauseMenu, chest_ui: &ChestUI, crafting_ui: &CraftingUI, furnace_ui: &crate::ui::FurnaceUI, recipe_registry: &RecipeRegistry, lightning_system: &LightningSystem, weather_state: &WeatherState) {
+    pub fn render(&mut self, camera: &Camera, world: &mut World, inventory: &Inventory, targeted_block: Option<(i32, i32, i32)>, entity_manager: &EntityManager, particle_system: &ParticleSystem, underwater: bool, debug_info: &DebugInfo, pause_menu: &PauseMenu, chest_ui: &ChestUI, crafting_ui: &CraftingUI, furnace_ui: &crate::ui::FurnaceUI, trading_ui: &crate::ui::TradingUI, sign_ui: &crate::ui::SignEditUI, recipe_registry: &RecipeRegistry, lightning_system: &LightningSystem, weather_state: &WeatherState) {
         let now = Instant::now();
         let dt = (now - self.last_render).as_secs_f32();
         self.last_render = now;
@@ -2756,6 +2996,11 @@ impl Renderer {
             ambient_intensity = ambient_intensity.max(0.8 * lightning_system.sky_flash);
         }
 
+        // Night vision lights up caves and nights as if it were day
+        if camera.effects.has(crate::effect::StatusEffectType::NightVision) {
+            ambient_intensity = ambient_intensity.max(0.6);
+        }
+
         // Sun color changes with time of day
         let sun_color = if sun_direction.y < 0.0 {
             [0.1, 0.1, 0.2]  // Moonlight (blue-ish)
@@ -2773,8 +3018,15 @@ impl Renderer {
         // Update villager mesh
         self.update_villager_mesh(entity_manager.get_villagers());
 
+        // Update player models (the local player is hidden in first person)
+        let mut player_poses = Vec::new();
+        if camera.shows_player_body() {
+            player_poses.push(PlayerPose::from_camera(camera, self.arm_swing_progress, &inventory.armor));
+        }
+        self.update_player_mesh(&player_poses);
+
         // Update animal mesh
-        self.update_animal_mesh(entity_manager.get_animals());
+        self.update_animal_mesh(entity_manager.get_animals(), camera.position);
 
         // Update hostile mob mesh (including projectiles)
         self.update_hostile_mob_mesh(entity_manager.get_hostile_mobs(), entity_manager.get_projectiles());
@@ -2782,6 +3034,9 @@ impl Renderer {
         // Update plane mesh
         self.update_plane_mesh(entity_manager.get_planes());
 
+        // Update boat and minecart mesh
+        self.update_vehicle_mesh(entity_manager.get_vehicles());
+
         // Update missile mesh
         self.update_missile_mesh(entity_manager.get_missiles());
 
@@ -2806,7 +3061,7 @@ impl Renderer {
         let uniform = Uniform {
             view_proj: view_proj.into(),
             inverse_view_proj: inverse_view_proj.into(),
-            camera_pos: [camera.position.x, camera.position.y, camera.position.z],
+            camera_pos: [camera.view_position().x, camera.view_position().y, camera.view_position().z],
             time_of_day: self.time_of_day,
             sun_direction: [sun_direction.x, sun_direction.y, sun_direction.z],
             ambient_intensity,
@@ -2904,6 +3159,12 @@ impl Renderer {
                 shadow_pass.draw_indexed(0..self.villager_index_count, 0, 0..1);
             }
 
+            if self.player_index_count > 0 {
+                shadow_pass.set_vertex_buffer(0, self.player_vertex_buffer.slice(..));
+                shadow_pass.set_index_buffer(self.player_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+                shadow_pass.draw_indexed(0..self.player_index_count, 0, 0..1);
+            }
+
             // Render animals to shadow map
             if self.animal_index_count > 0 {
                 shadow_pass.set_vertex_buffer(0, self.animal_vertex_buffer.slice(..));
@@ -2998,6 +3259,13 @@ impl Renderer {
                 render_pass.draw_indexed(0..self.villager_index_count, 0, 0..1);
             }
 
+            // Render the player's body
+            if self.player_index_count > 0 {
+                render_pass.set_vertex_buffer(0, self.player_vertex_buffer.slice(..));
+                render_pass.set_index_buffer(self.player_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+                render_pass.draw_indexed(0..self.player_index_count, 0, 0..1);
+            }
+
             // Render animals
             if self.animal_index_count > 0 {
                 render_pass.set_vertex_buffer(0, self.animal_vertex_buffer.slice(..));
@@ -3019,6 +3287,13 @@ impl Renderer {
                 render_pass.draw_indexed(0..self.plane_index_count, 0, 0..1);
             }
 
+            // Render boats and minecarts
+            if self.vehicle_index_count > 0 {
+                render_pass.set_vertex_buffer(0, self.vehicle_vertex_buffer.slice(..));
+                render_pass.set_index_buffer(self.vehicle_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+                render_pass.draw_indexed(0..self.vehicle_index_count, 0, 0..1);
+            }
+
             // Render missiles
             if self.missile_index_count > 0 {
                 render_pass.set_vertex_buffer(0, self.missile_vertex_buffer.slice(..));
@@ -3103,8 +3378,8 @@ impl Renderer {
                 render_pass.draw_indexed(0..72, 0, 0..1);
             }
 
-            // Only render held item when not piloting a plane
-            if !camera.is_piloting() {
+            // Only render held item in first person and when not piloting a plane
+            if !camera.is_piloting() && !camera.shows_player_body() {
                 let opt_item = inventory.get_selected_item();
                 let vertices = Self::create_held_item_vertices(camera, opt_item, self.arm_swing_progress);
                 self.queue.write_buffer(&self.held_item_vertex_buffer, 0, bytemuck::cast_slice(&vertices));
@@ -3228,6 +3503,19 @@ impl Renderer {
             post_pass.draw(0..3, 0..1);
         }
 
+        // === SIGN TEXT ===
+        let signs = Self::visible_sign_text(camera, world);
+        if !signs.is_empty() {
+            self.ui_renderer.render_sign_text(
+                &self.device,
+                &mut encoder,
+                &view,
+                &self.texture_bind_group,
+                camera.view_proj,
+                &signs,
+            );
+        }
+
         // === UI PASS ===
         // Only render hotbar/crosshair when crafting UI is not open
         if !crafting_ui.open {
@@ -3273,7 +3561,7 @@ impl Renderer {
                 &self.queue,
                 &view,
                 &self.texture_bind_group,
-                pause_menu.selected_option,
+                pause_menu,
             );
         }
 
@@ -3326,8 +3614,48 @@ impl Renderer {
             }
         }
 
+        // Render trading UI
+        if trading_ui.open {
+            if let Some(villager) = trading_ui.villager_id.and_then(|id| entity_manager.get_villager(id)) {
+                self.ui_renderer.render_trading_ui(
+                    &self.device,
+                    &self.queue,
+                    &view,
+                    &self.texture_bind_group,
+                    trading_ui,
+                    villager,
+                    inventory,
+                );
+            }
+        }
+
+        // Render sign editor
+        if sign_ui.open {
+            self.ui_renderer.render_sign_editor(
+                &self.device,
+                &self.queue,
+                &view,
+                &self.texture_bind_group,
+                sign_ui,
+            );
+        }
+
+        // Render flight HUD while piloting
+        if !pause_menu.visible {
+            if let Some(plane) = camera.piloted_plane_id.and_then(|id| entity_manager.get_planes().iter().find(|p| p.id == id)) {
+                self.ui_renderer.render_flight_hud(
+                    &self.device,
+                    &self.queue,
+                    &view,
+                    &self.texture_bind_group,
+                    plane,
+                    pause_menu.flight_model,
+                );
+            }
+        }
+
         // Render survival UI (health, hunger, air)
-        if !pause_menu.visible && !chest_ui.open && !crafting_ui.open {
+        if !pause_menu.visible && !chest_ui.open && !crafting_ui.open && !sign_ui.open {
             self.ui_renderer.render_survival_ui(
                 &self.device,
                 &self.queue,
@@ -3336,12 +3664,24 @@ impl Renderer {
                 camera.health,
                 camera.max_health,
                 camera.hunger,
+                camera.armor_points,
                 camera.air_supply,
                 underwater,
                 camera.damage_flash > 0.0,
             );
         }
 
+        // Render status effect icons and timers
+        if !pause_menu.visible && !chest_ui.open && !crafting_ui.open && !sign_ui.open && !camera.effects.is_empty() {
+            self.ui_renderer.render_status_effects(
+                &self.device,
+                &self.queue,
+                &view,
+                &self.texture_bind_group,
+                &camera.effects,
+            );
+        }
+
         // Render death screen
         if camera.is_dead {
             self.ui_renderer.render_death_screen(
@@ -3758,6 +4098,42 @@ impl Renderer {
             &mut trans_vertices, &mut trans_indices,
         );
 
+        // Render redstone wire, levers, buttons, plates, torches and repeaters
+        Self::render_redstone(
+            world, chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
+        // Render rails shaped by their connections
+        Self::render_rails(
+            world, chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
+        // Render crops as crossed quads that grow taller with each stage
+        Self::render_crops(
+            world, chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
+        // Render fire as crossed sheets of flame
+        Self::render_fire(
+            chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
+        // Render lightning rods as a thin post with a knob on top
+        Self::render_lightning_rods(
+            chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
+        // Render signs as thin boards (text is drawn separately each frame)
+        Self::render_signs(
+            world, chunk, chunk_x_offset, chunk_z_offset,
+            &mut opaque_vertices, &mut opaque_indices,
+        );
+
         (opaque_vertices, opaque_indices, trans_vertices, trans_indices)
     }
 
@@ -3778,6 +4154,8 @@ impl Renderer {
                     let block_type = chunk.blocks[x][y][z];
                     if block_type == BlockType::Air || block_type == BlockType::Barrier || block_type == BlockType::Torch
                        || block_type == BlockType::Ladder || block_type.is_trapdoor() || block_type.is_fence() || block_type == BlockType::GlassPane
+                       || block_type.is_redstone_component() || block_type.is_plant() || block_type.is_sign() || block_type == BlockType::Rail || block_type == BlockType::Fire
+                       || block_type == BlockType::LightningRod
                        || block_type.is_bottom_slab() || block_type.is_top_slab() || block_type.is_stairs() {
                         continue;  // Torches, slabs, stairs, ladders, and trapdoors are rendered separately with special geometry
                     }
@@ -5211,97 +5589,679 @@ impl Renderer {
     }
 
     // Greedy mesh for horizontal faces (top/bottom) - iterates Y layers, merges in XZ plane
-    fn greedy_mesh_horizontal<F>(
+    // Render redstone components with small custom geometry
+    fn render_redstone(
         world: &World,
         chunk: &crate::world::Chunk,
         chunk_x_offset: i32,
         chunk_z_offset: i32,
-        neighbor_y: F,
-        face: Face,
         opaque_vertices: &mut Vec<Vertex>,
         opaque_indices: &mut Vec<u16>,
-        trans_vertices: &mut Vec<Vertex>,
-        trans_indices: &mut Vec<u16>,
-    ) where F: Fn(i32) -> i32 {
-        let size = World::CHUNK_SIZE;
-        let height = World::CHUNK_HEIGHT;
-
-        for y in 0..height {
-            // Build mask of exposed faces at this Y level
-            let mut mask: [[Option<BlockType>; 16]; 16] = [[None; 16]; 16];
-
-            for x in 0..size {
-                for z in 0..size {
+    ) {
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
                     let block_type = chunk.blocks[x][y][z];
-                    if block_type == BlockType::Air || block_type == BlockType::Barrier || block_type == BlockType::Torch
-                       || block_type == BlockType::Ladder || block_type.is_trapdoor() || block_type.is_fence() || block_type == BlockType::GlassPane
-                       || block_type.is_bottom_slab() || block_type.is_top_slab() || block_type.is_stairs() {
-                        continue;  // Torches, slabs, stairs, ladders, and trapdoors are rendered separately with special geometry
+                    if !block_type.is_redstone_component() {
+                        continue;
                     }
 
                     let world_x = chunk_x_offset + x as i32;
+                    let world_y = y as i32;
                     let world_z = chunk_z_offset + z as i32;
-                    let check_y = neighbor_y(y as i32);
 
-                    // Skip damaged blocks - they're rendered separately with crack effects
-                    if world.get_block_damage(world_x, y as i32, world_z) > 0.0 {
-                        continue;
-                    }
+                    let base_x = world_x as f32;
+                    let base_y = world_y as f32;
+                    let base_z = world_z as f32;
 
-                    if Self::is_face_exposed(world, world_x, check_y, world_z, block_type) {
-                        mask[x][z] = Some(block_type);
+                    let powered = world.get_redstone_data(world_x, world_y, world_z)
+                        .is_some_and(|d| d.powered);
+                    let block_type_f = Self::block_type_to_float(block_type);
+
+                    match block_type {
+                        BlockType::RedstoneWire => {
+                            // Thin dust lines toward each connection, center dot always drawn
+                            let wire_f = if powered { 53.0 } else { 52.0 };
+                            let (north, south, east, west) = world.wire_connections(world_x, world_y, world_z);
+                            let h = 0.0625;
+                            let lo = 0.375;
+                            let hi = 0.625;
+
+                            Self::add_fence_post(opaque_vertices, opaque_indices,
+                                base_x + lo, base_y, base_z + lo,
+                                hi - lo, h, hi - lo, wire_f);
+                            if north {
+                                Self::add_fence_post(opaque_vertices, opaque_indices,
+                                    base_x + lo, base_y, base_z,
+                                    hi - lo, h, lo, wire_f);
+                            }
+                            if south {
+                                Self::add_fence_post(opaque_vertices, opaque_indices,
+                                    base_x + lo, base_y, base_z + hi,
+                                    hi - lo, h, 1.0 - hi, wire_f);
+                            }
+                            if east {
+                                Self::add_fence_post(opaque_vertices, opaque_indices,
+                                    base_x + hi, base_y, base_z + lo,
+                                    1.0 - hi, h, hi - lo, wire_f);
+                            }
+                            if west {
+                                Self::add_fence_post(opaque_vertices, opaque_indices,
+                                    base_x, base_y, base_z + lo,
+                                    lo, h, hi - lo, wire_f);
+                            }
+                        }
+                        BlockType::PressurePlate => {
+                            // Plate sinks slightly while something stands on it
+                            let h = if powered { 0.03125 } else { 0.0625 };
+                            Self::add_fence_post(opaque_vertices, opaque_indices,
+                                base_x + 0.0625, base_y, base_z + 0.0625,
+                                0.875, h, 0.875, block_type_f);
+                        }
+                        BlockType::Repeater => {
+                            // Stone slab with an input and output nub showing direction
+                            Self::add_fence_post(opaque_vertices, opaque_indices,
+                                base_x, base_y, base_z,
+                                1.0, 0.125, 1.0, block_type_f);
+
+                            let facing = world.get_redstone_data(world_x, world_y, world_z)
+                                .map_or(crate::world::BlockFacing::North, |d| d.facing);
+                            let (dx, dz) = facing.offset();
+                            let nub_f = if powered { 53.0 } else { 52.0 };
+                            let nub = 0.125;
+                            // Output nub near the front edge, input nub near the back edge
+                            for dist in [0.3, -0.25] {
+                                let cx = base_x + 0.5 + dx as f32 * dist;
+                                let cz = base_z + 0.5 + dz as f32 * dist;
+                                Self::add_fence_post(opaque_vertices, opaque_indices,
+                                    cx - nub / 2.0, base_y + 0.125, cz - nub / 2.0,
+                                    nub, 0.25, nub, nub_f);
+                            }
+                        }
+                        BlockType::Lever | BlockType::StoneButton | BlockType::RedstoneTorch => {
+                            let face = world.get_torch_face(world_x, world_y, world_z)
+                                .unwrap_or(TorchFace::Top);
+                            Self::add_wall_redstone(opaque_vertices, opaque_indices,
+                                base_x, base_y, base_z, block_type, face, powered);
+                        }
+                        _ => {}
                     }
                 }
             }
+        }
+    }
 
-            // Greedy merge
-            let mut visited = [[false; 16]; 16];
+    // Render rails as sleepers under two iron bars, laid out to match their connections.
+    // Slopes climb in four short steps
+    fn render_rails(
+        world: &World,
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+    ) {
+        use crate::world::RailShape;
 
-            for start_x in 0..size {
-                for start_z in 0..size {
-                    if visited[start_x][start_z] || mask[start_x][start_z].is_none() {
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
+                    if chunk.blocks[x][y][z] != BlockType::Rail {
                         continue;
                     }
 
-                    let block_type = mask[start_x][start_z].unwrap();
-                    let is_water = block_type == BlockType::Water;
+                    let world_x = chunk_x_offset + x as i32;
+                    let world_y = y as i32;
+                    let world_z = chunk_z_offset + z as i32;
+                    let base = (world_x as f32, world_y as f32, world_z as f32);
 
-                    // Water blocks skip merging - each needs individual depth value
-                    let (width, depth) = if is_water {
-                        (1, 1)
-                    } else {
-                        // Find width (extend in X)
-                        let mut w = 1;
-                        while start_x + w < size
-                            && !visited[start_x + w][start_z]
-                            && mask[start_x + w][start_z] == Some(block_type)
-                        {
-                            w += 1;
+                    match world.rail_shape(world_x, world_y, world_z) {
+                        straight @ (RailShape::NorthSouth | RailShape::EastWest) => {
+                            let facing = straight.exits()[0];
+                            for start in [-0.5, 0.0] {
+                                Self::add_rail_piece(opaque_vertices, opaque_indices, base, facing, start, 0.5, 0.0);
+                            }
                         }
-
-                        // Find depth (extend in Z)
-                        let mut d = 1;
-                        'outer: while start_z + d < size {
-                            for dx in 0..w {
-                                if visited[start_x + dx][start_z + d]
-                                    || mask[start_x + dx][start_z + d] != Some(block_type)
-                                {
-                                    break 'outer;
-                                }
+                        RailShape::Ascending(up) => {
+                            for (step, start) in [-0.5, -0.25, 0.0, 0.25].into_iter().enumerate() {
+                                Self::add_rail_piece(opaque_vertices, opaque_indices, base, up, start, 0.25, step as f32 * 0.25 + 0.125);
                             }
-                            d += 1;
                         }
-                        (w, d)
-                    };
-
-                    // Mark as visited
-                    for dx in 0..width {
-                        for dz in 0..depth {
-                            visited[start_x + dx][start_z + dz] = true;
+                        RailShape::Curve(a, b) => {
+                            // Two half-runs meeting in the middle of the block
+                            for facing in [a, b] {
+                                Self::add_rail_piece(opaque_vertices, opaque_indices, base, facing, 0.0, 0.5, 0.0);
+                            }
                         }
                     }
-
-                    // Generate merged quad
+                }
+            }
+        }
+    }
+
+    /// A short length of track: a sleeper with two bars on top, running along `facing`'s axis.
+    /// `start` is where the piece begins, measured from the block centre toward `facing`
+    fn add_rail_piece(
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u16>,
+        (base_x, base_y, base_z): (f32, f32, f32),
+        facing: crate::world::BlockFacing,
+        start: f32,
+        length: f32,
+        lift: f32,
+    ) {
+        let rail_f = Self::block_type_to_float(BlockType::Rail);
+        let tie_f = Self::block_type_to_float(BlockType::Planks);
+        let (dx, dz) = facing.offset();
+        let dir = (dx + dz) as f32;
+        let near = 0.5 + start * dir;
+        let far = near + length * dir;
+        let lo = near.min(far);
+        let tie = lo + length / 2.0 - 0.0625;
+        let y = base_y + lift;
+        let h = 0.0625;
+
+        if dx != 0 {
+            Self::add_fence_post(vertices, indices,
+                base_x + tie, y, base_z + 0.0625,
+                0.125, h, 0.875, tie_f);
+            for side in [0.1875, 0.6875] {
+                Self::add_fence_post(vertices, indices,
+                    base_x + lo, y + h, base_z + side,
+                    length, h, 0.125, rail_f);
+            }
+        } else {
+            Self::add_fence_post(vertices, indices,
+                base_x + 0.0625, y, base_z + tie,
+                0.875, h, 0.125, tie_f);
+            for side in [0.1875, 0.6875] {
+                Self::add_fence_post(vertices, indices,
+                    base_x + side, y + h, base_z + lo,
+                    0.125, h, length, rail_f);
+            }
+        }
+    }
+
+    // Render crops and saplings as two diagonal quads (X shape); crops scale by growth stage
+    fn render_crops(
+        world: &World,
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+    ) {
+        use crate::world::CROP_MAX_STAGE;
+
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
+                    let block_type = chunk.blocks[x][y][z];
+                    if !block_type.is_plant() {
+                        continue;
+                    }
+
+                    let world_x = chunk_x_offset + x as i32;
+                    let world_y = y as i32;
+                    let world_z = chunk_z_offset + z as i32;
+
+                    let stage = if block_type.is_crop() {
+                        world.get_crop_stage(world_x, world_y, world_z)
+                    } else {
+                        CROP_MAX_STAGE
+                    };
+                    let growth = stage as f32 / CROP_MAX_STAGE as f32;
+
+                    // Wheat grows to a full block, root crops stay low and leafy
+                    let max_height = match block_type {
+                        BlockType::WheatCrop => 0.95,
+                        BlockType::Sapling => 0.8,
+                        _ => 0.6,
+                    };
+                    let height = 0.15 + (max_height - 0.15) * growth;
+
+                    let block_type_f = if stage < CROP_MAX_STAGE || block_type == BlockType::Sapling {
+                        Self::block_type_to_float(block_type)
+                    } else {
+                        match block_type {
+                            BlockType::WheatCrop => 59.0,  // Golden wheat
+                            BlockType::CarrotCrop => 60.0, // Leaves with orange roots showing
+                            _ => 61.0,                     // Leaves with potato tops showing
+                        }
+                    };
+
+                    let x0 = world_x as f32 + 0.1;
+                    let x1 = world_x as f32 + 0.9;
+                    let z0 = world_z as f32 + 0.1;
+                    let z1 = world_z as f32 + 0.9;
+                    let y0 = world_y as f32;
+                    let y1 = y0 + height;
+
+                    // Diagonal from (-X,-Z) to (+X,+Z)
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x0, y0, z0], [x1, y0, z1], [x1, y1, z1], [x0, y1, z0],
+                        [0.707, 0.0, -0.707], block_type_f, 0.0);
+                    // Diagonal from (+X,-Z) to (-X,+Z)
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x1, y0, z0], [x0, y0, z1], [x0, y1, z1], [x1, y1, z0],
+                        [0.707, 0.0, 0.707], block_type_f, 0.0);
+                }
+            }
+        }
+    }
+
+    // Fire: two diagonal sheets of flame filling the block, plus one just inside each side
+    fn render_fire(
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+    ) {
+        let block_type_f = Self::block_type_to_float(BlockType::Fire);
+
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
+                    if chunk.blocks[x][y][z] != BlockType::Fire {
+                        continue;
+                    }
+
+                    let x0 = (chunk_x_offset + x as i32) as f32;
+                    let z0 = (chunk_z_offset + z as i32) as f32;
+                    let x1 = x0 + 1.0;
+                    let z1 = z0 + 1.0;
+                    let y0 = y as f32;
+                    let y1 = y0 + 1.0;
+                    let inset = 0.1;
+
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x0, y0, z0], [x1, y0, z1], [x1, y1, z1], [x0, y1, z0],
+                        [0.707, 0.0, -0.707], block_type_f, 0.0);
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x1, y0, z0], [x0, y0, z1], [x0, y1, z1], [x1, y1, z0],
+                        [0.707, 0.0, 0.707], block_type_f, 0.0);
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x0, y0, z0 + inset], [x1, y0, z0 + inset], [x1, y1, z0 + inset], [x0, y1, z0 + inset],
+                        [0.0, 0.0, 1.0], block_type_f, 0.0);
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x1, y0, z1 - inset], [x0, y0, z1 - inset], [x0, y1, z1 - inset], [x1, y1, z1 - inset],
+                        [0.0, 0.0, -1.0], block_type_f, 0.0);
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x0 + inset, y0, z1], [x0 + inset, y0, z0], [x0 + inset, y1, z0], [x0 + inset, y1, z1],
+                        [1.0, 0.0, 0.0], block_type_f, 0.0);
+                    Self::add_quad_face(opaque_vertices, opaque_indices,
+                        [x1 - inset, y0, z0], [x1 - inset, y0, z1], [x1 - inset, y1, z1], [x1 - inset, y1, z0],
+                        [-1.0, 0.0, 0.0], block_type_f, 0.0);
+                }
+            }
+        }
+    }
+
+    // Lightning rods: a thin post standing in the middle of the block, capped by a wider knob
+    fn render_lightning_rods(
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+    ) {
+        let block_type_f = Self::block_type_to_float(BlockType::LightningRod);
+        let post = 0.125;
+        let knob = 0.25;
+
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
+                    if chunk.blocks[x][y][z] != BlockType::LightningRod {
+                        continue;
+                    }
+
+                    let base_x = (chunk_x_offset + x as i32) as f32;
+                    let base_y = y as f32;
+                    let base_z = (chunk_z_offset + z as i32) as f32;
+
+                    Self::add_fence_post(opaque_vertices, opaque_indices,
+                        base_x + 0.5 - post / 2.0, base_y, base_z + 0.5 - post / 2.0,
+                        post, 1.0 - knob, post,
+                        block_type_f);
+                    Self::add_fence_post(opaque_vertices, opaque_indices,
+                        base_x + 0.5 - knob / 2.0, base_y + 1.0 - knob, base_z + 0.5 - knob / 2.0,
+                        knob, knob, knob,
+                        block_type_f);
+                }
+            }
+        }
+    }
+
+    // Sign boards: standing signs on a post, wall signs flat against their wall
+    fn render_signs(
+        world: &World,
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+    ) {
+        let block_type_f = Self::block_type_to_float(BlockType::SignPost);
+
+        for x in 0..World::CHUNK_SIZE {
+            for y in 0..World::CHUNK_HEIGHT {
+                for z in 0..World::CHUNK_SIZE {
+                    let block_type = chunk.blocks[x][y][z];
+                    if !block_type.is_sign() {
+                        continue;
+                    }
+
+                    let world_x = chunk_x_offset + x as i32;
+                    let world_y = y as i32;
+                    let world_z = chunk_z_offset + z as i32;
+                    let base_x = world_x as f32;
+                    let base_y = world_y as f32;
+                    let base_z = world_z as f32;
+
+                    let facing = world.get_sign_data(world_x, world_y, world_z)
+                        .map(|d| d.facing)
+                        .unwrap_or(crate::world::BlockFacing::South);
+                    let (out_x, out_z) = facing.offset();
+
+                    if block_type == BlockType::WallSign {
+                        let (x0, z0, w, d) = Self::wall_box(base_x, base_z, out_x as f32, out_z as f32, SIGN_BOARD_THICKNESS, 0.875);
+                        Self::add_fence_post(opaque_vertices, opaque_indices,
+                            x0, base_y + 0.25, z0, w, 0.5, d, block_type_f);
+                    } else {
+                        // Post
+                        Self::add_fence_post(opaque_vertices, opaque_indices,
+                            base_x + 0.4375, base_y, base_z + 0.4375,
+                            0.125, 0.45, 0.125, block_type_f);
+                        // Board centered on the post, across the facing direction
+                        let half = SIGN_BOARD_THICKNESS / 2.0;
+                        let (x0, z0, w, d) = if out_x != 0 {
+                            (base_x + 0.5 - half, base_z + 0.0625, SIGN_BOARD_THICKNESS, 0.875)
+                        } else {
+                            (base_x + 0.0625, base_z + 0.5 - half, 0.875, SIGN_BOARD_THICKNESS)
+                        };
+                        Self::add_fence_post(opaque_vertices, opaque_indices,
+                            x0, base_y + 0.45, z0, w, 0.5, d, block_type_f);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Center of a sign's front face and the direction its text runs
+    fn sign_text_anchor(x: i32, y: i32, z: i32, block_type: BlockType, facing: crate::world::BlockFacing) -> (Point3<f32>, Vector3<f32>) {
+        let (out_x, out_z) = facing.offset();
+        let (out_x, out_z) = (out_x as f32, out_z as f32);
+        // Stand the text slightly off the board so it doesn't z-fight
+        let (offset, center_y) = if block_type == BlockType::WallSign {
+            (-0.5 + SIGN_BOARD_THICKNESS + 0.005, 0.5)
+        } else {
+            (SIGN_BOARD_THICKNESS / 2.0 + 0.005, 0.7)
+        };
+        let anchor = Point3::new(
+            x as f32 + 0.5 + out_x * offset,
+            y as f32 + center_y,
+            z as f32 + 0.5 + out_z * offset,
+        );
+        // Viewed from the front, text runs to the viewer's right
+        (anchor, Vector3::new(out_z, 0.0, -out_x))
+    }
+
+    /// Signs close to the camera whose text faces it and isn't hidden behind solid blocks
+    fn visible_sign_text<'w>(camera: &Camera, world: &'w World) -> Vec<(Point3<f32>, Vector3<f32>, &'w [String; 4])> {
+        let mut signs = Vec::new();
+        for (&(x, y, z), data) in &world.sign_data {
+            if data.lines.iter().all(|line| line.is_empty()) {
+                continue;
+            }
+            let Some(block_type) = world.get_block(x, y, z) else { continue };
+            let (anchor, right) = Self::sign_text_anchor(x, y, z, block_type, data.facing);
+
+            let to_camera = camera.view_position() - anchor;
+            let distance = to_camera.magnitude();
+            let (out_x, out_z) = data.facing.offset();
+            if distance > SIGN_TEXT_DISTANCE || to_camera.x * out_x as f32 + to_camera.z * out_z as f32 <= 0.0 {
+                continue;
+            }
+
+            // March from the sign toward the camera looking for blocks in the way
+            let step = to_camera / distance * 0.25;
+            let mut point = anchor;
+            let mut occluded = false;
+            for _ in 0..(distance / 0.25) as usize {
+                point += step;
+                let bx = point.x.floor() as i32;
+                let by = point.y.floor() as i32;
+                let bz = point.z.floor() as i32;
+                if (bx, by, bz) != (x, y, z)
+                    && world.get_block(bx, by, bz).is_some_and(|b| b.collision_shape() == crate::world::CollisionShape::FullBlock)
+                {
+                    occluded = true;
+                    break;
+                }
+            }
+            if !occluded {
+                signs.push((anchor, right, &data.lines));
+            }
+        }
+        signs
+    }
+
+    // Levers, buttons and redstone torches share the torch face attachment rules
+    fn add_wall_redstone(
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u16>,
+        base_x: f32, base_y: f32, base_z: f32,
+        block_type: BlockType,
+        face: TorchFace,
+        powered: bool,
+    ) {
+        let block_type_f = Self::block_type_to_float(block_type);
+
+        // Direction pointing from the supporting block out into this block
+        let (out_x, out_z) = match face {
+            TorchFace::Top => (0.0, 0.0),
+            TorchFace::North => (0.0, -1.0),
+            TorchFace::South => (0.0, 1.0),
+            TorchFace::East => (1.0, 0.0),
+            TorchFace::West => (-1.0, 0.0),
+        };
+
+        match block_type {
+            BlockType::RedstoneTorch => {
+                let hw = 0.0625;
+                let height = if face == TorchFace::Top { 0.625 } else { 0.53 };
+                let cx = base_x + 0.5 - out_x * 0.4;
+                let cz = base_z + 0.5 - out_z * 0.4;
+                let cy = if face == TorchFace::Top { base_y } else { base_y + 0.15 };
+                if powered {
+                    Self::add_torch_stick(vertices, indices,
+                        cx, cy, cz, hw, height,
+                        out_x * 0.35, out_z * 0.35,
+                        block_type_f);
+                } else {
+                    // Burnt out torch: plain stick with no flame
+                    Self::add_torch_stick_unlit(vertices, indices, cx, cy, cz, hw, height, out_x * 0.35, out_z * 0.35);
+                }
+            }
+            BlockType::StoneButton => {
+                // Small box on the attached face, pushed in while pressed
+                let depth = if powered { 0.0625 } else { 0.125 };
+                if face == TorchFace::Top {
+                    Self::add_fence_post(vertices, indices,
+                        base_x + 0.375, base_y, base_z + 0.3125,
+                        0.25, depth, 0.375, block_type_f);
+                } else {
+                    let (x0, z0, w, d) = Self::wall_box(base_x, base_z, out_x, out_z, depth, 0.375);
+                    Self::add_fence_post(vertices, indices,
+                        x0, base_y + 0.375, z0, w, 0.25, d, block_type_f);
+                }
+            }
+            BlockType::Lever => {
+                // Cobblestone base plate plus a stick that flips with the lever state
+                let base_f = Self::block_type_to_float(BlockType::Cobblestone);
+                let stick_tilt = if powered { 0.2 } else { -0.2 };
+                if face == TorchFace::Top {
+                    Self::add_fence_post(vertices, indices,
+                        base_x + 0.3125, base_y, base_z + 0.25,
+                        0.375, 0.125, 0.5, base_f);
+                    Self::add_torch_stick_unlit(vertices, indices,
+                        base_x + 0.5, base_y + 0.125, base_z + 0.5,
+                        0.0625, 0.5, stick_tilt, 0.0);
+                } else {
+                    let (x0, z0, w, d) = Self::wall_box(base_x, base_z, out_x, out_z, 0.125, 0.375);
+                    Self::add_fence_post(vertices, indices,
+                        x0, base_y + 0.25, z0, w, 0.5, d, base_f);
+                    Self::add_torch_stick_unlit(vertices, indices,
+                        base_x + 0.5 - out_x * 0.375, base_y + 0.5, base_z + 0.5 - out_z * 0.375,
+                        0.0625, 0.4,
+                        out_x * 0.3 + stick_tilt * (1.0 - out_x.abs()),
+                        out_z * 0.3 + stick_tilt * (1.0 - out_z.abs()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Box hugging the wall opposite `out`, with the given depth (away from wall) and width along the wall
+    fn wall_box(base_x: f32, base_z: f32, out_x: f32, out_z: f32, depth: f32, width: f32) -> (f32, f32, f32, f32) {
+        let side = (1.0 - width) / 2.0;
+        if out_x > 0.0 {
+            (base_x, base_z + side, depth, width)
+        } else if out_x < 0.0 {
+            (base_x + 1.0 - depth, base_z + side, depth, width)
+        } else if out_z > 0.0 {
+            (base_x + side, base_z, width, depth)
+        } else {
+            (base_x + side, base_z + 1.0 - depth, width, depth)
+        }
+    }
+
+    // Torch-shaped stick without a flame (unlit redstone torches, lever handles)
+    fn add_torch_stick_unlit(
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u16>,
+        cx: f32, cy: f32, cz: f32,
+        hw: f32,
+        height: f32,
+        tilt_x: f32, tilt_z: f32,
+    ) {
+        let block_type = Self::block_type_to_float(BlockType::Lever);
+        let top_cx = cx + tilt_x;
+        let top_cz = cz + tilt_z;
+        let top_y = cy + height;
+
+        let b0 = [cx - hw, cy, cz - hw];
+        let b1 = [cx + hw, cy, cz - hw];
+        let b2 = [cx + hw, cy, cz + hw];
+        let b3 = [cx - hw, cy, cz + hw];
+        let t0 = [top_cx - hw, top_y, top_cz - hw];
+        let t1 = [top_cx + hw, top_y, top_cz - hw];
+        let t2 = [top_cx + hw, top_y, top_cz + hw];
+        let t3 = [top_cx - hw, top_y, top_cz + hw];
+
+        Self::add_quad_face(vertices, indices, b3, b2, t2, t3, [0.0, 0.0, 1.0], block_type, 0.0);
+        Self::add_quad_face(vertices, indices, b1, b0, t0, t1, [0.0, 0.0, -1.0], block_type, 0.0);
+        Self::add_quad_face(vertices, indices, b2, b1, t1, t2, [1.0, 0.0, 0.0], block_type, 0.0);
+        Self::add_quad_face(vertices, indices, b0, b3, t3, t0, [-1.0, 0.0, 0.0], block_type, 0.0);
+        Self::add_quad_face(vertices, indices, t0, t1, t2, t3, [0.0, 1.0, 0.0], block_type, 0.0);
+        Self::add_quad_face(vertices, indices, b3, b2, b1, b0, [0.0, -1.0, 0.0], block_type, 0.0);
+    }
+
+    fn greedy_mesh_horizontal<F>(
+        world: &World,
+        chunk: &crate::world::Chunk,
+        chunk_x_offset: i32,
+        chunk_z_offset: i32,
+        neighbor_y: F,
+        face: Face,
+        opaque_vertices: &mut Vec<Vertex>,
+        opaque_indices: &mut Vec<u16>,
+        trans_vertices: &mut Vec<Vertex>,
+        trans_indices: &mut Vec<u16>,
+    ) where F: Fn(i32) -> i32 {
+        let size = World::CHUNK_SIZE;
+        let height = World::CHUNK_HEIGHT;
+
+        for y in 0..height {
+            // Build mask of exposed faces at this Y level
+            let mut mask: [[Option<BlockType>; 16]; 16] = [[None; 16]; 16];
+
+            for x in 0..size {
+                for z in 0..size {
+                    let block_type = chunk.blocks[x][y][z];
+                    if block_type == BlockType::Air || block_type == BlockType::Barrier || block_type == BlockType::Torch
+                       || block_type == BlockType::Ladder || block_type.is_trapdoor() || block_type.is_fence() || block_type == BlockType::GlassPane
+                       || block_type.is_redstone_component() || block_type.is_plant() || block_type.is_sign() || block_type == BlockType::Rail || block_type == BlockType::Fire
+                       || block_type == BlockType::LightningRod
+                       || block_type.is_bottom_slab() || block_type.is_top_slab() || block_type.is_stairs() {
+                        continue;  // Torches, slabs, stairs, ladders, and trapdoors are rendered separately with special geometry
+                    }
+
+                    let world_x = chunk_x_offset + x as i32;
+                    let world_z = chunk_z_offset + z as i32;
+                    let check_y = neighbor_y(y as i32);
+
+                    // Skip damaged blocks - they're rendered separately with crack effects
+                    if world.get_block_damage(world_x, y as i32, world_z) > 0.0 {
+                        continue;
+                    }
+
+                    if Self::is_face_exposed(world, world_x, check_y, world_z, block_type) {
+                        mask[x][z] = Some(block_type);
+                    }
+                }
+            }
+
+            // Greedy merge
+            let mut visited = [[false; 16]; 16];
+
+            for start_x in 0..size {
+                for start_z in 0..size {
+                    if visited[start_x][start_z] || mask[start_x][start_z].is_none() {
+                        continue;
+                    }
+
+                    let block_type = mask[start_x][start_z].unwrap();
+                    let is_water = block_type == BlockType::Water;
+
+                    // Water blocks skip merging - each needs individual depth value
+                    let (width, depth) = if is_water {
+                        (1, 1)
+                    } else {
+                        // Find width (extend in X)
+                        let mut w = 1;
+                        while start_x + w < size
+                            && !visited[start_x + w][start_z]
+                            && mask[start_x + w][start_z] == Some(block_type)
+                        {
+                            w += 1;
+                        }
+
+                        // Find depth (extend in Z)
+                        let mut d = 1;
+                        'outer: while start_z + d < size {
+                            for dx in 0..w {
+                                if visited[start_x + dx][start_z + d]
+                                    || mask[start_x + dx][start_z + d] != Some(block_type)
+                                {
+                                    break 'outer;
+                                }
+                            }
+                            d += 1;
+                        }
+                        (w, d)
+                    };
+
+                    // Mark as visited
+                    for dx in 0..width {
+                        for dz in 0..depth {
+                            visited[start_x + dx][start_z + dz] = true;
+                        }
+                    }
+
+                    // Generate merged quad
                     let world_x = chunk_x_offset + start_x as i32;
                     let world_z = chunk_z_offset + start_z as i32;
                     let water_depth = if is_water {
@@ -5358,6 +6318,8 @@ impl Renderer {
                     let block_type = chunk.blocks[x][y][z];
                     if block_type == BlockType::Air || block_type == BlockType::Barrier || block_type == BlockType::Torch
                        || block_type == BlockType::Ladder || block_type.is_trapdoor() || block_type.is_fence() || block_type == BlockType::GlassPane
+                       || block_type.is_redstone_component() || block_type.is_plant() || block_type.is_sign() || block_type == BlockType::Rail || block_type == BlockType::Fire
+                       || block_type == BlockType::LightningRod
                        || block_type.is_bottom_slab() || block_type.is_top_slab() || block_type.is_stairs() {
                         continue;  // Torches, slabs, stairs, ladders, and trapdoors are rendered separately with special geometry
                     }
@@ -5467,6 +6429,8 @@ impl Renderer {
                     let block_type = chunk.blocks[x][y][z];
                     if block_type == BlockType::Air || block_type == BlockType::Barrier || block_type == BlockType::Torch
                        || block_type == BlockType::Ladder || block_type.is_trapdoor() || block_type.is_fence() || block_type == BlockType::GlassPane
+                       || block_type.is_redstone_component() || block_type.is_plant() || block_type.is_sign() || block_type == BlockType::Rail || block_type == BlockType::Fire
+                       || block_type == BlockType::LightningRod
                        || block_type.is_bottom_slab() || block_type.is_top_slab() || block_type.is_stairs() {
                         continue;  // Torches, slabs, stairs, ladders, and trapdoors are rendered separately with special geometry
                     }
@@ -5866,6 +6830,60 @@ impl Renderer {
                 // Spade edge (rounded look)
                 add_box(verts, [0.0, 0.4, 0.0], [0.1, 0.06, 0.025], material_color);
             }
+            ToolType::Hoe => {
+                // Hoe: Long handle + thin blade sticking out to one side
+                // Handle
+                add_box(verts, [0.0, -0.1, 0.0], [0.05, 0.6, 0.05], handle_color);
+                // Blade collar
+                add_box(verts, [0.03, 0.22, 0.0], [0.12, 0.06, 0.05], material_color);
+                // Blade (points down at the tip)
+                add_box(verts, [0.14, 0.18, 0.0], [0.1, 0.12, 0.03], material_color);
+            }
+            ToolType::Bow => {
+                // Bow: curved limbs either side of the grip + taut string
+                // Grip
+                add_box(verts, [0.0, 0.0, 0.0], [0.07, 0.16, 0.07], handle_color);
+                // Limbs curving back towards the string
+                add_box(verts, [0.04, 0.18, 0.0], [0.05, 0.2, 0.05], handle_color);
+                add_box(verts, [0.04, -0.18, 0.0], [0.05, 0.2, 0.05], handle_color);
+                add_box(verts, [0.1, 0.33, 0.0], [0.05, 0.12, 0.05], handle_color);
+                add_box(verts, [0.1, -0.33, 0.0], [0.05, 0.12, 0.05], handle_color);
+                // String
+                add_box(verts, [0.13, 0.0, 0.0], [0.015, 0.78, 0.015], 9.0);
+            }
+            ToolType::Helmet => {
+                // Helmet: domed cap with cheek guards either side of the open face
+                add_box(verts, [0.0, 0.12, 0.0], [0.34, 0.12, 0.34], material_color);
+                add_box(verts, [-0.15, -0.02, 0.0], [0.04, 0.18, 0.34], material_color);
+                add_box(verts, [0.15, -0.02, 0.0], [0.04, 0.18, 0.34], material_color);
+                add_box(verts, [0.0, -0.02, 0.15], [0.34, 0.18, 0.04], material_color);
+            }
+            ToolType::Chestplate => {
+                // Chestplate: torso plate with shoulder pads
+                add_box(verts, [0.0, -0.02, 0.0], [0.3, 0.4, 0.14], material_color);
+                add_box(verts, [-0.2, 0.12, 0.0], [0.12, 0.12, 0.16], material_color);
+                add_box(verts, [0.2, 0.12, 0.0], [0.12, 0.12, 0.16], material_color);
+            }
+            ToolType::Leggings => {
+                // Leggings: waistband over two legs
+                add_box(verts, [0.0, 0.16, 0.0], [0.3, 0.08, 0.14], material_color);
+                add_box(verts, [-0.08, -0.06, 0.0], [0.12, 0.38, 0.13], material_color);
+                add_box(verts, [0.08, -0.06, 0.0], [0.12, 0.38, 0.13], material_color);
+            }
+            ToolType::Boots => {
+                // Boots: a pair of ankle cuffs on longer soles
+                for side in [-0.1, 0.1] {
+                    add_box(verts, [side, 0.04, 0.0], [0.12, 0.14, 0.12], material_color);
+                    add_box(verts, [side, -0.06, -0.04], [0.12, 0.06, 0.2], material_color);
+                }
+            }
+            ToolType::FlintAndSteel => {
+                // Flint and steel: a C-shaped steel striker beside a chip of flint
+                add_box(verts, [-0.06, 0.0, 0.0], [0.04, 0.22, 0.04], material_color);
+                add_box(verts, [0.0, 0.09, 0.0], [0.14, 0.04, 0.04], material_color);
+                add_box(verts, [0.0, -0.09, 0.0], [0.14, 0.04, 0.04], material_color);
+                add_box(verts, [0.14, 0.02, 0.0], [0.1, 0.14, 0.06], 11.0);
+            }
         }
     }
 
@@ -5968,8 +6986,9 @@ impl Renderer {
             if current_type == BlockType::Water {
                 block != BlockType::Water
             } else {
-                // Torch doesn't occlude faces - it's a small object, not a full block
-                block == BlockType::Air || block == BlockType::Torch
+                // Torches and redstone components don't occlude faces - they're small objects, not full blocks
+                block == BlockType::Air || block == BlockType::Torch || block.is_redstone_component() || block.is_plant() || block.is_sign() || block == BlockType::Rail || block == BlockType::Fire
+                    || block == BlockType::LightningRod
             }
         })
     }
@@ -6020,6 +7039,35 @@ impl Renderer {
             BlockType::StoneFence => 2.0,     // Stone texture
             BlockType::FenceGate => 44.0,     // Wood texture
             BlockType::GlassPane => 9.0,      // Ice/glass-like
+            // Redstone components
+            BlockType::Lever => 44.0,           // Wood handle
+            BlockType::StoneButton => 2.0,      // Stone texture
+            BlockType::PressurePlate => 2.0,    // Stone texture
+            BlockType::Repeater => 2.0,         // Stone base
+            BlockType::RedstoneWire => 52.0,    // Dull red dust (powered wire uses 53.0)
+            BlockType::RedstoneTorch => 53.0,   // Glowing red
+            BlockType::RedstoneLamp => 54.0,    // Dark amber glass
+            BlockType::RedstoneLampLit => 55.0, // Emissive warm yellow
+            // Farming
+            BlockType::Farmland => 56.0,        // Dry furrowed soil
+            BlockType::FarmlandWet => 57.0,     // Dark damp soil
+            BlockType::WheatCrop | BlockType::CarrotCrop | BlockType::PotatoCrop => 58.0, // Young green shoots (mature crops use 59-61)
+            BlockType::Sapling => 62.0,         // Brown stem with a leafy top
+            BlockType::Lead => 45.0,            // Rope, dark wood colour
+            BlockType::Arrow => 67.0,           // Wooden shaft, flint tip, pale fletching
+            BlockType::Boat => 68.0,            // Dark oak hull
+            BlockType::Minecart => 69.0,        // Iron tub
+            BlockType::Emerald => 63.0,         // Bright green gem
+            BlockType::Composter => 64.0,       // Slatted wooden bin
+            BlockType::Anvil => 65.0,           // Dark iron
+            BlockType::Lectern => 66.0,         // Wooden stand with an open book
+            BlockType::GlassBottle => 76.0,     // Empty pale glass
+            BlockType::PotionRegeneration => 77.0,   // Pink liquid in glass
+            BlockType::PotionSwiftness => 78.0,      // Sky blue
+            BlockType::PotionNightVision => 79.0,    // Deep blue
+            BlockType::PotionWaterBreathing => 80.0, // Sea blue
+            BlockType::Fire => 81.0,            // Animated emissive flames
+            BlockType::LightningRod => 82.0,    // Polished copper
             _ => 0.0,
         }
     }
@@ -6140,6 +7188,8 @@ impl Renderer {
             // Animation swing for arms and legs
             let swing = if villager.state == VillagerState::Walking {
                 (villager.animation_time * 8.0).sin() * 0.5
+            } else if villager.state == VillagerState::Fleeing {
+                (villager.animation_time * 14.0).sin() * 0.8
             } else {
                 0.0
             };
@@ -6229,8 +7279,201 @@ impl Renderer {
         }
     }
 
+    /// Add one box of a skinned model. `min` and `size` are in skin pixels in the part's own
+    /// space (front facing -Z), `uv` is the box's corner in the 64x64 skin layout and
+    /// `shader_id` picks the skin itself or an armor material.
+    fn add_skin_box(
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u16>,
+        transform: &Matrix4<f32>,
+        min: [f32; 3],
+        size: [f32; 3],
+        uv: [f32; 2],
+        shader_id: f32,
+    ) {
+        let [w, h, d] = size;
+        // Each face: outward normal, corners from the image's top-left going clockwise
+        // (as 0/1 along each axis of the box), and its rectangle in the skin
+        let faces = [
+            ([0.0, 0.0, -1.0], [[1.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0], [1.0, 0.0, 0.0]], [d, d, w, h]),
+            ([0.0, 0.0, 1.0], [[0.0, 1.0, 1.0], [1.0, 1.0, 1.0], [1.0, 0.0, 1.0], [0.0, 0.0, 1.0]], [2.0 * d + w, d, w, h]),
+            ([1.0, 0.0, 0.0], [[1.0, 1.0, 1.0], [1.0, 1.0, 0.0], [1.0, 0.0, 0.0], [1.0, 0.0, 1.0]], [0.0, d, d, h]),
+            ([-1.0, 0.0, 0.0], [[0.0, 1.0, 0.0], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]], [d + w, d, d, h]),
+            ([0.0, 1.0, 0.0], [[1.0, 1.0, 1.0], [0.0, 1.0, 1.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0]], [d, 0.0, w, d]),
+            ([0.0, -1.0, 0.0], [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 1.0]], [d + w, 0.0, w, d]),
+        ];
+        let corner_uv = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+        for (normal, corners, rect) in faces.iter() {
+            let base_index = vertices.len() as u16;
+            let n = (transform * Vector4::new(normal[0], normal[1], normal[2], 0.0)).truncate().normalize();
+            for (corner, st) in corners.iter().zip(corner_uv.iter()) {
+                let local = Vector4::new(
+                    min[0] + corner[0] * w,
+                    min[1] + corner[1] * h,
+                    min[2] + corner[2] * d,
+                    1.0,
+                );
+                let world = transform * local;
+                vertices.push(Vertex {
+                    position: [world.x, world.y, world.z],
+                    tex_coords: [
+                        (uv[0] + rect[0] + st[0] * rect[2]) / 64.0,
+                        (uv[1] + rect[1] + st[1] * rect[3]) / 64.0,
+                    ],
+                    normal: [n.x, n.y, n.z],
+                    block_type: shader_id,
+                    damage: 0.0,
+                });
+            }
+            for i in FACE_INDICES {
+                indices.push(base_index + i);
+            }
+        }
+    }
+
+    /// Build a posed player model: head, body, arms and legs in the standard skin layout
+    fn add_player_model(vertices: &mut Vec<Vertex>, indices: &mut Vec<u16>, pose: &PlayerPose) {
+        use cgmath::Rad;
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let px = PLAYER_PIXEL;
+        // The model is built facing -Z; turn it to face the camera yaw
+        let facing = Matrix4::from_angle_y(Rad(-(pose.yaw + 90.0).to_radians()));
+        // Seated bodies start at the hips rather than the feet, prone ones lie flat on the
+        // ground and fallen ones rest on their side
+        let prone = pose.swimming || pose.crawling;
+        let drop = if pose.sitting {
+            12.0 * px
+        } else if prone {
+            10.0 * px
+        } else {
+            0.0
+        } - pose.death * 4.0 * px;
+        let mut root = Matrix4::from_translation(Vector3::new(pose.feet.x, pose.feet.y - drop, pose.feet.z)) * facing;
+        // Dying topples the body over sideways about the feet
+        root = root * Matrix4::from_angle_z(Rad(pose.death * FRAC_PI_2));
+        root = root * Matrix4::from_scale(px);
+
+        // Swimming and crawling lay the body flat about the hips, sneaking hunches it and
+        // sprinting leans it forward a little
+        let lean = if prone {
+            FRAC_PI_2
+        } else if pose.sneaking {
+            0.5
+        } else {
+            (pose.walk_amount - 1.0).max(0.0) * 0.4
+        };
+        let hips = Vector3::new(0.0, 12.0, 0.0);
+        let body = root * Matrix4::from_translation(hips) * Matrix4::from_angle_x(Rad(-lean)) * Matrix4::from_translation(-hips);
+
+        // Limb angles about each joint's X axis, positive swings forward
+        let cycle = pose.walk_phase.sin();
+        let stride = pose.walk_amount.min(1.5);
+        let (mut right_arm, mut left_arm, right_leg, left_leg) = if pose.swimming {
+            // Arms sweep overhead and back, legs flutter
+            let stroke = PI * (0.5 + 0.5 * pose.walk_phase.sin());
+            let kick = (pose.walk_phase * 2.0).sin() * 0.3;
+            (stroke, stroke, kick, -kick)
+        } else if pose.crawling {
+            // Reach ahead with alternate arms, pushing with the legs
+            let reach = cycle * 0.4 * stride;
+            (PI - 0.3 + reach, PI - 0.3 - reach, reach * 0.75, -reach * 0.75)
+        } else if pose.sitting {
+            (0.6, 0.6, FRAC_PI_2, FRAC_PI_2)
+        } else {
+            // Legs stay upright under a leaning body
+            (-cycle * 0.7 * stride, cycle * 0.7 * stride, cycle * 0.9 * stride + lean, -cycle * 0.9 * stride + lean)
+        };
+
+        // Attacking or using an item brings the right arm up and forward, then back down
+        if pose.arm_swing > 0.0 {
+            let t = 1.0 - pose.arm_swing;
+            right_arm += (t * PI).sin() * 1.6;
+        }
+        // Arms go slack and fall away from the sides as the body topples
+        let spread = pose.death * 0.3;
+        right_arm *= 1.0 - pose.death;
+        left_arm *= 1.0 - pose.death;
+
+        let joint = |at: [f32; 3], pitch: f32, roll: f32| {
+            body * Matrix4::from_translation(Vector3::new(at[0], at[1], at[2]))
+                * Matrix4::from_angle_z(Rad(roll))
+                * Matrix4::from_angle_x(Rad(pitch))
+        };
+
+        // Head pitches on the neck; when leaning over it tilts back up to look ahead
+        let head_pitch = pose.head_pitch.to_radians().clamp(-1.4, 1.4) + lean;
+        let head = joint([0.0, 24.0, 0.0], head_pitch, 0.0);
+        Self::add_skin_box(vertices, indices, &head, [-4.0, 0.0, -4.0], [8.0, 8.0, 8.0], [0.0, 0.0], PLAYER_SKIN_SHADER_ID);
+
+        Self::add_skin_box(vertices, indices, &body, [-4.0, 12.0, -2.0], [8.0, 12.0, 4.0], [16.0, 16.0], PLAYER_SKIN_SHADER_ID);
+
+        // Arms hang from the shoulders, two pixels below the top of the arm
+        let arms = [joint([6.0, 22.0, 0.0], right_arm, spread), joint([-6.0, 22.0, 0.0], left_arm, -spread)];
+        Self::add_skin_box(vertices, indices, &arms[0], [-2.0, -10.0, -2.0], [4.0, 12.0, 4.0], [40.0, 16.0], PLAYER_SKIN_SHADER_ID);
+        Self::add_skin_box(vertices, indices, &arms[1], [-2.0, -10.0, -2.0], [4.0, 12.0, 4.0], [32.0, 48.0], PLAYER_SKIN_SHADER_ID);
+
+        let legs = [joint([2.0, 12.0, 0.0], right_leg, 0.0), joint([-2.0, 12.0, 0.0], left_leg, 0.0)];
+        Self::add_skin_box(vertices, indices, &legs[0], [-2.0, -12.0, -2.0], [4.0, 12.0, 4.0], [0.0, 16.0], PLAYER_SKIN_SHADER_ID);
+        Self::add_skin_box(vertices, indices, &legs[1], [-2.0, -12.0, -2.0], [4.0, 12.0, 4.0], [16.0, 48.0], PLAYER_SKIN_SHADER_ID);
+
+        // Armor plates sit just outside the parts they cover; the helmet leaves the face open
+        let [helmet, chestplate, leggings, boots] = pose.armor.map(|piece| piece.map(Self::armor_shader_id));
+        if let Some(id) = helmet {
+            Self::add_skin_box(vertices, indices, &head, [-4.5, 4.5, -4.5], [9.0, 4.0, 9.0], [0.0, 0.0], id);
+            Self::add_skin_box(vertices, indices, &head, [-4.5, 0.5, 3.5], [9.0, 4.0, 1.0], [0.0, 0.0], id);
+        }
+        if let Some(id) = chestplate {
+            Self::add_skin_box(vertices, indices, &body, [-4.5, 11.5, -2.5], [9.0, 13.0, 5.0], [16.0, 16.0], id);
+            for arm in arms.iter() {
+                Self::add_skin_box(vertices, indices, arm, [-2.5, -4.0, -2.5], [5.0, 6.5, 5.0], [40.0, 16.0], id);
+            }
+        }
+        if let Some(id) = leggings {
+            Self::add_skin_box(vertices, indices, &body, [-4.3, 11.0, -2.3], [8.6, 3.0, 4.6], [16.0, 16.0], id);
+            for leg in legs.iter() {
+                Self::add_skin_box(vertices, indices, leg, [-2.3, -8.0, -2.3], [4.6, 8.3, 4.6], [0.0, 16.0], id);
+            }
+        }
+        if let Some(id) = boots {
+            for leg in legs.iter() {
+                Self::add_skin_box(vertices, indices, leg, [-2.5, -12.5, -2.5], [5.0, 5.5, 5.0], [0.0, 16.0], id);
+            }
+        }
+    }
+
+    /// Shader ID for armor plates of each material (71-75)
+    fn armor_shader_id(material: ToolMaterial) -> f32 {
+        match material {
+            ToolMaterial::Wood => 71.0,
+            ToolMaterial::Stone => 72.0,
+            ToolMaterial::Iron => 73.0,
+            ToolMaterial::Gold => 74.0,
+            ToolMaterial::Diamond => 75.0,
+        }
+    }
+
+    /// Update the player models: the local player in third person, on the death screen and
+    /// from the free camera, plus any other players sharing the world
+    pub fn update_player_mesh(&mut self, poses: &[PlayerPose]) {
+        let mut vertices: Vec<Vertex> = Vec::with_capacity(poses.len() * PLAYER_MODEL_BOXES * 24);
+        let mut indices: Vec<u16> = Vec::with_capacity(poses.len() * PLAYER_MODEL_BOXES * 36);
+
+        for pose in poses.iter().take(MAX_PLAYER_MODELS) {
+            Self::add_player_model(&mut vertices, &mut indices, pose);
+        }
+
+        self.player_index_count = indices.len() as u32;
+
+        if !vertices.is_empty() {
+            self.queue.write_buffer(&self.player_vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+            self.queue.write_buffer(&self.player_index_buffer, 0, bytemuck::cast_slice(&indices));
+        }
+    }
+
     /// Update the animal mesh (all animal types including flying)
-    pub fn update_animal_mesh(&mut self, animals: &[crate::entity::Animal]) {
+    pub fn update_animal_mesh(&mut self, animals: &[crate::entity::Animal], player_pos: Point3<f32>) {
         use crate::entity::{AnimalState, AnimalType, MovementType};
 
         let mut vertices: Vec<Vertex> = Vec::with_capacity(animals.len() * 8 * 24);
@@ -6242,11 +7485,31 @@ impl Renderer {
             let z = animal.position.z;
             let yaw = animal.yaw.to_radians();
             let color = animal.animal_type.color_index();
-            let (width, height) = animal.animal_type.dimensions();
+            let (width, height) = animal.dimensions();
             let movement_type = animal.animal_type.movement_type();
 
             let pivot = [x, y, z];
 
+            // Red marker bobbing overhead while looking for a mate
+            if animal.love_timer > 0.0 {
+                let bob = (animal.animation_time * 3.0).sin() * 0.1;
+                Self::generate_villager_cube(&mut vertices, &mut indices, [x, y + 0.4 + bob, z], [0.15, 0.15, 0.15], 99.0, yaw, pivot);
+            }
+
+            // Lead: a sagging rope of small segments from the animal to the player's hand
+            if animal.leashed {
+                let hand = Point3::new(player_pos.x, player_pos.y - 0.6, player_pos.z);
+                let start = Point3::new(x, y - height * 0.3, z);
+                let length = (hand - start).magnitude();
+                let segments = (length / 0.25).ceil().max(1.0) as usize;
+                for i in 0..=segments {
+                    let t = i as f32 / segments as f32;
+                    let sag = (t * std::f32::consts::PI).sin() * (crate::entity::LEASH_LENGTH - length).max(0.0) * 0.2;
+                    let point = start + (hand - start) * t;
+                    Self::generate_villager_cube(&mut vertices, &mut indices, [point.x, point.y - sag, point.z], [0.05, 0.05, 0.05], 45.0, 0.0, [point.x, point.y, point.z]);
+                }
+            }
+
             match movement_type {
                 MovementType::Flying => {
                     // Flying animals: body + head + 2 flapping wings
@@ -6368,9 +7631,11 @@ impl Renderer {
                     };
 
                     let head_y_offset = if animal.state == AnimalState::Eating { -0.15 } else { 0.0 };
+                    // Sitting pets drop their body onto folded legs
+                    let sit_drop = if animal.sitting { height * 0.2 } else { 0.0 };
 
                     // Body
-                    let body_y = y - height * 0.4;
+                    let body_y = y - height * 0.4 - sit_drop;
                     Self::generate_villager_cube(
                         &mut vertices,
                         &mut indices,
@@ -6385,7 +7650,7 @@ impl Renderer {
                     let head_forward = width * 0.5 + width * 0.2;
                     let head_x = x - yaw.sin() * head_forward;
                     let head_z = z - yaw.cos() * head_forward;
-                    let head_y = y - height * 0.25 + head_y_offset;
+                    let head_y = y - height * 0.25 + head_y_offset - sit_drop;
                     Self::generate_villager_cube(
                         &mut vertices,
                         &mut indices,
@@ -6396,8 +7661,22 @@ impl Renderer {
                         pivot,
                     );
 
+                    // Tamed pets wear a red collar where the head meets the body
+                    if animal.tamed {
+                        let collar_forward = width * 0.5;
+                        Self::generate_villager_cube(
+                            &mut vertices,
+                            &mut indices,
+                            [x - yaw.sin() * collar_forward, head_y - width * 0.1, z - yaw.cos() * collar_forward],
+                            [width * 0.32, width * 0.08, width * 0.1],
+                            99.0,
+                            yaw,
+                            pivot,
+                        );
+                    }
+
                     // 4 Legs
-                    let leg_height = height * 0.35;
+                    let leg_height = height * if animal.sitting { 0.15 } else { 0.35 };
                     let leg_y = y - height + leg_height * 0.5;
                     let leg_offsets = [
                         (-0.15 * width, 0.25 * width, 1.0),
@@ -6417,7 +7696,7 @@ impl Renderer {
                             &mut vertices,
                             &mut indices,
                             [x + rot_dx + swing_x, leg_y, z + rot_dz + swing_z],
-                            [0.12, leg_height, 0.12],
+                            [0.12 * animal.scale(), leg_height, 0.12 * animal.scale()],
                             color,
                             yaw,
                             pivot,
@@ -6448,8 +7727,14 @@ impl Renderer {
             let z = mob.position.z;
             let yaw = mob.yaw.to_radians();
 
-            // Use mob-specific color, flash red when hit
-            let color = if mob.damage_flash > 0.0 { 99.0 } else { mob.mob_type.color_index() };
+            // Use mob-specific color, flash red when hit; charged creepers crackle blue
+            let color = if mob.damage_flash > 0.0 {
+                99.0
+            } else if mob.charged {
+                83.0
+            } else {
+                mob.mob_type.color_index()
+            };
 
             let (width, height) = mob.mob_type.dimensions();
             let pivot = [x, y, z];
@@ -6884,6 +8169,96 @@ impl Renderer {
         }
     }
 
+    /// Update boat and minecart meshes, tilted by each vehicle's pitch and roll
+    pub fn update_vehicle_mesh(&mut self, vehicles: &[crate::entity::Vehicle]) {
+        use crate::entity::VehicleKind;
+
+        let mut vertices: Vec<Vertex> = Vec::with_capacity(vehicles.len() * 10 * 24);
+        let mut indices: Vec<u16> = Vec::with_capacity(vehicles.len() * 10 * 36);
+
+        const WHEEL_COLOR: f32 = 42.0;  // Dark iron (spawner cage)
+
+        for vehicle in vehicles {
+            let (x, y, z) = (vehicle.position.x, vehicle.position.y, vehicle.position.z);
+            let (sin_yaw, cos_yaw) = vehicle.yaw.to_radians().sin_cos();
+            let (sin_pitch, cos_pitch) = vehicle.pitch.to_radians().sin_cos();
+            let (sin_roll, cos_roll) = vehicle.roll.to_radians().sin_cos();
+
+            // Same roll, pitch, yaw order as planes, pivoting on the bottom of the hull
+            let rotate_point = |lx: f32, ly: f32, lz: f32| -> [f32; 3] {
+                let (rx, ry) = (lx * cos_roll - ly * sin_roll, lx * sin_roll + ly * cos_roll);
+                let (ry, rz) = (ry * cos_pitch - lz * sin_pitch, ry * sin_pitch + lz * cos_pitch);
+                [x + rx * cos_yaw + rz * sin_yaw, y + ry, z - rx * sin_yaw + rz * cos_yaw]
+            };
+
+            let add_part = |vertices: &mut Vec<Vertex>, indices: &mut Vec<u16>,
+                            offset: [f32; 3], size: [f32; 3], color: f32| {
+                let half = [size[0] / 2.0, size[1] / 2.0, size[2] / 2.0];
+                let corner = |sx: f32, sy: f32, sz: f32| {
+                    rotate_point(offset[0] + sx * half[0], offset[1] + sy * half[1], offset[2] + sz * half[2])
+                };
+                let corners = [
+                    corner(-1.0, -1.0, -1.0), corner(1.0, -1.0, -1.0), corner(1.0, 1.0, -1.0), corner(-1.0, 1.0, -1.0),
+                    corner(-1.0, -1.0, 1.0), corner(1.0, -1.0, 1.0), corner(1.0, 1.0, 1.0), corner(-1.0, 1.0, 1.0),
+                ];
+                let faces = [
+                    ([4, 5, 6, 7], [0.0, 0.0, 1.0]),
+                    ([1, 0, 3, 2], [0.0, 0.0, -1.0]),
+                    ([5, 1, 2, 6], [1.0, 0.0, 0.0]),
+                    ([0, 4, 7, 3], [-1.0, 0.0, 0.0]),
+                    ([7, 6, 2, 3], [0.0, 1.0, 0.0]),
+                    ([0, 1, 5, 4], [0.0, -1.0, 0.0]),
+                ];
+                let tex_coords_quad = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+
+                for (face_indices, normal) in faces.iter() {
+                    let face_base = vertices.len() as u16;
+                    for (i, &vi) in face_indices.iter().enumerate() {
+                        vertices.push(Vertex {
+                            position: corners[vi],
+                            tex_coords: tex_coords_quad[i],
+                            normal: *normal,
+                            block_type: color,
+                            damage: 0.0,
+                        });
+                    }
+                    indices.extend_from_slice(&[face_base, face_base + 1, face_base + 2, face_base, face_base + 2, face_base + 3]);
+                }
+            };
+
+            let color = Self::block_type_to_float(vehicle.kind.item());
+            match vehicle.kind {
+                VehicleKind::Boat => {
+                    // Flat bottom, two long sides, bow and stern boards and a seat plank
+                    add_part(&mut vertices, &mut indices, [0.0, 0.05, 0.0], [1.2, 0.1, 2.0], color);
+                    add_part(&mut vertices, &mut indices, [-0.55, 0.3, 0.0], [0.1, 0.5, 2.0], color);
+                    add_part(&mut vertices, &mut indices, [0.55, 0.3, 0.0], [0.1, 0.5, 2.0], color);
+                    add_part(&mut vertices, &mut indices, [0.0, 0.3, -0.95], [1.0, 0.5, 0.1], color);
+                    add_part(&mut vertices, &mut indices, [0.0, 0.3, 0.95], [1.0, 0.5, 0.1], color);
+                    add_part(&mut vertices, &mut indices, [0.0, 0.25, 0.2], [1.0, 0.08, 0.35], color);
+                }
+                VehicleKind::Minecart => {
+                    // Open iron tub on four small wheels
+                    add_part(&mut vertices, &mut indices, [0.0, 0.2, 0.0], [0.9, 0.1, 1.1], color);
+                    add_part(&mut vertices, &mut indices, [-0.4, 0.5, 0.0], [0.1, 0.6, 1.1], color);
+                    add_part(&mut vertices, &mut indices, [0.4, 0.5, 0.0], [0.1, 0.6, 1.1], color);
+                    add_part(&mut vertices, &mut indices, [0.0, 0.5, -0.5], [0.7, 0.6, 0.1], color);
+                    add_part(&mut vertices, &mut indices, [0.0, 0.5, 0.5], [0.7, 0.6, 0.1], color);
+                    for (wx, wz) in [(-0.35, -0.35), (0.35, -0.35), (-0.35, 0.35), (0.35, 0.35)] {
+                        add_part(&mut vertices, &mut indices, [wx, 0.12, wz], [0.12, 0.16, 0.16], WHEEL_COLOR);
+                    }
+                }
+            }
+        }
+
+        self.vehicle_index_count = indices.len() as u32;
+
+        if !vertices.is_empty() {
+            self.queue.write_buffer(&self.vehicle_vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+            self.queue.write_buffer(&self.vehicle_index_buffer, 0, bytemuck::cast_slice(&indices));
+        }
+    }
+
     /// Update missile mesh for rendering
     pub fn update_missile_mesh(&mut self, missiles: &[crate::entity::Missile]) {
         let mut vertices: Vec<Vertex> = Vec::with_capacity(missiles.len() * 24);
@@ -7156,6 +8531,13 @@ impl Renderer {
                         ToolType::Axe => 65.0,
                         ToolType::Shovel => 70.0,
                         ToolType::Sword => 75.0,
+                        ToolType::Hoe => 109.0,
+                        ToolType::Bow => 131.0,
+                        ToolType::Helmet => 134.0,
+                        ToolType::Chestplate => 139.0,
+                        ToolType::Leggings => 144.0,
+                        ToolType::Boots => 149.0,
+                        ToolType::FlintAndSteel => 159.0,
                     };
                     let material_offset = match tool.material {
                         ToolMaterial::Wood => 0.0,
@@ -7204,8 +8586,9 @@ impl Renderer {
         }
 
         // Calculate camera right and up vectors for billboarding
-        let yaw_rad = camera.yaw.to_radians();
-        let pitch_rad = camera.pitch.to_radians();
+        let (view_yaw, view_pitch) = camera.view_angles();
+        let yaw_rad = view_yaw.to_radians();
+        let pitch_rad = view_pitch.to_radians();
 
         let forward = Vector3::new(
             yaw_rad.sin() * pitch_rad.cos(),
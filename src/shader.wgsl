@@ -56,6 +56,8 @@ var t_snow: texture_2d<f32>;
 var t_torch: texture_2d<f32>;
 @group(1) @binding(6)
 var s_diffuse: sampler;
+@group(1) @binding(13)
+var t_skin: texture_2d<f32>;
 
 // Shadow map
 @group(2) @binding(0)
@@ -222,6 +224,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let sand_color = textureSample(t_sand, s_diffuse, in.tex_coords);
     let snow_color = textureSample(t_snow, s_diffuse, in.tex_coords);
     let torch_color = textureSample(t_torch, s_diffuse, in.tex_coords);
+    let skin_color = textureSample(t_skin, s_diffuse, in.tex_coords);
 
     // Select texture based on block type
     let bt = floor(in.block_type + 0.5);
@@ -414,6 +417,166 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         let moss_green = vec3<f32>(0.3, 0.45, 0.25);
         texture_color = vec4<f32>(mix(base_gray, moss_green, moss * 0.6), 1.0);
         roughness = 0.8;
+    } else if (bt == 52.0) {
+        // Redstone wire - dull dark red dust
+        texture_color = vec4<f32>(0.35, 0.05, 0.03, 1.0);
+        roughness = 0.9;
+    } else if (bt == 53.0) {
+        // Powered redstone - glowing red
+        texture_color = vec4<f32>(1.6, 0.15, 0.08, 1.0);
+        roughness = 0.6;
+    } else if (bt == 54.0) {
+        // Redstone lamp (off) - dark amber glass in a frame
+        let frame = noise(in.tex_coords * 5.0) * 0.06;
+        texture_color = vec4<f32>(0.4 + frame, 0.28 + frame, 0.15, 1.0);
+        roughness = 0.4;
+    } else if (bt == 55.0) {
+        // Redstone lamp (on) - emissive warm yellow
+        let glow = noise(in.tex_coords * 5.0) * 0.2;
+        texture_color = vec4<f32>(2.2 + glow, 1.7 + glow, 0.8, 1.0);
+        roughness = 0.4;
+    } else if (bt == 56.0) {
+        // Farmland (dry) - light brown soil with furrows
+        let furrow = step(0.5, fract(in.tex_coords.x * 4.0)) * 0.06;
+        texture_color = vec4<f32>(0.52 - furrow, 0.37 - furrow, 0.22 - furrow, 1.0);
+        roughness = 0.95;
+    } else if (bt == 57.0) {
+        // Farmland (hydrated) - dark damp soil with furrows
+        let furrow = step(0.5, fract(in.tex_coords.x * 4.0)) * 0.04;
+        texture_color = vec4<f32>(0.3 - furrow, 0.2 - furrow, 0.12 - furrow, 1.0);
+        roughness = 0.7;
+    } else if (bt == 58.0) {
+        // Young crop - bright green shoots
+        let shade = noise(in.tex_coords * 8.0) * 0.1;
+        texture_color = vec4<f32>(0.3 + shade, 0.62 + shade, 0.18, 1.0);
+        roughness = 0.9;
+    } else if (bt == 59.0) {
+        // Mature wheat - golden stalks
+        let shade = noise(in.tex_coords * 8.0) * 0.1;
+        texture_color = vec4<f32>(0.85 + shade, 0.72 + shade, 0.3, 1.0);
+        roughness = 0.9;
+    } else if (bt == 60.0) {
+        // Mature carrots - green leaves, orange roots at the base
+        let root = step(0.8, in.tex_coords.y);
+        texture_color = mix(vec4<f32>(0.25, 0.55, 0.15, 1.0), vec4<f32>(0.95, 0.5, 0.1, 1.0), root);
+        roughness = 0.9;
+    } else if (bt == 61.0) {
+        // Mature potatoes - green leaves, tan tubers at the base
+        let root = step(0.8, in.tex_coords.y);
+        texture_color = mix(vec4<f32>(0.28, 0.5, 0.18, 1.0), vec4<f32>(0.75, 0.6, 0.35, 1.0), root);
+        roughness = 0.9;
+    } else if (bt == 62.0) {
+        // Sapling - leafy crown over a thin brown stem
+        let stem = step(0.6, in.tex_coords.y) * step(abs(in.tex_coords.x - 0.5), 0.08);
+        let shade = noise(in.tex_coords * 10.0) * 0.12;
+        let leaf = vec4<f32>(0.2 + shade, 0.48 + shade, 0.14, 1.0);
+        texture_color = mix(leaf, vec4<f32>(0.4, 0.28, 0.15, 1.0), stem);
+        roughness = 0.9;
+    } else if (bt == 63.0) {
+        // Emerald - faceted bright green gem
+        let facet = noise(in.tex_coords * 6.0) * 0.15;
+        texture_color = vec4<f32>(0.1 + facet, 0.75 + facet, 0.35 + facet, 1.0);
+        roughness = 0.2;
+    } else if (bt == 64.0) {
+        // Composter - wooden slats with dark gaps
+        let slat = step(0.8, fract(in.tex_coords.y * 4.0)) * 0.2;
+        texture_color = vec4<f32>(0.55 - slat, 0.4 - slat, 0.22 - slat, 1.0);
+        roughness = 0.85;
+    } else if (bt == 65.0) {
+        // Anvil - dark worn iron
+        let wear = noise(in.tex_coords * 8.0) * 0.06;
+        texture_color = vec4<f32>(0.22 + wear, 0.22 + wear, 0.24 + wear, 1.0);
+        roughness = 0.35;
+    } else if (bt == 66.0) {
+        // Lectern - wood with pale book pages across the top
+        let page = step(0.75, in.tex_coords.y) * step(abs(in.tex_coords.x - 0.5), 0.35);
+        let grain = noise(in.tex_coords * 6.0) * 0.08;
+        texture_color = mix(vec4<f32>(0.5 + grain, 0.36 + grain, 0.2, 1.0), vec4<f32>(0.9, 0.87, 0.78, 1.0), page);
+        roughness = 0.8;
+    } else if (bt == 67.0) {
+        // Arrow - grey flint tip, wooden shaft, pale fletching
+        let tip = step(0.8, in.tex_coords.y);
+        let fletch = step(in.tex_coords.y, 0.2);
+        let shaft = vec4<f32>(0.55, 0.4, 0.24, 1.0);
+        texture_color = mix(mix(shaft, vec4<f32>(0.35, 0.35, 0.37, 1.0), tip), vec4<f32>(0.92, 0.92, 0.88, 1.0), fletch);
+        roughness = 0.8;
+    } else if (bt == 68.0) {
+        // Boat - dark oak planks with seams between the boards
+        let seam = step(0.9, fract(in.tex_coords.y * 4.0));
+        let grain = noise(in.tex_coords * 8.0) * 0.06;
+        texture_color = vec4<f32>(0.42 + grain, 0.29 + grain, 0.16 + grain, 1.0) * (1.0 - seam * 0.35);
+        roughness = 0.8;
+    } else if (bt == 69.0) {
+        // Minecart - grey iron with a darker rim
+        let rim = step(0.85, in.tex_coords.y);
+        texture_color = mix(vec4<f32>(0.55, 0.56, 0.58, 1.0), vec4<f32>(0.3, 0.3, 0.32, 1.0), rim);
+        roughness = 0.35;
+        metallic = 0.6;
+    } else if (bt == 70.0) {
+        // Player model - sampled from the 64x64 skin
+        texture_color = vec4<f32>(skin_color.rgb, 1.0);
+        roughness = 0.7;
+    } else if (bt >= 71.0 && bt <= 75.0) {
+        // Worn armor (wood, stone, iron, gold, diamond) - plates with per-pixel wear
+        let wear = noise(floor(in.tex_coords * 64.0)) * 0.08;
+        var plate = vec3<f32>(0.55, 0.38, 0.2);
+        roughness = 0.85;
+        if (bt == 72.0) {
+            plate = vec3<f32>(0.47, 0.47, 0.48);
+            roughness = 0.6;
+        } else if (bt == 73.0) {
+            plate = vec3<f32>(0.78, 0.78, 0.8);
+            roughness = 0.3;
+            metallic = 0.7;
+        } else if (bt == 74.0) {
+            plate = vec3<f32>(0.95, 0.78, 0.25);
+            roughness = 0.25;
+            metallic = 0.8;
+        } else if (bt == 75.0) {
+            plate = vec3<f32>(0.35, 0.85, 0.85);
+            roughness = 0.2;
+            metallic = 0.3;
+        }
+        texture_color = vec4<f32>(plate - vec3<f32>(wear), 1.0);
+    } else if (bt >= 76.0 && bt <= 80.0) {
+        // Glass bottle, empty or filled two thirds of the way with a coloured potion
+        let glass = vec3<f32>(0.82, 0.88, 0.9);
+        var liquid = glass;
+        if (bt == 77.0) {
+            liquid = vec3<f32>(0.9, 0.35, 0.6);
+        } else if (bt == 78.0) {
+            liquid = vec3<f32>(0.5, 0.75, 0.95);
+        } else if (bt == 79.0) {
+            liquid = vec3<f32>(0.15, 0.2, 0.65);
+        } else if (bt == 80.0) {
+            liquid = vec3<f32>(0.15, 0.5, 0.7);
+        }
+        let filled = step(0.35, in.tex_coords.y);
+        texture_color = vec4<f32>(mix(glass, liquid, filled), 1.0);
+        roughness = 0.15;
+    } else if (bt == 81.0) {
+        // Fire - flickering flame tongues, yellow at the base fading to deep red at the tips
+        let fire_time = u_uniform.time_of_day * 600.0;  // Seconds, like the leaf sway
+        let rise = 1.0 - in.tex_coords.y;
+        let flicker = noise(vec2<f32>(floor(in.tex_coords.x * 6.0), floor(fire_time * 8.0)));
+        let tongue = noise(floor(in.tex_coords * vec2<f32>(8.0, 12.0) + vec2<f32>(0.0, fire_time * 6.0)));
+        let heat = clamp(1.0 - rise * (0.8 + flicker * 0.6) + tongue * 0.25, 0.0, 1.0);
+        let flame = mix(vec3<f32>(0.5, 0.05, 0.0), vec3<f32>(1.0, 0.85, 0.3), heat);
+        texture_color = vec4<f32>(flame * 2.5, 1.0);
+        roughness = 1.0;
+    } else if (bt == 82.0) {
+        // Lightning rod - polished copper with faint streaks
+        let streak = noise(vec2<f32>(in.tex_coords.x * 4.0, in.tex_coords.y * 16.0)) * 0.08;
+        texture_color = vec4<f32>(0.85 + streak, 0.5 + streak, 0.32, 1.0);
+        roughness = 0.3;
+        metallic = 0.9;
+    } else if (bt == 83.0) {
+        // Charged creeper - creeper green under a crackling blue aura
+        let charge_time = u_uniform.time_of_day * 600.0;
+        let arc = step(0.8, noise(floor(in.tex_coords * 10.0 + vec2<f32>(charge_time * 3.0, charge_time * 5.0))));
+        let skin = vec3<f32>(0.3, 0.65, 0.25);
+        texture_color = vec4<f32>(mix(skin, vec3<f32>(0.5, 0.75, 1.0) * 2.0, arc * 0.8), 1.0);
+        roughness = 0.6;
     }
 
     // Crack effect - dark cracks that spread as damage increases
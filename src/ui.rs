@@ -1,6 +1,6 @@
 use wgpu::util::DeviceExt;
 use bytemuck::{Pod, Zeroable};
-use crate::world::{BlockType, ItemStack, Tool, ToolType, ToolMaterial};
+use crate::world::{BlockType, ItemStack, Tool, ToolType, ToolMaterial, SIGN_LINE_LENGTH};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
@@ -14,6 +14,7 @@ struct UIVertex {
 pub struct Inventory {
     pub slots: [Option<ItemStack>; HOTBAR_NUM_SLOTS],
     pub selected_slot: usize,
+    pub armor: [Option<Tool>; 4],  // Worn helmet, chestplate, leggings, boots
 }
 
 impl Inventory {
@@ -21,6 +22,7 @@ impl Inventory {
         Self {
             slots: Default::default(),
             selected_slot: 0,
+            armor: Default::default(),
         }
     }
 }
@@ -44,6 +46,7 @@ impl DebugInfo {
 pub struct PauseMenu {
     pub visible: bool,
     pub selected_option: usize,
+    pub flight_model: crate::entity::FlightModel,  // Difficulty of flying planes
 }
 
 impl PauseMenu {
@@ -51,6 +54,7 @@ impl PauseMenu {
         Self {
             visible: false,
             selected_option: 0,
+            flight_model: crate::entity::FlightModel::Arcade,
         }
     }
 
@@ -177,6 +181,98 @@ impl FurnaceUI {
     }
 }
 
+// ============ TRADING UI ============
+
+pub struct TradingUI {
+    pub open: bool,
+    pub villager_id: Option<u32>,
+    pub selected_offer: usize,
+}
+
+impl TradingUI {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            villager_id: None,
+            selected_offer: 0,
+        }
+    }
+
+    pub fn open_trading(&mut self, villager_id: u32) {
+        self.open = true;
+        self.villager_id = Some(villager_id);
+        self.selected_offer = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.villager_id = None;
+    }
+
+    pub fn navigate(&mut self, dy: i32, offer_count: usize) {
+        if offer_count > 0 {
+            self.selected_offer = (self.selected_offer as i32 + dy).clamp(0, offer_count as i32 - 1) as usize;
+        }
+    }
+}
+
+// ============ SIGN EDITING ============
+
+pub struct SignEditUI {
+    pub open: bool,
+    pub sign_pos: Option<(i32, i32, i32)>,
+    pub lines: [String; 4],
+    pub selected_line: usize,
+    swallow_open_key: bool,  // The E press that opened the editor also arrives as a typed character
+}
+
+impl SignEditUI {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            sign_pos: None,
+            lines: Default::default(),
+            selected_line: 0,
+            swallow_open_key: false,
+        }
+    }
+
+    pub fn open_sign(&mut self, pos: (i32, i32, i32), lines: [String; 4]) {
+        self.open = true;
+        self.sign_pos = Some(pos);
+        self.lines = lines;
+        self.selected_line = 0;
+        self.swallow_open_key = true;
+    }
+
+    /// Close the editor, returning the sign position and its edited text
+    pub fn close(&mut self) -> Option<((i32, i32, i32), [String; 4])> {
+        self.open = false;
+        let pos = self.sign_pos.take()?;
+        Some((pos, std::mem::take(&mut self.lines)))
+    }
+
+    pub fn navigate(&mut self, dy: i32) {
+        self.selected_line = (self.selected_line as i32 + dy).clamp(0, 3) as usize;
+    }
+
+    /// Append a typed character to the selected line (printable ASCII only, like the font)
+    pub fn type_char(&mut self, c: char) {
+        if std::mem::take(&mut self.swallow_open_key) && c.eq_ignore_ascii_case(&'e') {
+            return;
+        }
+        let line = &mut self.lines[self.selected_line];
+        if (' '..='~').contains(&c) && line.len() < SIGN_LINE_LENGTH {
+            line.push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.swallow_open_key = false;
+        self.lines[self.selected_line].pop();
+    }
+}
+
 // ============ CRAFTING SYSTEM ============
 
 /// Represents a crafting recipe
@@ -311,8 +407,161 @@ impl RecipeRegistry {
             ItemStack::Block(BlockType::Fence, 3),
         ));
 
+        // 6 Planks over a Stick -> 3 Signs
+        self.recipes.push(Recipe::shaped(
+            &["PPP", "PPP", " S "],
+            &[('P', BlockType::Planks), ('S', BlockType::Stick)],
+            ItemStack::Block(BlockType::SignPost, 3),
+        ));
+
+        // 3 Wheat in a row -> Bread
+        self.recipes.push(Recipe::shaped(
+            &["WWW"],
+            &[('W', BlockType::Wheat)],
+            ItemStack::Block(BlockType::Bread, 1),
+        ));
+
+        // Wheat twisted around a Stick -> 2 Leads
+        self.recipes.push(Recipe::shaped(
+            &["WW ", "WS ", "  W"],
+            &[('W', BlockType::Wheat), ('S', BlockType::Stick)],
+            ItemStack::Block(BlockType::Lead, 2),
+        ));
+
+        // Sticks strung with Wheat -> Bow
+        self.recipes.push(Recipe::shaped(
+            &[" SW", "S W", " SW"],
+            &[('S', BlockType::Stick), ('W', BlockType::Wheat)],
+            ItemStack::Tool(Tool::new(ToolType::Bow, ToolMaterial::Wood)),
+        ));
+
+        // Gravel tip, Stick shaft and Wheat fletching -> 4 Arrows
+        self.recipes.push(Recipe::shaped(
+            &["G", "S", "W"],
+            &[('G', BlockType::Gravel), ('S', BlockType::Stick), ('W', BlockType::Wheat)],
+            ItemStack::Block(BlockType::Arrow, 4),
+        ));
+
+        // 5 Planks in a hull -> Boat
+        self.recipes.push(Recipe::shaped(
+            &["P P", "PPP"],
+            &[('P', BlockType::Planks)],
+            ItemStack::Block(BlockType::Boat, 1),
+        ));
+
+        // 5 Iron Ingots in a tub -> Minecart
+        self.recipes.push(Recipe::shaped(
+            &["I I", "III"],
+            &[('I', BlockType::IronIngot)],
+            ItemStack::Block(BlockType::Minecart, 1),
+        ));
+
+        // Iron Ingot bars either side of a Stick -> 16 Rails
+        self.recipes.push(Recipe::shaped(
+            &["I I", "ISI", "I I"],
+            &[('I', BlockType::IronIngot), ('S', BlockType::Stick)],
+            ItemStack::Block(BlockType::Rail, 16),
+        ));
+
+        // Three Iron Ingots in a column -> Lightning Rod
+        self.recipes.push(Recipe::shaped(
+            &["I", "I", "I"],
+            &[('I', BlockType::IronIngot)],
+            ItemStack::Block(BlockType::LightningRod, 1),
+        ));
+
+        // === REDSTONE RECIPES ===
+
+        // Iron Ingot + Coal -> 4 Redstone Wire
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::IronIngot, BlockType::Coal],
+            ItemStack::Block(BlockType::RedstoneWire, 4),
+        ));
+
+        // Stick over Cobblestone -> Lever
+        self.recipes.push(Recipe::shaped(
+            &["S", "C"],
+            &[('S', BlockType::Stick), ('C', BlockType::Cobblestone)],
+            ItemStack::Block(BlockType::Lever, 1),
+        ));
+
+        // Stone -> Button
+        self.recipes.push(Recipe::shaped(
+            &["T"],
+            &[('T', BlockType::Stone)],
+            ItemStack::Block(BlockType::StoneButton, 1),
+        ));
+
+        // 2 Stone side by side -> Pressure Plate
+        self.recipes.push(Recipe::shaped(
+            &["TT"],
+            &[('T', BlockType::Stone)],
+            ItemStack::Block(BlockType::PressurePlate, 1),
+        ));
+
+        // Wire over Stick -> Redstone Torch
+        self.recipes.push(Recipe::shaped(
+            &["R", "S"],
+            &[('R', BlockType::RedstoneWire), ('S', BlockType::Stick)],
+            ItemStack::Block(BlockType::RedstoneTorch, 1),
+        ));
+
+        // Torch-Wire-Torch over Stone -> Repeater
+        self.recipes.push(Recipe::shaped(
+            &["TRT", "SSS"],
+            &[('T', BlockType::RedstoneTorch), ('R', BlockType::RedstoneWire), ('S', BlockType::Stone)],
+            ItemStack::Block(BlockType::Repeater, 1),
+        ));
+
+        // Wire around Glass -> Redstone Lamp
+        self.recipes.push(Recipe::shaped(
+            &[" R ", "RGR", " R "],
+            &[('R', BlockType::RedstoneWire), ('G', BlockType::Glass)],
+            ItemStack::Block(BlockType::RedstoneLamp, 1),
+        ));
+
+        // === POTION RECIPES ===
+
+        // Glass in a V -> 3 Glass Bottles
+        self.recipes.push(Recipe::shaped(
+            &["G G", " G "],
+            &[('G', BlockType::Glass)],
+            ItemStack::Block(BlockType::GlassBottle, 3),
+        ));
+
+        // Bottle + Apple + Gold Ingot -> Potion of Regeneration
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::GlassBottle, BlockType::Apple, BlockType::GoldIngot],
+            ItemStack::Block(BlockType::PotionRegeneration, 1),
+        ));
+
+        // Bottle + Redstone Wire -> Potion of Swiftness
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::GlassBottle, BlockType::RedstoneWire],
+            ItemStack::Block(BlockType::PotionSwiftness, 1),
+        ));
+
+        // Bottle + Carrot + Gold Ingot -> Potion of Night Vision
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::GlassBottle, BlockType::Carrot, BlockType::GoldIngot],
+            ItemStack::Block(BlockType::PotionNightVision, 1),
+        ));
+
+        // Bottle + Ice -> Potion of Water Breathing
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::GlassBottle, BlockType::Ice],
+            ItemStack::Block(BlockType::PotionWaterBreathing, 1),
+        ));
+
+        // Iron Ingot + Gravel (for the flint) -> Flint and Steel
+        self.recipes.push(Recipe::shapeless(
+            &[BlockType::IronIngot, BlockType::Gravel],
+            ItemStack::Tool(Tool::new(ToolType::FlintAndSteel, ToolMaterial::Iron)),
+        ));
+
         // === TOOL RECIPES ===
         self.register_tool_recipes();
+        self.register_armor_recipes();
     }
 
     fn register_tool_recipes(&mut self) {
@@ -360,6 +609,60 @@ impl RecipeRegistry {
                 &[('M', block), ('S', BlockType::Stick)],
                 ItemStack::Tool(Tool::new(ToolType::Sword, material)),
             ));
+
+            // Hoe: MM_ / _S_ / _S_
+            self.recipes.push(Recipe::shaped(
+                &["MM", " S", " S"],
+                &[('M', block), ('S', BlockType::Stick)],
+                ItemStack::Tool(Tool::new(ToolType::Hoe, material)),
+            ));
+
+            // Hoe (mirrored): _MM / _S_ / _S_
+            self.recipes.push(Recipe::shaped(
+                &["MM", "S ", "S "],
+                &[('M', block), ('S', BlockType::Stick)],
+                ItemStack::Tool(Tool::new(ToolType::Hoe, material)),
+            ));
+        }
+    }
+
+    fn register_armor_recipes(&mut self) {
+        // Armor is made from planks and smelted ingots (there is no stone armor)
+        let materials = [
+            (BlockType::Planks, ToolMaterial::Wood),
+            (BlockType::IronIngot, ToolMaterial::Iron),
+            (BlockType::GoldIngot, ToolMaterial::Gold),
+            (BlockType::Diamond, ToolMaterial::Diamond),
+        ];
+
+        for (block, material) in materials {
+            // Helmet: MMM / M_M
+            self.recipes.push(Recipe::shaped(
+                &["MMM", "M M"],
+                &[('M', block)],
+                ItemStack::Tool(Tool::new(ToolType::Helmet, material)),
+            ));
+
+            // Chestplate: M_M / MMM / MMM
+            self.recipes.push(Recipe::shaped(
+                &["M M", "MMM", "MMM"],
+                &[('M', block)],
+                ItemStack::Tool(Tool::new(ToolType::Chestplate, material)),
+            ));
+
+            // Leggings: MMM / M_M / M_M
+            self.recipes.push(Recipe::shaped(
+                &["MMM", "M M", "M M"],
+                &[('M', block)],
+                ItemStack::Tool(Tool::new(ToolType::Leggings, material)),
+            ));
+
+            // Boots: M_M / M_M
+            self.recipes.push(Recipe::shaped(
+                &["M M", "M M"],
+                &[('M', block)],
+                ItemStack::Tool(Tool::new(ToolType::Boots, material)),
+            ));
         }
     }
 
@@ -701,6 +1004,40 @@ impl Inventory {
         false
     }
 
+    /// Total quantity of a block type across all slots
+    pub fn count_block(&self, block_type: BlockType) -> u32 {
+        self.slots.iter()
+            .map(|slot| match slot {
+                Some(ItemStack::Block(bt, qty)) if *bt == block_type => *qty,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Take a quantity of a block type from any slots holding it.
+    /// Returns false (and takes nothing) if there aren't enough.
+    pub fn remove_blocks(&mut self, block_type: BlockType, mut quantity: u32) -> bool {
+        if self.count_block(block_type) < quantity {
+            return false;
+        }
+        for slot in self.slots.iter_mut() {
+            if quantity == 0 {
+                break;
+            }
+            if let Some(ItemStack::Block(bt, qty)) = slot {
+                if *bt == block_type {
+                    let taken = quantity.min(*qty);
+                    *qty -= taken;
+                    quantity -= taken;
+                    if *qty == 0 {
+                        *slot = None;
+                    }
+                }
+            }
+        }
+        true
+    }
+
     /// Add an item stack to inventory
     pub fn add_item(&mut self, item: ItemStack) -> bool {
         match item {
@@ -715,6 +1052,37 @@ impl Inventory {
             ItemStack::Tool(tool) => self.add_tool(tool),
         }
     }
+
+    /// Put on the selected armor piece, swapping out whatever was worn in its slot.
+    /// Returns false if the selected item isn't armor.
+    pub fn equip_selected_armor(&mut self) -> bool {
+        let Some(slot) = self.get_selected_tool().and_then(|tool| tool.tool_type.armor_slot()) else {
+            return false;
+        };
+        let Some(ItemStack::Tool(piece)) = self.slots[self.selected_slot].take() else {
+            return false;
+        };
+        self.slots[self.selected_slot] = self.armor[slot].replace(piece).map(ItemStack::Tool);
+        true
+    }
+
+    /// Total protection points from worn armor
+    pub fn armor_points(&self) -> u32 {
+        self.armor.iter().flatten().map(|piece| piece.armor_points()).sum()
+    }
+
+    /// Wear down every worn piece after a hit absorbed `damage`; broken pieces are removed
+    pub fn wear_armor(&mut self, damage: f32) {
+        let wear = ((damage / 4.0).floor() as u32).max(1);
+        for slot in self.armor.iter_mut() {
+            if let Some(piece) = slot {
+                piece.durability = piece.durability.saturating_sub(wear);
+                if piece.durability == 0 {
+                    *slot = None;
+                }
+            }
+        }
+    }
 }
 
 // Hotbar layout constants
@@ -1012,19 +1380,64 @@ impl UIRenderer {
             BlockType::FenceGate => 100.0,
             // Building blocks - Glass Panes
             BlockType::GlassPane => 101.0,
-            BlockType::Air | BlockType::Barrier => 0.0,
+            // Redstone components
+            BlockType::Lever => 102.0,
+            BlockType::StoneButton => 103.0,
+            BlockType::PressurePlate => 104.0,
+            BlockType::RedstoneWire => 105.0,
+            BlockType::RedstoneTorch => 106.0,
+            BlockType::Repeater => 107.0,
+            BlockType::RedstoneLamp | BlockType::RedstoneLampLit => 108.0,
+            // Farming (109-113 are hoes)
+            BlockType::Farmland | BlockType::FarmlandWet => 114.0,
+            BlockType::WheatCrop => 115.0,
+            BlockType::CarrotCrop => 116.0,
+            BlockType::PotatoCrop => 117.0,
+            BlockType::Seeds => 118.0,
+            BlockType::Wheat => 119.0,
+            BlockType::Carrot => 120.0,
+            BlockType::Potato => 121.0,
+            BlockType::Bread => 122.0,
+            BlockType::Sapling => 123.0,
+            BlockType::Apple => 124.0,
+            BlockType::Lead => 125.0,
+            BlockType::Arrow => 130.0,
+            BlockType::Boat => 132.0,
+            BlockType::Minecart => 133.0,
+            // Villages
+            BlockType::Emerald => 126.0,
+            BlockType::Composter => 127.0,
+            BlockType::Anvil => 128.0,
+            BlockType::Lectern => 129.0,
+            // Potions
+            BlockType::GlassBottle => 154.0,
+            BlockType::PotionRegeneration => 155.0,
+            BlockType::PotionSwiftness => 156.0,
+            BlockType::PotionNightVision => 157.0,
+            BlockType::PotionWaterBreathing => 158.0,
+            BlockType::LightningRod => 162.0,
+            BlockType::Air | BlockType::Barrier | BlockType::Fire => 0.0,
         }
     }
 
     fn tool_to_ui_index(tool: &Tool) -> f32 {
-        // Tool indices: 60-79 reserved for tools
-        // Layout: Pickaxe=60-64, Axe=65-69, Shovel=70-74, Sword=75-79
+        // Tool indices: 60-79 reserved for tools, hoes added later at 109-113, bows at 131, armor at 134-153,
+        // flint and steel (always iron) at 161
+        // Layout: Pickaxe=60-64, Axe=65-69, Shovel=70-74, Sword=75-79, Hoe=109-113, Bow=131,
+        //         Helmet=134-138, Chestplate=139-143, Leggings=144-148, Boots=149-153, FlintAndSteel=161
         // Materials: Wood=0, Stone=1, Iron=2, Gold=3, Diamond=4
         let type_offset = match tool.tool_type {
             ToolType::Pickaxe => 60.0,
             ToolType::Axe => 65.0,
             ToolType::Shovel => 70.0,
             ToolType::Sword => 75.0,
+            ToolType::Hoe => 109.0,
+            ToolType::Bow => 131.0,
+            ToolType::Helmet => 134.0,
+            ToolType::Chestplate => 139.0,
+            ToolType::Leggings => 144.0,
+            ToolType::Boots => 149.0,
+            ToolType::FlintAndSteel => 159.0,
         };
         let material_offset = match tool.material {
             ToolMaterial::Wood => 0.0,
@@ -1323,6 +1736,63 @@ impl UIRenderer {
         });
     }
     
+    /// Icon for a tool or armor piece in a slot, with a durability bar once it's worn
+    fn generate_tool_icon(tool: &Tool, x: f32, y: f32, icon_size: f32, base_index: u16) -> (Vec<UIVertex>, Vec<u16>) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let icon_color = [1.0, 1.0, 1.0, 1.0];
+
+        // Render tool icon
+        let tool_type_val = Self::tool_to_ui_index(tool);
+        let icon_base = base_index + vertices.len() as u16;
+        vertices.extend_from_slice(&[
+            UIVertex { position: [x - icon_size, y - icon_size], tex_coords: [0.0, 1.0], color: icon_color, use_texture: tool_type_val },
+            UIVertex { position: [x + icon_size, y - icon_size], tex_coords: [1.0, 1.0], color: icon_color, use_texture: tool_type_val },
+            UIVertex { position: [x + icon_size, y + icon_size], tex_coords: [1.0, 0.0], color: icon_color, use_texture: tool_type_val },
+            UIVertex { position: [x - icon_size, y + icon_size], tex_coords: [0.0, 0.0], color: icon_color, use_texture: tool_type_val },
+        ]);
+        indices.extend_from_slice(&[icon_base, icon_base + 1, icon_base + 2, icon_base, icon_base + 2, icon_base + 3]);
+
+        // Durability bar (only if damaged)
+        if tool.durability < tool.max_durability {
+            let durability_ratio = tool.durability_ratio();
+            let bar_width = icon_size * 1.6;
+            let bar_height = icon_size * 0.15;
+            let bar_y = y - icon_size + bar_height;
+
+            // Background (dark)
+            let bar_bg_base = base_index + vertices.len() as u16;
+            let bg_color = [0.1, 0.1, 0.1, 1.0];
+            vertices.extend_from_slice(&[
+                UIVertex { position: [x - bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
+                UIVertex { position: [x + bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
+                UIVertex { position: [x + bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
+                UIVertex { position: [x - bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
+            ]);
+            indices.extend_from_slice(&[bar_bg_base, bar_bg_base + 1, bar_bg_base + 2, bar_bg_base, bar_bg_base + 2, bar_bg_base + 3]);
+
+            // Foreground (green -> yellow -> red)
+            let bar_color = if durability_ratio > 0.5 {
+                [0.2, 0.8, 0.2, 1.0] // Green
+            } else if durability_ratio > 0.25 {
+                [0.8, 0.8, 0.2, 1.0] // Yellow
+            } else {
+                [0.8, 0.2, 0.2, 1.0] // Red
+            };
+            let fill_width = bar_width * durability_ratio;
+            let bar_fg_base = base_index + vertices.len() as u16;
+            vertices.extend_from_slice(&[
+                UIVertex { position: [x - bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
+                UIVertex { position: [x - bar_width / 2.0 + fill_width, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
+                UIVertex { position: [x - bar_width / 2.0 + fill_width, bar_y], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
+                UIVertex { position: [x - bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
+            ]);
+            indices.extend_from_slice(&[bar_fg_base, bar_fg_base + 1, bar_fg_base + 2, bar_fg_base, bar_fg_base + 2, bar_fg_base + 3]);
+        }
+
+        (vertices, indices)
+    }
+
     pub fn update_inventory_selection(&mut self, device: &wgpu::Device, inventory: &Inventory) {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
@@ -1393,59 +1863,38 @@ impl UIRenderer {
                         }
                     }
                     ItemStack::Tool(tool) => {
-                        // Render tool icon
-                        let tool_type_val = Self::tool_to_ui_index(tool);
-                        let icon_base = vertices.len() as u16;
-                        vertices.extend_from_slice(&[
-                            UIVertex { position: [slot_x - icon_size, HOTBAR_Y - icon_size], tex_coords: [0.0, 1.0], color: icon_color, use_texture: tool_type_val },
-                            UIVertex { position: [slot_x + icon_size, HOTBAR_Y - icon_size], tex_coords: [1.0, 1.0], color: icon_color, use_texture: tool_type_val },
-                            UIVertex { position: [slot_x + icon_size, HOTBAR_Y + icon_size], tex_coords: [1.0, 0.0], color: icon_color, use_texture: tool_type_val },
-                            UIVertex { position: [slot_x - icon_size, HOTBAR_Y + icon_size], tex_coords: [0.0, 0.0], color: icon_color, use_texture: tool_type_val },
-                        ]);
-                        indices.extend_from_slice(&[icon_base, icon_base + 1, icon_base + 2, icon_base, icon_base + 2, icon_base + 3]);
-
-                        // Durability bar (only if damaged)
-                        if tool.durability < tool.max_durability {
-                            let durability_ratio = tool.durability_ratio();
-                            let bar_width = icon_size * 1.6;
-                            let bar_height = icon_size * 0.15;
-                            let bar_y = HOTBAR_Y - icon_size + bar_height;
-
-                            // Background (dark)
-                            let bar_bg_base = vertices.len() as u16;
-                            let bg_color = [0.1, 0.1, 0.1, 1.0];
-                            vertices.extend_from_slice(&[
-                                UIVertex { position: [slot_x - bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x + bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x + bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x - bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bg_color, use_texture: 0.0 },
-                            ]);
-                            indices.extend_from_slice(&[bar_bg_base, bar_bg_base + 1, bar_bg_base + 2, bar_bg_base, bar_bg_base + 2, bar_bg_base + 3]);
-
-                            // Foreground (green -> yellow -> red)
-                            let bar_color = if durability_ratio > 0.5 {
-                                [0.2, 0.8, 0.2, 1.0] // Green
-                            } else if durability_ratio > 0.25 {
-                                [0.8, 0.8, 0.2, 1.0] // Yellow
-                            } else {
-                                [0.8, 0.2, 0.2, 1.0] // Red
-                            };
-                            let fill_width = bar_width * durability_ratio;
-                            let bar_fg_base = vertices.len() as u16;
-                            vertices.extend_from_slice(&[
-                                UIVertex { position: [slot_x - bar_width / 2.0, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x - bar_width / 2.0 + fill_width, bar_y - bar_height], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x - bar_width / 2.0 + fill_width, bar_y], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
-                                UIVertex { position: [slot_x - bar_width / 2.0, bar_y], tex_coords: [0.0, 0.0], color: bar_color, use_texture: 0.0 },
-                            ]);
-                            indices.extend_from_slice(&[bar_fg_base, bar_fg_base + 1, bar_fg_base + 2, bar_fg_base, bar_fg_base + 2, bar_fg_base + 3]);
-                        }
+                        let (tool_verts, tool_inds) = Self::generate_tool_icon(
+                            tool, slot_x, HOTBAR_Y, icon_size, vertices.len() as u16
+                        );
+                        vertices.extend(tool_verts);
+                        indices.extend(tool_inds);
                     }
                     _ => {}
                 }
             }
         }
 
+        // Worn armor in its own slots left of the hotbar, helmet outermost
+        let armor_start_x = panel_left - panel_padding - HOTBAR_SLOT_SIZE
+            - (inventory.armor.len() - 1) as f32 * (HOTBAR_SLOT_SIZE * 2.0 + HOTBAR_DIVIDER_WIDTH);
+        for (i, piece) in inventory.armor.iter().enumerate() {
+            let slot_x = armor_start_x + i as f32 * (HOTBAR_SLOT_SIZE * 2.0 + HOTBAR_DIVIDER_WIDTH);
+            let (slot_verts, slot_inds) = Self::generate_slot_vertices(
+                slot_x, HOTBAR_Y, HOTBAR_SLOT_SIZE * 0.95,
+                0, [0.75, 0.75, 0.8, 0.9], vertices.len() as u16
+            );
+            vertices.extend(slot_verts);
+            indices.extend(slot_inds);
+
+            if let Some(tool) = piece {
+                let (tool_verts, tool_inds) = Self::generate_tool_icon(
+                    tool, slot_x, HOTBAR_Y, icon_size, vertices.len() as u16
+                );
+                vertices.extend(tool_verts);
+                indices.extend(tool_inds);
+            }
+        }
+
         self.inventory_vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Inventory Vertex Buffer"),
             contents: bytemuck::cast_slice(&vertices),
@@ -1815,8 +2264,9 @@ impl UIRenderer {
         queue: &wgpu::Queue,
         view: &wgpu::TextureView,
         texture_bind_group: &wgpu::BindGroup,
-        selected_option: usize,
+        pause_menu: &PauseMenu,
     ) {
+        let selected_option = pause_menu.selected_option;
         let mut vertices: Vec<UIVertex> = Vec::new();
         let mut indices: Vec<u16> = Vec::new();
 
@@ -1850,8 +2300,9 @@ impl UIRenderer {
         vertices.extend(title_verts);
         indices.extend(title_inds);
 
-        // Menu options using bitmap font
-        let options = ["Resume", "Options", "Quit"];
+        // Menu options using bitmap font (Options cycles the flight model)
+        let flight_option = format!("Flight: {}", pause_menu.flight_model.name());
+        let options = ["Resume", flight_option.as_str(), "Quit"];
         let option_size = 0.04;
         let option_line_height = 0.1;
         let option_color = [0.85, 0.85, 0.85, 1.0];
@@ -1863,8 +2314,9 @@ impl UIRenderer {
 
             // Selection indicator
             if idx == selected_option {
+                let arrow_x = -Self::text_width(option, option_size) / 2.0 - 0.05;
                 let (arrow_verts, arrow_inds) = Self::generate_text_vertices(
-                    ">", -0.15, option_y, option_size, color, vertices.len() as u16
+                    ">", arrow_x, option_y, option_size, color, vertices.len() as u16
                 );
                 vertices.extend(arrow_verts);
                 indices.extend(arrow_inds);
@@ -1918,16 +2370,14 @@ impl UIRenderer {
         queue.submit(std::iter::once(encoder.finish()));
     }
 
-    /// Render chest UI overlay
-    pub fn render_chest_ui(
+    /// Render the sign text editor
+    pub fn render_sign_editor(
         &self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         view: &wgpu::TextureView,
         texture_bind_group: &wgpu::BindGroup,
-        chest_ui: &ChestUI,
-        chest_contents: &[Option<(BlockType, u32)>; 9],
-        inventory: &Inventory,
+        sign_ui: &SignEditUI,
     ) {
         let mut vertices: Vec<UIVertex> = Vec::new();
         let mut indices: Vec<u16> = Vec::new();
@@ -1941,56 +2391,264 @@ impl UIRenderer {
         vertices.push(UIVertex { position: [-1.0, 1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
         indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
 
-        // Layout constants
-        let slot_size = 0.055;
-        let slot_spacing = 0.075;
-        let num_slots = 9;
-        let panel_border = 0.015;
-        let row_width = num_slots as f32 * slot_spacing;
-
-        // Main container panel
-        let panel_width = row_width + 0.08;
-        let panel_height = 0.65;
-        let panel_x = -panel_width / 2.0;
-        let panel_y = -0.35;
-
-        let (panel_verts, panel_inds) = Self::generate_nine_slice_panel(
-            panel_x, panel_y, panel_width, panel_height,
-            panel_border, [1.0, 1.0, 1.0, 0.95], vertices.len() as u16
-        );
-        vertices.extend(panel_verts);
-        indices.extend(panel_inds);
-
-        // Title "Chest" using bitmap font
+        // Title "Edit Sign" using bitmap font
         let (title_verts, title_inds) = Self::generate_centered_text(
-            "Chest", 0.0, 0.22, 0.05, [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+            "Edit Sign", 0.0, 0.38, 0.06, [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
         );
         vertices.extend(title_verts);
         indices.extend(title_inds);
 
-        // Chest section
-        let chest_row_y = 0.1;
-        let row_start_x = -(num_slots as f32 * slot_spacing) / 2.0 + slot_spacing / 2.0;
-
-        for i in 0..num_slots {
-            let slot_x = row_start_x + i as f32 * slot_spacing;
-            let is_selected = chest_ui.in_chest_section && chest_ui.selected_slot == i;
+        // Wooden board behind the text
+        let char_size = 0.05;
+        let line_height = 0.09;
+        let board_width = Self::text_width(&"X".repeat(SIGN_LINE_LENGTH + 2), char_size);
+        let board_height = line_height * 4.0 + 0.06;
+        let board_x = -board_width / 2.0;
+        let board_y = -0.12;
+        let board_color = [0.62, 0.47, 0.28, 1.0];
+        let base = vertices.len() as u16;
+        vertices.push(UIVertex { position: [board_x, board_y], tex_coords: [0.0, 0.0], color: board_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [board_x + board_width, board_y], tex_coords: [0.0, 0.0], color: board_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [board_x + board_width, board_y + board_height], tex_coords: [0.0, 0.0], color: board_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [board_x, board_y + board_height], tex_coords: [0.0, 0.0], color: board_color, use_texture: 0.0 });
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
 
-            // Slot background from atlas
-            let slot_type = if is_selected { 1 } else { 0 };
-            let (slot_verts, slot_inds) = Self::generate_slot_vertices(
-                slot_x, chest_row_y, slot_size, slot_type,
-                [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+        // Sign lines, with a cursor on the one being edited
+        let text_color = [0.1, 0.07, 0.04, 1.0];
+        for (idx, line) in sign_ui.lines.iter().enumerate() {
+            let line_y = board_y + board_height - 0.03 - (idx as f32 + 1.0) * line_height + (line_height - char_size) / 2.0;
+            let text = if idx == sign_ui.selected_line {
+                format!("> {} <", line)
+            } else {
+                line.clone()
+            };
+            let (line_verts, line_inds) = Self::generate_centered_text(
+                &text, 0.0, line_y, char_size, text_color, vertices.len() as u16
             );
-            vertices.extend(slot_verts);
-            indices.extend(slot_inds);
+            vertices.extend(line_verts);
+            indices.extend(line_inds);
+        }
 
-            // Draw item if present
-            if let Some((block_type, qty)) = chest_contents[i] {
-                let block_type_val = Self::block_type_to_ui_index(block_type);
-                let icon_size = slot_size * 0.7;
-                let icon_base = vertices.len() as u16;
-                vertices.push(UIVertex { position: [slot_x - icon_size, chest_row_y - icon_size], tex_coords: [0.0, 1.0], color: [1.0, 1.0, 1.0, 1.0], use_texture: block_type_val });
+        // Instructions at bottom using bitmap font
+        let (help_verts, help_inds) = Self::generate_centered_text(
+            "Up/Down: Line  Enter: Next  Esc: Done", 0.0, -0.3, 0.03, [0.8, 0.8, 0.8, 1.0], vertices.len() as u16
+        );
+        vertices.extend(help_verts);
+        indices.extend(help_inds);
+
+        // Create buffers and render
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sign Editor Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sign Editor Index Buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Sign Editor Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Sign Editor Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_pipeline(&self.ui_render_pipeline);
+            render_pass.set_bind_group(0, texture_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Draw sign text in the world: each glyph is laid out on the sign's face plane
+    /// (anchor = center of the face, `right` = text direction) and projected to the screen.
+    /// Callers are responsible for skipping signs that are hidden behind blocks.
+    pub fn render_sign_text(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        texture_bind_group: &wgpu::BindGroup,
+        view_proj: cgmath::Matrix4<f32>,
+        signs: &[(cgmath::Point3<f32>, cgmath::Vector3<f32>, &[String; 4])],
+    ) {
+        let mut vertices: Vec<UIVertex> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+
+        // Sizes in blocks
+        let char_height = 0.09;
+        let char_width = char_height * 0.6;
+        let line_height = 0.11;
+        let text_color = [0.1, 0.07, 0.04, 1.0];
+        let up = cgmath::Vector3::new(0.0, 1.0, 0.0);
+
+        let project = |p: cgmath::Point3<f32>| -> Option<[f32; 2]> {
+            let clip = view_proj * cgmath::Vector4::new(p.x, p.y, p.z, 1.0);
+            if clip.w <= 0.01 {
+                return None;
+            }
+            Some([clip.x / clip.w, clip.y / clip.w])
+        };
+
+        'signs: for (anchor, right, lines) in signs {
+            for (line_idx, line) in lines.iter().enumerate() {
+                let bottom = (1.5 - line_idx as f32) * line_height - char_height / 2.0;
+                let start = -(line.len() as f32 * char_width) / 2.0;
+
+                for (i, c) in line.chars().enumerate() {
+                    if vertices.len() + 4 > u16::MAX as usize {
+                        break 'signs;
+                    }
+                    let left = start + i as f32 * char_width;
+                    let corner = |dx: f32, dy: f32| anchor + right * (left + dx) + up * (bottom + dy);
+                    let (Some(bl), Some(br), Some(tr), Some(tl)) = (
+                        project(corner(0.0, 0.0)),
+                        project(corner(char_width, 0.0)),
+                        project(corner(char_width, char_height)),
+                        project(corner(0.0, char_height)),
+                    ) else {
+                        continue;
+                    };
+
+                    let (u0, v0, u1, v1) = Self::char_to_uv(c);
+                    let idx = vertices.len() as u16;
+                    vertices.extend_from_slice(&[
+                        UIVertex { position: bl, tex_coords: [u0, v1], color: text_color, use_texture: -1.0 },
+                        UIVertex { position: br, tex_coords: [u1, v1], color: text_color, use_texture: -1.0 },
+                        UIVertex { position: tr, tex_coords: [u1, v0], color: text_color, use_texture: -1.0 },
+                        UIVertex { position: tl, tex_coords: [u0, v0], color: text_color, use_texture: -1.0 },
+                    ]);
+                    indices.extend_from_slice(&[idx, idx + 1, idx + 2, idx, idx + 2, idx + 3]);
+                }
+            }
+        }
+
+        if indices.is_empty() {
+            return;
+        }
+
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sign Text Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sign Text Index Buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Sign Text Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        render_pass.set_pipeline(&self.ui_render_pipeline);
+        render_pass.set_bind_group(0, texture_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+    }
+
+    /// Render chest UI overlay
+    pub fn render_chest_ui(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        texture_bind_group: &wgpu::BindGroup,
+        chest_ui: &ChestUI,
+        chest_contents: &[Option<(BlockType, u32)>; 9],
+        inventory: &Inventory,
+    ) {
+        let mut vertices: Vec<UIVertex> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+
+        // Full screen dark overlay
+        let overlay_color = [0.0, 0.0, 0.0, 0.6];
+        let base = vertices.len() as u16;
+        vertices.push(UIVertex { position: [-1.0, -1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [1.0, -1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [1.0, 1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [-1.0, 1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+
+        // Layout constants
+        let slot_size = 0.055;
+        let slot_spacing = 0.075;
+        let num_slots = 9;
+        let panel_border = 0.015;
+        let row_width = num_slots as f32 * slot_spacing;
+
+        // Main container panel
+        let panel_width = row_width + 0.08;
+        let panel_height = 0.65;
+        let panel_x = -panel_width / 2.0;
+        let panel_y = -0.35;
+
+        let (panel_verts, panel_inds) = Self::generate_nine_slice_panel(
+            panel_x, panel_y, panel_width, panel_height,
+            panel_border, [1.0, 1.0, 1.0, 0.95], vertices.len() as u16
+        );
+        vertices.extend(panel_verts);
+        indices.extend(panel_inds);
+
+        // Title "Chest" using bitmap font
+        let (title_verts, title_inds) = Self::generate_centered_text(
+            "Chest", 0.0, 0.22, 0.05, [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+        );
+        vertices.extend(title_verts);
+        indices.extend(title_inds);
+
+        // Chest section
+        let chest_row_y = 0.1;
+        let row_start_x = -(num_slots as f32 * slot_spacing) / 2.0 + slot_spacing / 2.0;
+
+        for i in 0..num_slots {
+            let slot_x = row_start_x + i as f32 * slot_spacing;
+            let is_selected = chest_ui.in_chest_section && chest_ui.selected_slot == i;
+
+            // Slot background from atlas
+            let slot_type = if is_selected { 1 } else { 0 };
+            let (slot_verts, slot_inds) = Self::generate_slot_vertices(
+                slot_x, chest_row_y, slot_size, slot_type,
+                [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+            );
+            vertices.extend(slot_verts);
+            indices.extend(slot_inds);
+
+            // Draw item if present
+            if let Some((block_type, qty)) = chest_contents[i] {
+                let block_type_val = Self::block_type_to_ui_index(block_type);
+                let icon_size = slot_size * 0.7;
+                let icon_base = vertices.len() as u16;
+                vertices.push(UIVertex { position: [slot_x - icon_size, chest_row_y - icon_size], tex_coords: [0.0, 1.0], color: [1.0, 1.0, 1.0, 1.0], use_texture: block_type_val });
                 vertices.push(UIVertex { position: [slot_x + icon_size, chest_row_y - icon_size], tex_coords: [1.0, 1.0], color: [1.0, 1.0, 1.0, 1.0], use_texture: block_type_val });
                 vertices.push(UIVertex { position: [slot_x + icon_size, chest_row_y + icon_size], tex_coords: [1.0, 0.0], color: [1.0, 1.0, 1.0, 1.0], use_texture: block_type_val });
                 vertices.push(UIVertex { position: [slot_x - icon_size, chest_row_y + icon_size], tex_coords: [0.0, 0.0], color: [1.0, 1.0, 1.0, 1.0], use_texture: block_type_val });
@@ -2802,6 +3460,185 @@ impl UIRenderer {
         queue.submit(std::iter::once(encoder.finish()));
     }
 
+    pub fn render_trading_ui(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        texture_bind_group: &wgpu::BindGroup,
+        trading_ui: &TradingUI,
+        villager: &crate::entity::Villager,
+        inventory: &Inventory,
+    ) {
+        let mut vertices: Vec<UIVertex> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+
+        // Full screen dark overlay
+        let overlay_color = [0.0, 0.0, 0.0, 0.6];
+        let base = vertices.len() as u16;
+        vertices.push(UIVertex { position: [-1.0, -1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [1.0, -1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [1.0, 1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        vertices.push(UIVertex { position: [-1.0, 1.0], tex_coords: [0.0, 0.0], color: overlay_color, use_texture: 0.0 });
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+
+        // Layout constants
+        let slot_size = 0.04;
+        let row_spacing = 0.1;
+        let panel_border = 0.015;
+        let first_row_y = 0.26;
+
+        // Main container panel
+        let panel_width = 0.7;
+        let panel_height = 0.9;
+        let panel_x = -panel_width / 2.0;
+        let panel_y = -0.45;
+
+        let (panel_verts, panel_inds) = Self::generate_nine_slice_panel(
+            panel_x, panel_y, panel_width, panel_height,
+            panel_border, [1.0, 1.0, 1.0, 0.95], vertices.len() as u16
+        );
+        vertices.extend(panel_verts);
+        indices.extend(panel_inds);
+
+        // Title: the villager's profession
+        let (title_verts, title_inds) = Self::generate_centered_text(
+            villager.profession.name(), 0.0, 0.36, 0.05, [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+        );
+        vertices.extend(title_verts);
+        indices.extend(title_inds);
+
+        // One row per offer: cost -> result
+        let cost_x = -0.18;
+        let arrow_x = -0.04;
+        let result_x = 0.1;
+        for (i, offer) in villager.trades.iter().enumerate() {
+            let row_y = first_row_y - i as f32 * row_spacing;
+            let (cost_block, cost_qty) = offer.cost;
+            let affordable = !offer.is_sold_out() && inventory.count_block(cost_block) >= cost_qty;
+
+            // Highlight the selected row
+            if trading_ui.selected_offer == i {
+                let highlight = [1.0, 1.0, 1.0, 0.15];
+                let hl_base = vertices.len() as u16;
+                let half_h = row_spacing * 0.45;
+                vertices.push(UIVertex { position: [panel_x + 0.03, row_y - half_h], tex_coords: [0.0, 0.0], color: highlight, use_texture: 0.0 });
+                vertices.push(UIVertex { position: [-panel_x - 0.03, row_y - half_h], tex_coords: [0.0, 0.0], color: highlight, use_texture: 0.0 });
+                vertices.push(UIVertex { position: [-panel_x - 0.03, row_y + half_h], tex_coords: [0.0, 0.0], color: highlight, use_texture: 0.0 });
+                vertices.push(UIVertex { position: [panel_x + 0.03, row_y + half_h], tex_coords: [0.0, 0.0], color: highlight, use_texture: 0.0 });
+                indices.extend_from_slice(&[hl_base, hl_base + 1, hl_base + 2, hl_base, hl_base + 2, hl_base + 3]);
+            }
+
+            let slot_type = if trading_ui.selected_offer == i { 1 } else { 0 };
+            for slot_x in [cost_x, result_x] {
+                let (slot_verts, slot_inds) = Self::generate_slot_vertices(
+                    slot_x, row_y, slot_size, slot_type,
+                    [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+                );
+                vertices.extend(slot_verts);
+                indices.extend(slot_inds);
+            }
+            Self::render_item_in_slot(&mut vertices, &mut indices, cost_x, row_y, slot_size, &ItemStack::Block(cost_block, cost_qty));
+            Self::render_item_in_slot(&mut vertices, &mut indices, result_x, row_y, slot_size, &offer.result);
+
+            // Arrow: green when the player can pay, gray otherwise
+            let arrow_color = if affordable { [0.3, 0.9, 0.3, 1.0] } else { [0.4, 0.4, 0.4, 1.0] };
+            let arrow_width = 0.05;
+            let arrow_height = 0.012;
+            let arrow_base = vertices.len() as u16;
+            vertices.push(UIVertex { position: [arrow_x - arrow_width, row_y - arrow_height], tex_coords: [0.0, 0.0], color: arrow_color, use_texture: 0.0 });
+            vertices.push(UIVertex { position: [arrow_x + arrow_width, row_y - arrow_height], tex_coords: [0.0, 0.0], color: arrow_color, use_texture: 0.0 });
+            vertices.push(UIVertex { position: [arrow_x + arrow_width, row_y + arrow_height], tex_coords: [0.0, 0.0], color: arrow_color, use_texture: 0.0 });
+            vertices.push(UIVertex { position: [arrow_x - arrow_width, row_y + arrow_height], tex_coords: [0.0, 0.0], color: arrow_color, use_texture: 0.0 });
+            indices.extend_from_slice(&[arrow_base, arrow_base + 1, arrow_base + 2, arrow_base, arrow_base + 2, arrow_base + 3]);
+
+            // Stock left, or sold out until the villager restocks at their workstation
+            let (stock_text, stock_color) = if offer.is_sold_out() {
+                ("Sold out".to_string(), [0.9, 0.3, 0.3, 1.0])
+            } else {
+                (format!("{} left", offer.max_uses - offer.uses), [0.8, 0.8, 0.8, 1.0])
+            };
+            let (stock_verts, stock_inds) = Self::generate_text_vertices(
+                &stock_text, result_x + slot_size + 0.03, row_y - 0.012, 0.025, stock_color, vertices.len() as u16
+            );
+            vertices.extend(stock_verts);
+            indices.extend(stock_inds);
+        }
+
+        // Inventory label
+        let (inv_label_verts, inv_label_inds) = Self::generate_centered_text(
+            "Inventory", 0.0, -0.25, 0.035, [0.9, 0.9, 0.9, 1.0], vertices.len() as u16
+        );
+        vertices.extend(inv_label_verts);
+        indices.extend(inv_label_inds);
+
+        // Player inventory (9 slots)
+        let inv_slot_spacing = 0.07;
+        let inv_row_y = -0.31;
+        let inv_start_x = -(9.0 * inv_slot_spacing) / 2.0 + inv_slot_spacing / 2.0;
+        for i in 0..9 {
+            let slot_x = inv_start_x + i as f32 * inv_slot_spacing;
+            let (slot_verts, slot_inds) = Self::generate_slot_vertices(
+                slot_x, inv_row_y, slot_size * 0.8, 0,
+                [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+            );
+            vertices.extend(slot_verts);
+            indices.extend(slot_inds);
+
+            if let Some(ref item_stack) = inventory.slots[i] {
+                Self::render_item_in_slot(&mut vertices, &mut indices, slot_x, inv_row_y, slot_size * 0.8, item_stack);
+            }
+        }
+
+        // Instructions
+        let (inst_verts, inst_inds) = Self::generate_centered_text(
+            "Up/Down: Select  Enter: Trade  Esc: Close",
+            0.0, -0.41, 0.022, [0.7, 0.7, 0.7, 1.0], vertices.len() as u16
+        );
+        vertices.extend(inst_verts);
+        indices.extend(inst_inds);
+
+        // Create buffers and render
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Trading UI Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Trading UI Index Buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Trading UI Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Trading UI Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_pipeline(&self.ui_render_pipeline);
+            render_pass.set_bind_group(0, texture_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+
     /// Helper to render an item (block or tool) in a slot
     fn render_item_in_slot(
         vertices: &mut Vec<UIVertex>,
@@ -2849,6 +3686,7 @@ impl UIRenderer {
         health: f32,
         max_health: f32,
         hunger: f32,
+        armor_points: u32,
         air_supply: f32,
         is_underwater: bool,
         damage_flash: bool,
@@ -2972,6 +3810,49 @@ impl UIRenderer {
             }
         }
 
+        // Armor bar above the hearts (10 chestplates, 2 points each; hidden without armor)
+        if armor_points > 0 {
+            let armor_y = bar_y + 0.05;
+            let full_plates = (armor_points / 2) as i32;
+            let half_plate = armor_points % 2 == 1;
+
+            for i in 0..num_icons {
+                let x = health_start_x + i as f32 * icon_spacing;
+
+                // Plate outline (dark grey background)
+                let outline_color = [0.15, 0.15, 0.17, 0.9];
+                let base = vertices.len() as u16;
+                vertices.extend_from_slice(&[
+                    UIVertex { position: [x - icon_size, armor_y - icon_size], tex_coords: [0.0, 0.0], color: outline_color, use_texture: 0.0 },
+                    UIVertex { position: [x + icon_size, armor_y - icon_size], tex_coords: [0.0, 0.0], color: outline_color, use_texture: 0.0 },
+                    UIVertex { position: [x + icon_size, armor_y + icon_size], tex_coords: [0.0, 0.0], color: outline_color, use_texture: 0.0 },
+                    UIVertex { position: [x - icon_size, armor_y + icon_size], tex_coords: [0.0, 0.0], color: outline_color, use_texture: 0.0 },
+                ]);
+                indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+
+                // Filled plate (silver), left half only for an odd point
+                let fill_right = if i < full_plates {
+                    Some(icon_size * 0.75)
+                } else if i == full_plates && half_plate {
+                    Some(0.0)
+                } else {
+                    None
+                };
+                if let Some(fill_right) = fill_right {
+                    let fill_color = [0.78, 0.8, 0.84, 1.0];
+                    let inner_size = icon_size * 0.75;
+                    let base = vertices.len() as u16;
+                    vertices.extend_from_slice(&[
+                        UIVertex { position: [x - inner_size, armor_y - inner_size], tex_coords: [0.0, 0.0], color: fill_color, use_texture: 0.0 },
+                        UIVertex { position: [x + fill_right, armor_y - inner_size], tex_coords: [0.0, 0.0], color: fill_color, use_texture: 0.0 },
+                        UIVertex { position: [x + fill_right, armor_y + inner_size], tex_coords: [0.0, 0.0], color: fill_color, use_texture: 0.0 },
+                        UIVertex { position: [x - inner_size, armor_y + inner_size], tex_coords: [0.0, 0.0], color: fill_color, use_texture: 0.0 },
+                    ]);
+                    indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+                }
+            }
+        }
+
         // Air bubbles (only when underwater)
         if is_underwater {
             let bubble_y = bar_y + 0.05; // Above hunger bar
@@ -3078,6 +3959,253 @@ impl UIRenderer {
         queue.submit(std::iter::once(encoder.finish()));
     }
 
+    /// Render the flight HUD while piloting (hull, fuel, airspeed, altitude, throttle and stall warning)
+    pub fn render_flight_hud(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        texture_bind_group: &wgpu::BindGroup,
+        plane: &crate::entity::Plane,
+        flight_model: crate::entity::FlightModel,
+    ) {
+        use crate::entity::{FlightModel, PlaneState, PLANE_MAX_FUEL, PLANE_MAX_HEALTH, PLANE_MAX_LANDING_SINK_RATE, PLANE_STALL_SPEED};
+
+        let mut vertices: Vec<UIVertex> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+
+        let add_rect = |vertices: &mut Vec<UIVertex>, indices: &mut Vec<u16>, x0: f32, y0: f32, x1: f32, y1: f32, color: [f32; 4]| {
+            let base = vertices.len() as u16;
+            vertices.extend_from_slice(&[
+                UIVertex { position: [x0, y0], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x1, y0], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x1, y1], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x0, y1], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        };
+
+        // Blink warnings twice a second
+        let blink = (std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() / 250).is_multiple_of(2);
+
+        // Panel in the top-right corner
+        let panel_x = 0.58;
+        let panel_y = 0.6;
+        let panel_width = 0.38;
+        let panel_height = 0.36;
+        add_rect(&mut vertices, &mut indices, panel_x, panel_y, panel_x + panel_width, panel_y + panel_height, [0.0, 0.0, 0.0, 0.5]);
+
+        let text_scale = 0.035;
+        let label_x = panel_x + 0.02;
+        let bar_x = panel_x + 0.13;
+        let bar_width = panel_width - 0.15;
+        let bar_height = 0.025;
+        let line_height = 0.065;
+        let mut line_y = panel_y + panel_height - 0.05;
+
+        // Hull and fuel bars
+        let hull_ratio = plane.health / PLANE_MAX_HEALTH;
+        let hull_color = if hull_ratio > 0.5 {
+            [0.2, 0.8, 0.2, 1.0]
+        } else if hull_ratio > 0.25 {
+            [0.8, 0.8, 0.2, 1.0]
+        } else {
+            [0.8, 0.2, 0.2, 1.0]
+        };
+        let fuel_ratio = plane.fuel / PLANE_MAX_FUEL;
+        let fuel_color = if fuel_ratio > 0.2 || blink { [0.9, 0.6, 0.1, 1.0] } else { [0.5, 0.3, 0.05, 1.0] };
+        for (label, ratio, color) in [("HULL", hull_ratio, hull_color), ("FUEL", fuel_ratio, fuel_color)] {
+            let (text_verts, text_inds) = Self::generate_text_with_shadow(
+                label, label_x, line_y, text_scale, [1.0, 1.0, 1.0, 1.0], vertices.len() as u16
+            );
+            vertices.extend(text_verts);
+            indices.extend(text_inds);
+
+            let bar_y = line_y - bar_height * 0.5;
+            add_rect(&mut vertices, &mut indices, bar_x, bar_y, bar_x + bar_width, bar_y + bar_height, [0.1, 0.1, 0.1, 1.0]);
+            add_rect(&mut vertices, &mut indices, bar_x, bar_y, bar_x + bar_width * ratio.clamp(0.0, 1.0), bar_y + bar_height, color);
+            line_y -= line_height;
+        }
+
+        // Readouts
+        let mut readouts = vec![
+            (format!("SPD {:.0}", plane.airspeed()), [1.0, 1.0, 1.0, 1.0]),
+            (format!("ALT {:.0}", plane.position.y), [1.0, 1.0, 1.0, 1.0]),
+        ];
+        if flight_model == FlightModel::Realistic {
+            let sink_color = if plane.sink_rate() > PLANE_MAX_LANDING_SINK_RATE { [1.0, 0.4, 0.3, 1.0] } else { [1.0, 1.0, 1.0, 1.0] };
+            readouts.push((format!("THR {:.0}%  SINK {:.1}", plane.throttle * 100.0, plane.sink_rate().max(0.0)), sink_color));
+        }
+        for (text, color) in readouts {
+            let (text_verts, text_inds) = Self::generate_text_with_shadow(
+                &text, label_x, line_y, text_scale, color, vertices.len() as u16
+            );
+            vertices.extend(text_verts);
+            indices.extend(text_inds);
+            line_y -= line_height * 0.8;
+        }
+
+        // Warnings in the middle of the screen
+        let stalling = flight_model == FlightModel::Realistic
+            && plane.state == PlaneState::Flying
+            && plane.airspeed() < PLANE_STALL_SPEED;
+        let warning = if stalling {
+            Some("STALL")
+        } else if !plane.has_fuel() {
+            Some("NO FUEL")
+        } else {
+            None
+        };
+        if let Some(warning) = warning.filter(|_| blink) {
+            let (warn_verts, warn_inds) = Self::generate_centered_text(
+                warning, 0.0, 0.3, 0.06, [1.0, 0.25, 0.2, 1.0], vertices.len() as u16
+            );
+            vertices.extend(warn_verts);
+            indices.extend(warn_inds);
+        }
+
+        // Create buffers and render
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Flight HUD Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Flight HUD Index Buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Flight HUD Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Flight HUD Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_pipeline(&self.ui_render_pipeline);
+            render_pass.set_bind_group(0, texture_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Render running status effects down the right side: icon, name and time left
+    pub fn render_status_effects(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        texture_bind_group: &wgpu::BindGroup,
+        effects: &crate::effect::StatusEffects,
+    ) {
+        let mut vertices: Vec<UIVertex> = Vec::new();
+        let mut indices: Vec<u16> = Vec::new();
+
+        let add_rect = |vertices: &mut Vec<UIVertex>, indices: &mut Vec<u16>, x0: f32, y0: f32, x1: f32, y1: f32, color: [f32; 4]| {
+            let base = vertices.len() as u16;
+            vertices.extend_from_slice(&[
+                UIVertex { position: [x0, y0], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x1, y0], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x1, y1], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+                UIVertex { position: [x0, y1], tex_coords: [0.0, 0.0], color, use_texture: 0.0 },
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        };
+
+        // Below the flight HUD panel so both fit while piloting
+        let row_x = 0.58;
+        let row_width = 0.38;
+        let row_height = 0.07;
+        let icon_size = 0.05;
+        let text_scale = 0.03;
+        let mut row_y = 0.52;
+
+        for effect in effects.iter() {
+            add_rect(&mut vertices, &mut indices, row_x, row_y, row_x + row_width, row_y + row_height, [0.0, 0.0, 0.0, 0.5]);
+
+            // Icon: the effect's colour inside a dark border
+            let icon_x = row_x + 0.01;
+            let icon_y = row_y + (row_height - icon_size) * 0.5;
+            add_rect(&mut vertices, &mut indices, icon_x, icon_y, icon_x + icon_size, icon_y + icon_size, [0.1, 0.1, 0.1, 1.0]);
+            add_rect(&mut vertices, &mut indices, icon_x + 0.006, icon_y + 0.006, icon_x + icon_size - 0.006, icon_y + icon_size - 0.006, effect.effect_type.color());
+
+            // Flash the name in the last few seconds
+            let text_color = if effect.remaining < 5.0 && (effect.remaining * 4.0) as u32 % 2 == 1 {
+                [0.6, 0.6, 0.6, 1.0]
+            } else {
+                [1.0, 1.0, 1.0, 1.0]
+            };
+            let seconds = effect.remaining.ceil() as u32;
+            let text = format!("{} {}:{:02}", effect.display_name(), seconds / 60, seconds % 60);
+            let (text_verts, text_inds) = Self::generate_text_with_shadow(
+                &text, icon_x + icon_size + 0.015, row_y + row_height * 0.5, text_scale, text_color, vertices.len() as u16
+            );
+            vertices.extend(text_verts);
+            indices.extend(text_inds);
+
+            row_y -= row_height + 0.01;
+        }
+
+        // Create buffers and render
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Status Effects Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Status Effects Index Buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Status Effects Encoder"),
+        });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Status Effects Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_pipeline(&self.ui_render_pipeline);
+            render_pass.set_bind_group(0, texture_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+
     /// Render death screen overlay
     pub fn render_death_screen(
         &self,
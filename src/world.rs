@@ -1,7 +1,10 @@
 use noise::{NoiseFn, Perlin, Simplex};
 use cgmath::{Vector3, Point3};
-use std::collections::{HashMap, VecDeque};
-use rand::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::effect::{StatusEffectType, TimedEffect};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BlockType {
@@ -81,6 +84,51 @@ pub enum BlockType {
     FenceGate,
     // Building blocks - Glass Panes
     GlassPane,
+    // Redstone components
+    Lever,             // Toggled on/off by the player
+    StoneButton,       // Emits a short pulse when pressed
+    PressurePlate,     // Powered while an entity stands on it
+    RedstoneWire,      // Dust that carries signal strength 0-15
+    RedstoneTorch,     // Inverter: lit unless its attached block is powered
+    Repeater,          // Delays and re-strengthens a signal in one direction
+    RedstoneLamp,      // Unlit lamp
+    RedstoneLampLit,   // Lit lamp (powered)
+    // Farming
+    Farmland,          // Tilled dirt (dry)
+    FarmlandWet,       // Tilled dirt hydrated by nearby water
+    WheatCrop,         // Planted wheat, grows through CROP_MAX_STAGE stages
+    CarrotCrop,        // Planted carrots
+    PotatoCrop,        // Planted potatoes
+    Seeds,             // Plants wheat, dropped from grass
+    Wheat,             // Harvested from mature wheat
+    Carrot,            // Plants carrots, +3 hunger
+    Potato,            // Plants potatoes, +1 hunger
+    Bread,             // Crafted from wheat, +5 hunger
+    // Trees
+    Sapling,           // Dropped by leaves, grows into a tree
+    Apple,             // Rare leaf drop, +4 hunger
+    // Animals
+    Lead,              // Leashes animals to the player
+    // Combat
+    Arrow,             // Bow ammunition, can be pulled back out of blocks
+    // Vehicles
+    Boat,              // Placed on water and paddled around
+    Minecart,          // Placed on rails and rides along the track
+    // Villages
+    Emerald,           // Currency for villager trades
+    Composter,         // Farmer workstation
+    Anvil,             // Blacksmith workstation
+    Lectern,           // Cleric workstation
+    // Potions
+    GlassBottle,           // Crafted from glass, left over after drinking a potion
+    PotionRegeneration,    // Heals over time
+    PotionSwiftness,       // Faster movement
+    PotionNightVision,     // See clearly in the dark
+    PotionWaterBreathing,  // Air never runs out underwater
+    // Fire
+    Fire,                  // Spreads to flammable neighbours and burns them away
+    // Weather
+    LightningRod,          // Draws lightning strikes within range onto itself
 }
 
 impl BlockType {
@@ -97,6 +145,11 @@ impl BlockType {
             BlockType::CookedBeef => Some((8.0, 12.8)),
             BlockType::CookedChicken => Some((6.0, 7.2)),
             BlockType::CookedMutton => Some((6.0, 9.6)),
+            // Farm food
+            BlockType::Carrot => Some((3.0, 3.6)),
+            BlockType::Potato => Some((1.0, 0.6)),
+            BlockType::Bread => Some((5.0, 6.0)),
+            BlockType::Apple => Some((4.0, 2.4)),
             _ => None,
         }
     }
@@ -106,16 +159,40 @@ impl BlockType {
         self.food_properties().is_some()
     }
 
+    /// Effect a food may give when eaten, with the chance (0.0-1.0) of getting it
+    pub fn food_effect(&self) -> Option<(TimedEffect, f64)> {
+        match self {
+            BlockType::RawChicken => Some(((StatusEffectType::Poison, 5.0, 0), 0.3)),
+            _ => None,
+        }
+    }
+
+    /// Effect a potion gives when drunk
+    pub fn potion_effect(&self) -> Option<TimedEffect> {
+        match self {
+            BlockType::PotionRegeneration => Some((StatusEffectType::Regeneration, 45.0, 0)),
+            BlockType::PotionSwiftness => Some((StatusEffectType::Speed, 180.0, 0)),
+            BlockType::PotionNightVision => Some((StatusEffectType::NightVision, 180.0, 0)),
+            BlockType::PotionWaterBreathing => Some((StatusEffectType::WaterBreathing, 180.0, 0)),
+            _ => None,
+        }
+    }
+
     /// Base hardness value (hits to break with fist)
     pub fn hardness(&self) -> f32 {
         match self {
-            BlockType::Air | BlockType::Water | BlockType::Lava => 0.0,
+            BlockType::Air | BlockType::Water | BlockType::Lava | BlockType::Fire => 0.0,
+            BlockType::WheatCrop | BlockType::CarrotCrop | BlockType::PotatoCrop | BlockType::Sapling => 0.0,
             BlockType::Leaves => 1.0,
             BlockType::Grass | BlockType::Dirt => 3.0,
+            BlockType::Farmland | BlockType::FarmlandWet => 3.0,
             BlockType::Sand | BlockType::Gravel | BlockType::Clay => 3.0,
             BlockType::Wood | BlockType::Planks | BlockType::Fence => 5.0,
             BlockType::Stone | BlockType::Cobblestone | BlockType::MossyCobblestone => 10.0,
             BlockType::Brick => 10.0,
+            BlockType::Anvil => 15.0,
+            BlockType::LightningRod => 10.0,
+            BlockType::Composter | BlockType::Lectern => 5.0,
             BlockType::Coal => 10.0,
             BlockType::Iron => 15.0,
             BlockType::Gold => 15.0,
@@ -159,7 +236,8 @@ impl BlockType {
             BlockType::Wood | BlockType::Planks | BlockType::Fence | BlockType::Leaves => Some(ToolType::Axe),
             // Shovel for earth
             BlockType::Dirt | BlockType::Grass | BlockType::Sand
-            | BlockType::Gravel | BlockType::Clay | BlockType::Snow => Some(ToolType::Shovel),
+            | BlockType::Gravel | BlockType::Clay | BlockType::Snow
+            | BlockType::Farmland | BlockType::FarmlandWet => Some(ToolType::Shovel),
             _ => None,
         }
     }
@@ -169,7 +247,16 @@ impl BlockType {
         match self {
             // No collision
             BlockType::Air | BlockType::Water | BlockType::Lava | BlockType::Torch
-            | BlockType::SignPost | BlockType::WallSign => CollisionShape::None,
+            | BlockType::SignPost | BlockType::WallSign | BlockType::Fire
+            | BlockType::LightningRod => CollisionShape::None,
+            // Flat or wall-mounted redstone components
+            BlockType::Lever | BlockType::StoneButton | BlockType::PressurePlate
+            | BlockType::RedstoneWire | BlockType::RedstoneTorch | BlockType::Repeater => CollisionShape::None,
+            // Crops and saplings can be walked through
+            BlockType::WheatCrop | BlockType::CarrotCrop | BlockType::PotatoCrop
+            | BlockType::Sapling => CollisionShape::None,
+            // Rails lie flat on the block below
+            BlockType::Rail => CollisionShape::None,
             // Bottom slabs
             BlockType::StoneSlabBottom | BlockType::WoodSlabBottom
             | BlockType::CobblestoneSlabBottom => CollisionShape::SlabBottom,
@@ -233,10 +320,125 @@ impl BlockType {
         matches!(self, BlockType::WoodFence | BlockType::StoneFence | BlockType::FenceGate)
     }
 
+    /// Check if this block is a sign (standing or on a wall)
+    pub fn is_sign(&self) -> bool {
+        matches!(self, BlockType::SignPost | BlockType::WallSign)
+    }
+
     /// Check if this block is a trapdoor
     pub fn is_trapdoor(&self) -> bool {
         matches!(self, BlockType::WoodTrapdoor | BlockType::IronTrapdoor)
     }
+
+    /// Check if this block is a redstone component with its own small geometry
+    pub fn is_redstone_component(&self) -> bool {
+        matches!(self, BlockType::Lever | BlockType::StoneButton | BlockType::PressurePlate
+            | BlockType::RedstoneWire | BlockType::RedstoneTorch | BlockType::Repeater)
+    }
+
+    /// Check if this block is a redstone lamp (lit or unlit)
+    pub fn is_redstone_lamp(&self) -> bool {
+        matches!(self, BlockType::RedstoneLamp | BlockType::RedstoneLampLit)
+    }
+
+    /// Check if this block is a full opaque block that can conduct redstone power
+    pub fn is_redstone_conductor(&self) -> bool {
+        self.collision_shape() == CollisionShape::FullBlock
+            && !matches!(self, BlockType::Glass | BlockType::Ice | BlockType::Leaves
+                | BlockType::DoorBottom | BlockType::DoorTop | BlockType::Chest)
+            && !self.is_redstone_lamp()
+    }
+
+    /// Check if this block is a growing crop
+    pub fn is_crop(&self) -> bool {
+        matches!(self, BlockType::WheatCrop | BlockType::CarrotCrop | BlockType::PotatoCrop)
+    }
+
+    /// Check if this block is a small plant drawn as crossed quads (crops and saplings)
+    pub fn is_plant(&self) -> bool {
+        self.is_crop() || *self == BlockType::Sapling
+    }
+
+    /// Fire odds for this block as (chance to catch from a nearby fire, chance to burn away),
+    /// in percent per fire tick; None for blocks that never burn
+    pub fn flammability(&self) -> Option<(u32, u32)> {
+        match self {
+            BlockType::Leaves | BlockType::Sapling
+            | BlockType::WheatCrop | BlockType::CarrotCrop | BlockType::PotatoCrop => Some((30, 20)),
+            BlockType::Planks | BlockType::Fence | BlockType::WoodFence | BlockType::FenceGate
+            | BlockType::WoodStairs | BlockType::WoodSlabBottom | BlockType::WoodSlabTop
+            | BlockType::Composter | BlockType::Lectern => Some((10, 8)),
+            BlockType::Wood => Some((5, 3)),
+            _ => None,
+        }
+    }
+
+    /// Check if this block gives a villager their profession
+    pub fn is_workstation(&self) -> bool {
+        matches!(self, BlockType::Composter | BlockType::Anvil | BlockType::Lectern)
+    }
+
+    /// Check if this block is tilled soil
+    pub fn is_farmland(&self) -> bool {
+        matches!(self, BlockType::Farmland | BlockType::FarmlandWet)
+    }
+
+    /// The crop this item plants on farmland, if any
+    pub fn planted_crop(&self) -> Option<BlockType> {
+        match self {
+            BlockType::Seeds => Some(BlockType::WheatCrop),
+            BlockType::Carrot => Some(BlockType::CarrotCrop),
+            BlockType::Potato => Some(BlockType::PotatoCrop),
+            _ => None,
+        }
+    }
+
+    /// Saplings and apples that leaves occasionally drop when broken or decayed
+    pub fn leaf_bonus_drops<R: Rng>(rng: &mut R) -> Vec<BlockType> {
+        let mut drops = Vec::new();
+        if rng.gen_bool(SAPLING_DROP_CHANCE) {
+            drops.push(BlockType::Sapling);
+        }
+        if rng.gen_bool(APPLE_DROP_CHANCE) {
+            drops.push(BlockType::Apple);
+        }
+        drops
+    }
+
+    /// Items dropped when this block is harvested.
+    /// `crop_stage` only matters for crops (see World::get_crop_stage).
+    pub fn harvest_drops(&self, crop_stage: u8) -> Vec<BlockType> {
+        let mut rng = rand::thread_rng();
+        let mature = crop_stage >= CROP_MAX_STAGE;
+        match self {
+            BlockType::Grass => {
+                let mut drops = vec![BlockType::Grass];
+                if rng.gen_bool(GRASS_SEED_CHANCE) {
+                    drops.push(BlockType::Seeds);
+                }
+                drops
+            }
+            BlockType::Farmland | BlockType::FarmlandWet => vec![BlockType::Dirt],
+            BlockType::WallSign => vec![BlockType::SignPost],
+            BlockType::Fire => Vec::new(),
+            BlockType::Leaves => {
+                let mut drops = vec![BlockType::Leaves];
+                drops.extend(Self::leaf_bonus_drops(&mut rng));
+                drops
+            }
+            BlockType::WheatCrop if mature => {
+                let mut drops = vec![BlockType::Wheat];
+                drops.extend(std::iter::repeat_n(BlockType::Seeds, rng.gen_range(1..=3)));
+                drops
+            }
+            BlockType::WheatCrop => vec![BlockType::Seeds],
+            BlockType::CarrotCrop if mature => vec![BlockType::Carrot; rng.gen_range(1..=4)],
+            BlockType::CarrotCrop => vec![BlockType::Carrot],
+            BlockType::PotatoCrop if mature => vec![BlockType::Potato; rng.gen_range(1..=4)],
+            BlockType::PotatoCrop => vec![BlockType::Potato],
+            other => vec![*other],
+        }
+    }
 }
 
 // === TOOL SYSTEM ===
@@ -292,6 +494,18 @@ impl ToolMaterial {
         }
     }
 
+    /// Durability multiplier for armor pieces made of this material
+    pub fn armor_durability_factor(&self) -> u32 {
+        match self {
+            ToolMaterial::Wood => 5,
+            ToolMaterial::Iron => 15,
+            ToolMaterial::Gold => 7,
+            ToolMaterial::Diamond => 33,
+            // There is no stone armor (see register_armor_recipes)
+            ToolMaterial::Stone => 0,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             ToolMaterial::Wood => "Wooden",
@@ -309,15 +523,33 @@ pub enum ToolType {
     Axe,
     Shovel,
     Sword,
+    Hoe,
+    Bow,
+    Helmet,
+    Chestplate,
+    Leggings,
+    Boots,
+    FlintAndSteel,
 }
 
 impl ToolType {
+    /// Armor pieces in the order of their equipment slots
+    pub const ARMOR: [ToolType; 4] = [ToolType::Helmet, ToolType::Chestplate, ToolType::Leggings, ToolType::Boots];
+
+    /// Equipment slot this piece is worn in, or None for tools and weapons
+    pub fn armor_slot(&self) -> Option<usize> {
+        ToolType::ARMOR.iter().position(|piece| piece == self)
+    }
+
     pub fn base_attack_damage(&self) -> f32 {
         match self {
             ToolType::Sword => 4.0,
             ToolType::Axe => 3.0,
             ToolType::Pickaxe => 2.0,
             ToolType::Shovel => 1.5,
+            ToolType::Hoe => 1.0,
+            ToolType::Bow | ToolType::FlintAndSteel => 1.0,
+            ToolType::Helmet | ToolType::Chestplate | ToolType::Leggings | ToolType::Boots => 1.0,
         }
     }
 
@@ -327,6 +559,13 @@ impl ToolType {
             ToolType::Axe => "Axe",
             ToolType::Shovel => "Shovel",
             ToolType::Sword => "Sword",
+            ToolType::Hoe => "Hoe",
+            ToolType::Bow => "Bow",
+            ToolType::Helmet => "Helmet",
+            ToolType::Chestplate => "Chestplate",
+            ToolType::Leggings => "Leggings",
+            ToolType::Boots => "Boots",
+            ToolType::FlintAndSteel => "Flint and Steel",
         }
     }
 }
@@ -341,7 +580,17 @@ pub struct Tool {
 
 impl Tool {
     pub fn new(tool_type: ToolType, material: ToolMaterial) -> Self {
-        let max_durability = material.durability();
+        // Bows wear out per shot rather than per block, so they last longer than wooden tools;
+        // armor wears per hit taken and scales with how much of the body the piece covers
+        let max_durability = match tool_type {
+            ToolType::Bow => 384,
+            ToolType::FlintAndSteel => 64,
+            ToolType::Helmet => 11 * material.armor_durability_factor(),
+            ToolType::Chestplate => 16 * material.armor_durability_factor(),
+            ToolType::Leggings => 15 * material.armor_durability_factor(),
+            ToolType::Boots => 13 * material.armor_durability_factor(),
+            _ => material.durability(),
+        };
         Self {
             tool_type,
             material,
@@ -355,6 +604,19 @@ impl Tool {
         self.tool_type.base_attack_damage() + self.material.attack_bonus()
     }
 
+    /// Protection points this piece gives while worn (0 for tools)
+    pub fn armor_points(&self) -> u32 {
+        let points = match self.material {
+            ToolMaterial::Wood => [1, 3, 2, 1],
+            ToolMaterial::Iron => [2, 6, 5, 2],
+            ToolMaterial::Gold => [2, 5, 3, 1],
+            ToolMaterial::Diamond => [3, 8, 6, 3],
+            // There is no stone armor (see register_armor_recipes)
+            ToolMaterial::Stone => return 0,
+        };
+        self.tool_type.armor_slot().map_or(0, |slot| points[slot])
+    }
+
     /// Mining speed multiplier when used on appropriate block
     pub fn mining_speed(&self) -> f32 {
         self.material.mining_speed()
@@ -398,6 +660,9 @@ impl Tool {
     }
 
     pub fn display_name(&self) -> String {
+        if matches!(self.tool_type, ToolType::Bow | ToolType::FlintAndSteel) {
+            return self.tool_type.name().to_string();
+        }
         format!("{} {}", self.material.name(), self.tool_type.name())
     }
 }
@@ -466,6 +731,59 @@ pub enum BlockFacing {
     West,
 }
 
+impl BlockFacing {
+    /// (dx, dz) step for this direction (North = -Z, East = +X)
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            BlockFacing::North => (0, -1),
+            BlockFacing::South => (0, 1),
+            BlockFacing::East => (1, 0),
+            BlockFacing::West => (-1, 0),
+        }
+    }
+
+    pub fn opposite(&self) -> BlockFacing {
+        match self {
+            BlockFacing::North => BlockFacing::South,
+            BlockFacing::South => BlockFacing::North,
+            BlockFacing::East => BlockFacing::West,
+            BlockFacing::West => BlockFacing::East,
+        }
+    }
+}
+
+/// Track layout of a rail block, worked out from the rails around it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RailShape {
+    NorthSouth,
+    EastWest,
+    Ascending(BlockFacing),          // Slopes up one block toward this side
+    Curve(BlockFacing, BlockFacing), // Joins a north/south side to an east/west side
+}
+
+impl RailShape {
+    /// The two sides a cart can leave the block through
+    pub fn exits(&self) -> [BlockFacing; 2] {
+        match *self {
+            RailShape::NorthSouth => [BlockFacing::North, BlockFacing::South],
+            RailShape::EastWest => [BlockFacing::East, BlockFacing::West],
+            RailShape::Ascending(up) => [up, up.opposite()],
+            RailShape::Curve(a, b) => [a, b],
+        }
+    }
+
+    /// Track height above the rail block's floor at a point inside it (fx, fz in 0..1)
+    pub fn height_at(&self, fx: f32, fz: f32) -> f32 {
+        match *self {
+            RailShape::Ascending(up) => {
+                let (dx, dz) = up.offset();
+                (0.5 + (fx - 0.5) * dx as f32 + (fz - 0.5) * dz as f32).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
 /// Collision shape for blocks with non-standard collision
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CollisionShape {
@@ -483,6 +801,14 @@ pub struct DoorState {
     pub facing: BlockFacing,
 }
 
+/// Points of interest villagers use in a chunk (see World::find_village_sites)
+#[derive(Default)]
+pub struct VillageSites {
+    pub workstations: Vec<((i32, i32, i32), BlockType)>,
+    pub doors: Vec<(i32, i32, i32)>,          // Bottom half of each door
+    pub meeting_points: Vec<(i32, i32, i32)>, // Church lecterns and well centres
+}
+
 /// Bed data (head/foot position and facing)
 #[derive(Clone, Copy, Debug)]
 pub struct BedData {
@@ -553,11 +879,14 @@ pub struct TrapdoorData {
     pub top_half: bool,       // Attached to top or bottom of block space
 }
 
+/// Maximum characters per sign line
+pub const SIGN_LINE_LENGTH: usize = 15;
+
 /// Sign text data
 #[derive(Clone, Debug)]
 pub struct SignData {
     pub lines: [String; 4],
-    pub facing: BlockFacing,
+    pub facing: BlockFacing,  // Direction the text faces
 }
 
 impl SignData {
@@ -576,6 +905,85 @@ pub struct FenceGateData {
     pub facing: BlockFacing,
 }
 
+/// Redstone component state (levers, buttons, plates, torches, repeaters, wire, lamps)
+#[derive(Clone, Copy, Debug)]
+pub struct RedstoneData {
+    pub powered: bool,        // Output state: lever on, button pressed, torch lit, repeater on
+    pub power: u8,            // Wire signal strength (0-15)
+    pub facing: BlockFacing,  // Repeater output direction
+    pub delay: u8,            // Repeater delay in redstone ticks (1-4)
+}
+
+impl RedstoneData {
+    pub fn new(facing: BlockFacing) -> Self {
+        Self {
+            powered: false,
+            power: 0,
+            facing,
+            delay: 1,
+        }
+    }
+}
+
+/// Seconds per redstone tick
+pub const REDSTONE_TICK: f32 = 0.1;
+/// Maximum wire signal strength
+pub const MAX_REDSTONE_POWER: u8 = 15;
+/// How long a stone button stays pressed (redstone ticks)
+const BUTTON_PRESS_TICKS: u64 = 10;
+/// Redstone torches react to their input after this many ticks
+const REDSTONE_TORCH_DELAY: u64 = 1;
+
+/// Crops grow through stages 0..=CROP_MAX_STAGE
+pub const CROP_MAX_STAGE: u8 = 7;
+/// Per-random-tick growth chance on hydrated / dry farmland
+const CROP_GROWTH_CHANCE_WET: f64 = 0.8;
+const CROP_GROWTH_CHANCE_DRY: f64 = 0.35;
+/// Chance that breaking grass also drops seeds
+const GRASS_SEED_CHANCE: f64 = 0.125;
+/// Horizontal distance water hydrates farmland
+const FARMLAND_WATER_RANGE: i32 = 4;
+/// Chance that dry farmland with nothing planted turns back into dirt on a random tick
+const FARMLAND_REVERT_CHANCE: f64 = 0.25;
+
+/// Seconds between random ticks (20 per second)
+pub const RANDOM_TICK_INTERVAL: f32 = 0.05;
+/// Random blocks picked in each 16-high chunk section per random tick
+pub const RANDOM_TICKS_PER_SECTION: u32 = 3;
+const RANDOM_TICK_SECTION_HEIGHT: usize = 16;
+/// Only chunks this close to the player receive random ticks
+const RANDOM_TICK_CHUNK_RADIUS: i32 = 4;
+/// Leaves further than this (through other leaves) from a log decay
+const LEAF_DECAY_RANGE: u32 = 4;
+/// Seconds before a disconnected leaf block decays (random within this range)
+const LEAF_DECAY_DELAY: (f32, f32) = (0.5, 6.0);
+const SAPLING_DROP_CHANCE: f64 = 0.05;
+const APPLE_DROP_CHANCE: f64 = 0.005;
+/// Chance a sapling grows into a tree on a random tick
+const SAPLING_GROW_CHANCE: f64 = 0.15;
+/// Ice within this many blocks of a torch or lava melts
+const ICE_MELT_RANGE: i32 = 2;
+/// Per-chunk chance each random tick that a snow layer settles during a Tundra storm
+const SNOW_ACCUMULATION_CHANCE: f64 = 0.0625;
+/// Seconds between fire spread and burn checks
+const FIRE_TICK_INTERVAL: f32 = 0.5;
+/// Fires age one step per fire tick; at this age they start to burn out
+const FIRE_MAX_AGE: u8 = 15;
+/// Fire with nothing flammable around it goes out after this many ticks
+const FIRE_BARE_GROUND_AGE: u8 = 3;
+/// Chance per fire tick that an old fire over something that doesn't burn goes out
+const FIRE_BURN_OUT_CHANCE: f64 = 0.25;
+/// Chance per fire tick that rain puts out a fire open to the sky
+const FIRE_RAIN_CHANCE: f64 = 0.2;
+/// Chance a burnt-away block leaves fire in its place rather than air
+const FIRE_REPLACE_CHANCE: f64 = 0.6;
+/// Spots near each fire it tries to spread into every fire tick
+const FIRE_SPREAD_ATTEMPTS: u32 = 3;
+/// Spots above a random-ticked lava block it tries to set alight
+const LAVA_IGNITE_ATTEMPTS: u32 = 3;
+/// Lightning picks the tallest block (or lightning rod) within this many blocks of where it falls
+const LIGHTNING_TARGET_RADIUS: i32 = 12;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Biome {
     Plains,
@@ -635,6 +1043,27 @@ pub struct World {
     pub trapdoor_data: HashMap<(i32, i32, i32), TrapdoorData>,
     pub sign_data: HashMap<(i32, i32, i32), SignData>,
     pub fence_gate_data: HashMap<(i32, i32, i32), FenceGateData>,
+    // Redstone circuits
+    pub redstone_data: HashMap<(i32, i32, i32), RedstoneData>,
+    redstone_scheduled: Vec<(u64, (i32, i32, i32))>, // (due tick, component position)
+    redstone_powered_consumers: HashSet<(i32, i32, i32)>, // Doors/trapdoors/gates last seen powered
+    redstone_tick: u64,
+    redstone_tick_timer: f32,
+    redstone_dirty: bool,
+    // Farming: growth stage of player-planted crops (generated crops have no entry and are fully grown)
+    pub crop_stages: HashMap<(i32, i32, i32), u8>,
+    // Random ticks: seeded so the same world seed always evolves the same way
+    random_tick_rng: StdRng,
+    random_tick_timer: f32,
+    // Leaf decay
+    persistent_leaves: HashSet<(i32, i32, i32)>, // Player-placed leaves never decay
+    leaf_decay_checks: VecDeque<(i32, i32, i32)>, // Where logs were removed, waiting for a connectivity check
+    decaying_leaves: Vec<(f32, (i32, i32, i32))>, // (seconds left, position) of disconnected leaves
+    // Items produced by the world itself (decaying leaves); drained by the game loop into dropped items
+    pending_drops: Vec<(Point3<f32>, BlockType)>,
+    // Fire: age of every burning fire block, stepped each fire tick
+    pub fire_ages: HashMap<(i32, i32, i32), u8>,
+    fire_tick_timer: f32,
 }
 
 impl World {
@@ -645,8 +1074,11 @@ impl World {
 
     pub fn new() -> Self {
         let mut rng = rand::thread_rng();
-        let seed: u32 = rng.gen();
+        Self::with_seed(rng.gen())
+    }
 
+    /// Create a world from a fixed seed (terrain and random ticks are reproducible)
+    pub fn with_seed(seed: u32) -> Self {
         let mut world = Self {
             chunks: HashMap::new(),
             // Terrain layers with different seeds for variety
@@ -685,6 +1117,21 @@ impl World {
             trapdoor_data: HashMap::new(),
             sign_data: HashMap::new(),
             fence_gate_data: HashMap::new(),
+            redstone_data: HashMap::new(),
+            redstone_scheduled: Vec::new(),
+            redstone_powered_consumers: HashSet::new(),
+            redstone_tick: 0,
+            redstone_tick_timer: 0.0,
+            redstone_dirty: false,
+            crop_stages: HashMap::new(),
+            random_tick_rng: StdRng::seed_from_u64(seed as u64),
+            random_tick_timer: 0.0,
+            persistent_leaves: HashSet::new(),
+            leaf_decay_checks: VecDeque::new(),
+            decaying_leaves: Vec::new(),
+            pending_drops: Vec::new(),
+            fire_ages: HashMap::new(),
+            fire_tick_timer: 0.0,
         };
 
         // Load initial chunks around spawn
@@ -1639,100 +2086,106 @@ impl World {
     }
     
     fn place_oak_tree(&self, chunk: &mut Chunk, local_x: usize, local_z: usize, ground_y: usize, world_x: i32, world_z: i32) {
-        let trunk_height = 4 + (self.tree_noise.get([world_x as f64 * 0.1, world_z as f64 * 0.1]) * 2.0) as usize;
-        let trunk_height = trunk_height.max(3).min(6);
-        
-        // Place trunk
-        for y in 1..=trunk_height {
-            if ground_y + y < Self::CHUNK_HEIGHT {
-                chunk.blocks[local_x][ground_y + y][local_z] = BlockType::Wood;
+        let shape = self.oak_tree_shape(world_x, ground_y as i32, world_z);
+        Self::place_tree_shape_in_chunk(chunk, local_x, local_z, ground_y, &shape);
+    }
+
+    fn place_pine_tree(&self, chunk: &mut Chunk, local_x: usize, local_z: usize, ground_y: usize, world_x: i32, world_z: i32) {
+        let shape = self.pine_tree_shape(world_x, world_z);
+        Self::place_tree_shape_in_chunk(chunk, local_x, local_z, ground_y, &shape);
+    }
+
+    /// Write a tree shape into a chunk. Trunks overwrite, leaves only fill air,
+    /// and anything outside the chunk is clipped.
+    fn place_tree_shape_in_chunk(chunk: &mut Chunk, local_x: usize, local_z: usize, ground_y: usize, shape: &[(i32, i32, i32, BlockType)]) {
+        for &(dx, dy, dz, block) in shape {
+            let x = local_x as i32 + dx;
+            let y = ground_y as i32 + dy;
+            let z = local_z as i32 + dz;
+            if x < 0 || x >= Self::CHUNK_SIZE as i32 ||
+               z < 0 || z >= Self::CHUNK_SIZE as i32 ||
+               y < 0 || y >= Self::CHUNK_HEIGHT as i32 {
+                continue;
+            }
+
+            let existing = &mut chunk.blocks[x as usize][y as usize][z as usize];
+            if block == BlockType::Wood || *existing == BlockType::Air {
+                *existing = block;
             }
         }
-        
-        // Place leaves in a sphere-like pattern around the top
-        let leaves_center_y = ground_y + trunk_height;
+    }
+
+    /// Oak tree blocks as (dx, dy, dz, block) offsets from the ground block the tree stands on
+    fn oak_tree_shape(&self, world_x: i32, ground_y: i32, world_z: i32) -> Vec<(i32, i32, i32, BlockType)> {
+        let trunk_height = 4 + (self.tree_noise.get([world_x as f64 * 0.1, world_z as f64 * 0.1]) * 2.0).max(0.0) as i32;
+        let trunk_height = trunk_height.clamp(3, 6);
+        let mut shape = Vec::new();
+
+        // Trunk
+        for y in 1..=trunk_height {
+            shape.push((0, y, 0, BlockType::Wood));
+        }
+
+        // Leaves in a sphere-like pattern around the top
         let leaves_radius = 2;
-        
-        for dx in -(leaves_radius as i32)..=(leaves_radius as i32) {
-            for dz in -(leaves_radius as i32)..=(leaves_radius as i32) {
-                for dy in -(leaves_radius as i32)..=(leaves_radius as i32) {
-                    let leaf_x = local_x as i32 + dx;
-                    let leaf_z = local_z as i32 + dz;
-                    let leaf_y = leaves_center_y as i32 + dy;
-                    
-                    // Check bounds
-                    if leaf_x >= 0 && leaf_x < Self::CHUNK_SIZE as i32 && 
-                       leaf_z >= 0 && leaf_z < Self::CHUNK_SIZE as i32 &&
-                       leaf_y >= 0 && leaf_y < Self::CHUNK_HEIGHT as i32 {
-                        
-                        // Create a roughly spherical shape
-                        let distance_sq = dx * dx + dy * dy + dz * dz;
-                        if distance_sq <= (leaves_radius * leaves_radius) as i32 {
-                            // Don't replace trunk blocks
-                            if dx == 0 && dz == 0 && dy <= 0 {
-                                continue;
-                            }
-                            
-                            // Add some randomness to leaf placement
-                            let leaf_noise = self.tree_noise.get([
-                                (world_x + dx) as f64 * 0.3, 
-                                leaf_y as f64 * 0.3, 
-                                (world_z + dz) as f64 * 0.3
-                            ]);
-                            if leaf_noise > -0.3 {
-                                if chunk.blocks[leaf_x as usize][leaf_y as usize][leaf_z as usize] == BlockType::Air {
-                                    chunk.blocks[leaf_x as usize][leaf_y as usize][leaf_z as usize] = BlockType::Leaves;
-                                }
-                            }
-                        }
+        for dx in -leaves_radius..=leaves_radius {
+            for dz in -leaves_radius..=leaves_radius {
+                for dy in -leaves_radius..=leaves_radius {
+                    // Create a roughly spherical shape
+                    let distance_sq = dx * dx + dy * dy + dz * dz;
+                    if distance_sq > leaves_radius * leaves_radius {
+                        continue;
+                    }
+                    // Don't replace trunk blocks
+                    if dx == 0 && dz == 0 && dy <= 0 {
+                        continue;
+                    }
+
+                    // Add some randomness to leaf placement
+                    let leaf_y = ground_y + trunk_height + dy;
+                    let leaf_noise = self.tree_noise.get([
+                        (world_x + dx) as f64 * 0.3,
+                        leaf_y as f64 * 0.3,
+                        (world_z + dz) as f64 * 0.3
+                    ]);
+                    if leaf_noise > -0.3 {
+                        shape.push((dx, trunk_height + dy, dz, BlockType::Leaves));
                     }
                 }
             }
         }
+        shape
     }
-    
-    fn place_pine_tree(&self, chunk: &mut Chunk, local_x: usize, local_z: usize, ground_y: usize, world_x: i32, world_z: i32) {
-        let trunk_height = 6 + (self.tree_noise.get([world_x as f64 * 0.1, world_z as f64 * 0.1]) * 3.0) as usize;
-        let trunk_height = trunk_height.max(5).min(8);
-        
-        // Place trunk
+
+    /// Pine tree blocks as (dx, dy, dz, block) offsets from the ground block the tree stands on
+    fn pine_tree_shape(&self, world_x: i32, world_z: i32) -> Vec<(i32, i32, i32, BlockType)> {
+        let trunk_height = 6 + (self.tree_noise.get([world_x as f64 * 0.1, world_z as f64 * 0.1]) * 3.0).max(0.0) as i32;
+        let trunk_height = trunk_height.clamp(5, 8);
+        let mut shape = Vec::new();
+
+        // Trunk
         for y in 1..=trunk_height {
-            if ground_y + y < Self::CHUNK_HEIGHT {
-                chunk.blocks[local_x][ground_y + y][local_z] = BlockType::Wood;
-            }
+            shape.push((0, y, 0, BlockType::Wood));
         }
-        
-        // Place leaves in a conical pattern (pine tree shape)
+
+        // Leaves in a conical pattern (pine tree shape)
         for layer in 0..=(trunk_height / 2) {
-            let layer_y = ground_y + trunk_height - layer;
             let layer_radius = (layer / 2 + 1).min(3);
-            
-            for dx in -(layer_radius as i32)..=(layer_radius as i32) {
-                for dz in -(layer_radius as i32)..=(layer_radius as i32) {
-                    let leaf_x = local_x as i32 + dx;
-                    let leaf_z = local_z as i32 + dz;
-                    
-                    if leaf_x >= 0 && leaf_x < Self::CHUNK_SIZE as i32 && 
-                       leaf_z >= 0 && leaf_z < Self::CHUNK_SIZE as i32 &&
-                       layer_y < Self::CHUNK_HEIGHT {
-                        
-                        let distance_sq = dx * dx + dz * dz;
-                        if distance_sq <= (layer_radius * layer_radius) as i32 {
-                            // Don't replace trunk blocks
-                            if dx == 0 && dz == 0 {
-                                continue;
-                            }
-                            
-                            if chunk.blocks[leaf_x as usize][layer_y][leaf_z as usize] == BlockType::Air {
-                                chunk.blocks[leaf_x as usize][layer_y][leaf_z as usize] = BlockType::Leaves;
-                            }
-                        }
+            for dx in -layer_radius..=layer_radius {
+                for dz in -layer_radius..=layer_radius {
+                    // Don't replace trunk blocks
+                    if dx == 0 && dz == 0 {
+                        continue;
+                    }
+                    if dx * dx + dz * dz <= layer_radius * layer_radius {
+                        shape.push((dx, trunk_height - layer, dz, BlockType::Leaves));
                     }
                 }
             }
         }
+        shape
     }
-    
+
     fn place_cactus(&self, chunk: &mut Chunk, local_x: usize, local_z: usize, ground_y: usize, world_x: i32, world_z: i32) {
         let cactus_height = 2 + (self.tree_noise.get([world_x as f64 * 0.15, world_z as f64 * 0.15]) * 2.0) as usize;
         let cactus_height = cactus_height.max(1).min(4);
@@ -1815,12 +2268,14 @@ impl World {
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Planks;
                     } else if dy <= 3 && is_edge && !is_corner {
                         // Walls
-                        if dy == 2 && (dx == 0 || dz == 0) && !is_corner {
+                        if dy == 1 && dx == 0 && dz == 2 {
+                            // Front door (villagers shut it at night)
+                            chunk.blocks[x as usize][y][z as usize] = BlockType::DoorBottom;
+                        } else if dy == 2 && dx == 0 && dz == 2 {
+                            chunk.blocks[x as usize][y][z as usize] = BlockType::DoorTop;
+                        } else if dy == 2 && (dx == 0 || dz == 0) && !is_corner {
                             // Windows (glass)
                             chunk.blocks[x as usize][y][z as usize] = BlockType::Ice;
-                        } else if dy == 1 && dx == 0 && dz == 2 {
-                            // Door opening (air)
-                            chunk.blocks[x as usize][y][z as usize] = BlockType::Air;
                         } else {
                             chunk.blocks[x as usize][y][z as usize] = BlockType::Planks;
                         }
@@ -1865,11 +2320,13 @@ impl World {
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Planks;
                     } else if dy <= 5 && is_edge && !is_corner {
                         // Walls
-                        if (dy == 2 || dy == 5) && (dx == 0 || dz == 0) {
+                        if dy == 1 && dx == 0 && dz == 3 {
+                            // Front door (villagers shut it at night)
+                            chunk.blocks[x as usize][y][z as usize] = BlockType::DoorBottom;
+                        } else if dy == 2 && dx == 0 && dz == 3 {
+                            chunk.blocks[x as usize][y][z as usize] = BlockType::DoorTop;
+                        } else if (dy == 2 || dy == 5) && (dx == 0 || dz == 0) {
                             chunk.blocks[x as usize][y][z as usize] = BlockType::Ice;
-                        } else if dy == 1 && dx == 0 && dz == 3 {
-                            // Door
-                            chunk.blocks[x as usize][y][z as usize] = BlockType::Air;
                         } else {
                             chunk.blocks[x as usize][y][z as usize] = BlockType::Planks;
                         }
@@ -1918,6 +2375,9 @@ impl World {
                     } else if dy == 1 && dx == -2 && dz == -2 {
                         // Chest
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Chest;
+                    } else if dy == 1 && dx == 2 && dz == -2 {
+                        // Anvil (blacksmith workstation)
+                        chunk.blocks[x as usize][y][z as usize] = BlockType::Anvil;
                     } else if dy <= 3 && !is_edge {
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Air;
                     }
@@ -1958,6 +2418,9 @@ impl World {
                     } else if dy == 8 && in_tower && dx.abs() <= 1 && dz >= 2 && dz <= 4 {
                         // Tower roof
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Stone;
+                    } else if dy == 1 && dx == 0 && dz == -3 {
+                        // Lectern at the front of the nave (cleric workstation)
+                        chunk.blocks[x as usize][y][z as usize] = BlockType::Lectern;
                     } else if dy <= 4 && !is_edge {
                         chunk.blocks[x as usize][y][z as usize] = BlockType::Air;
                     }
@@ -1989,11 +2452,20 @@ impl World {
                             chunk.blocks[x as usize][ground_y + 1][z as usize] = BlockType::Fence;
                         }
                     } else {
-                        // Farmland with crops
-                        chunk.blocks[x as usize][ground_y][z as usize] = BlockType::Dirt;
-                        // Plant crops in alternating pattern
-                        if ground_y + 1 < Self::CHUNK_HEIGHT && (dx + dz) % 2 == 0 {
-                            chunk.blocks[x as usize][ground_y + 1][z as usize] = BlockType::Leaves;
+                        // Hydrated farmland (water channel runs down the middle)
+                        chunk.blocks[x as usize][ground_y][z as usize] = BlockType::FarmlandWet;
+                        // Rows of fully grown crops, a different crop every few columns
+                        let is_channel = dx == 0 && dz.abs() <= 2;
+                        if ground_y + 1 < Self::CHUNK_HEIGHT && dx == -3 && dz == -3 {
+                            // Composter in the corner (farmer workstation)
+                            chunk.blocks[x as usize][ground_y + 1][z as usize] = BlockType::Composter;
+                        } else if ground_y + 1 < Self::CHUNK_HEIGHT && !is_channel {
+                            let crop = match dx.rem_euclid(3) {
+                                0 => BlockType::WheatCrop,
+                                1 => BlockType::CarrotCrop,
+                                _ => BlockType::PotatoCrop,
+                            };
+                            chunk.blocks[x as usize][ground_y + 1][z as usize] = crop;
                         }
                     }
                 }
@@ -2140,9 +2612,45 @@ impl World {
             return;
         }
         
-        if let Some(chunk) = self.chunks.get_mut(&(chunk_x, chunk_z)) {
+        let previous = if let Some(chunk) = self.chunks.get_mut(&(chunk_x, chunk_z)) {
+            let previous = chunk.blocks[local_x][y as usize][local_z];
             chunk.blocks[local_x][y as usize][local_z] = block_type;
             chunk.dirty = true; // Mark chunk as needing mesh regeneration
+            previous
+        } else {
+            return;
+        };
+
+        if !block_type.is_crop() {
+            self.crop_stages.remove(&(x, y, z));
+        }
+
+        // Losing a log may leave nearby leaves disconnected
+        if previous == BlockType::Wood && block_type != BlockType::Wood && !self.leaf_decay_checks.contains(&(x, y, z)) {
+            self.leaf_decay_checks.push_back((x, y, z));
+        }
+        if block_type != BlockType::Leaves {
+            self.persistent_leaves.remove(&(x, y, z));
+        }
+        if !block_type.is_sign() {
+            self.sign_data.remove(&(x, y, z));
+        }
+        // Track fires so they can spread and burn out
+        if block_type == BlockType::Fire {
+            self.fire_ages.entry((x, y, z)).or_insert(0);
+        } else {
+            self.fire_ages.remove(&(x, y, z));
+        }
+
+        // Any block change can open or close a circuit
+        if !self.redstone_data.is_empty() {
+            let pos = (x, y, z);
+            let keeps_data = block_type.is_redstone_component() || block_type.is_redstone_lamp();
+            if !keeps_data && self.redstone_data.remove(&pos).is_some() {
+                self.redstone_scheduled.retain(|(_, p)| *p != pos);
+                self.torch_orientations.remove(&pos);
+            }
+            self.redstone_dirty = true;
         }
     }
     
@@ -2346,6 +2854,9 @@ impl World {
     pub fn place_block(&mut self, x: i32, y: i32, z: i32, block_type: BlockType) -> bool {
         if self.can_place_block_at(x, y, z) {
             self.set_block(x, y, z, block_type);
+            if block_type == BlockType::Leaves {
+                self.persistent_leaves.insert((x, y, z));
+            }
             // Trigger water flow updates for adjacent water blocks
             self.trigger_water_updates_around(x, y, z);
             true
@@ -2456,6 +2967,50 @@ impl World {
         }
     }
 
+    /// Place a sign - on top of a block it stands on a post facing the player,
+    /// on a wall it hangs flat against the wall
+    pub fn place_sign(&mut self, x: i32, y: i32, z: i32, face: TorchFace, player_facing: BlockFacing) -> bool {
+        let (block_type, facing) = match face {
+            TorchFace::Top => (BlockType::SignPost, player_facing),
+            TorchFace::North => (BlockType::WallSign, BlockFacing::North),
+            TorchFace::South => (BlockType::WallSign, BlockFacing::South),
+            TorchFace::East => (BlockType::WallSign, BlockFacing::East),
+            TorchFace::West => (BlockType::WallSign, BlockFacing::West),
+        };
+
+        // Wall signs need a solid block behind them
+        if block_type == BlockType::WallSign {
+            let (dx, dz) = facing.offset();
+            match self.get_block(x - dx, y, z - dz) {
+                Some(wall) if wall.collision_shape() == CollisionShape::FullBlock => {}
+                _ => return false,
+            }
+        }
+
+        if self.can_place_block_at(x, y, z) {
+            self.set_block(x, y, z, block_type);
+            self.sign_data.insert((x, y, z), SignData::new(facing));
+            self.mark_neighbors_dirty(x, y, z);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn get_sign_data(&self, x: i32, y: i32, z: i32) -> Option<&SignData> {
+        self.sign_data.get(&(x, y, z))
+    }
+
+    /// Replace the text on a sign, trimming each line to SIGN_LINE_LENGTH
+    pub fn set_sign_text(&mut self, x: i32, y: i32, z: i32, lines: [String; 4]) -> bool {
+        if let Some(data) = self.sign_data.get_mut(&(x, y, z)) {
+            data.lines = lines.map(|line| line.chars().take(SIGN_LINE_LENGTH).collect());
+            true
+        } else {
+            false
+        }
+    }
+
     fn mark_neighbors_dirty(&mut self, x: i32, _y: i32, z: i32) {
         // Mark all potentially affected chunks as dirty
         let offsets = [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)];
@@ -2605,6 +3160,7 @@ impl World {
         match self.get_block(x, y, z) {
             Some(BlockType::Air) => true,
             Some(BlockType::Water) => true, // Can update existing water
+            Some(BlockType::Fire) => true,  // Flowing water puts fires out
             _ => false,
         }
     }
@@ -2882,13 +3438,11 @@ impl World {
             return None;
         };
 
-        // Toggle the door state
-        if let Some(state) = self.door_states.get_mut(&bottom_pos) {
-            state.open = !state.open;
-            Some(state.open)
-        } else {
-            None
-        }
+        // Doors generated in village houses have no state until first used (closed, facing the street)
+        let state = self.door_states.entry(bottom_pos)
+            .or_insert(DoorState { open: false, facing: BlockFacing::South });
+        state.open = !state.open;
+        Some(state.open)
     }
 
     /// Check if a door at this position is open
@@ -3080,4 +3634,1515 @@ impl World {
             self.set_block(x, y, z, new_type);
         }
     }
-}
\ No newline at end of file
+
+    // ========== Redstone System ==========
+
+    const NEIGHBOR_OFFSETS: [(i32, i32, i32); 6] = [
+        (1, 0, 0), (-1, 0, 0),
+        (0, 1, 0), (0, -1, 0),
+        (0, 0, 1), (0, 0, -1),
+    ];
+
+    const HORIZONTAL_FACINGS: [BlockFacing; 4] = [
+        BlockFacing::North, BlockFacing::South, BlockFacing::East, BlockFacing::West,
+    ];
+
+    /// Block that a wall or floor mounted component (lever, button, redstone torch) hangs on
+    pub fn redstone_attachment(x: i32, y: i32, z: i32, face: TorchFace) -> (i32, i32, i32) {
+        match face {
+            TorchFace::Top => (x, y - 1, z),
+            TorchFace::North => (x, y, z + 1),
+            TorchFace::South => (x, y, z - 1),
+            TorchFace::East => (x - 1, y, z),
+            TorchFace::West => (x + 1, y, z),
+        }
+    }
+
+    /// Place a redstone component or lamp.
+    /// Levers, buttons and redstone torches attach to `face`; wire, plates and repeaters
+    /// need a solid block underneath. Repeaters output toward `facing`.
+    pub fn place_redstone_component(&mut self, x: i32, y: i32, z: i32, block_type: BlockType, face: TorchFace, facing: BlockFacing) -> bool {
+        if !block_type.is_redstone_component() && !block_type.is_redstone_lamp() {
+            return false;
+        }
+        if !self.can_place_block_at(x, y, z) {
+            return false;
+        }
+
+        let wall_mounted = matches!(block_type, BlockType::Lever | BlockType::StoneButton | BlockType::RedstoneTorch);
+        if block_type.is_redstone_component() {
+            let (sx, sy, sz) = if wall_mounted {
+                Self::redstone_attachment(x, y, z, face)
+            } else {
+                (x, y - 1, z)
+            };
+            let has_support = self.get_block(sx, sy, sz).is_some_and(|b| b.is_redstone_conductor());
+            if !has_support {
+                return false;
+            }
+        }
+
+        // Lamps always start unlit; propagation lights them if powered
+        let block_type = if block_type == BlockType::RedstoneLampLit { BlockType::RedstoneLamp } else { block_type };
+        self.set_block(x, y, z, block_type);
+        if wall_mounted {
+            // Reuse torch_orientations for the attached face, like torches and ladders
+            self.torch_orientations.insert((x, y, z), face);
+        }
+
+        let mut data = RedstoneData::new(facing);
+        // Redstone torches start lit and switch off once they see their input
+        data.powered = block_type == BlockType::RedstoneTorch;
+        self.redstone_data.insert((x, y, z), data);
+        self.redstone_dirty = true;
+        self.mark_neighbors_dirty(x, y, z);
+        true
+    }
+
+    pub fn get_redstone_data(&self, x: i32, y: i32, z: i32) -> Option<&RedstoneData> {
+        self.redstone_data.get(&(x, y, z))
+    }
+
+    /// Flip a lever. Returns the new state, or None if there is no lever here.
+    pub fn toggle_lever(&mut self, x: i32, y: i32, z: i32) -> Option<bool> {
+        if self.get_block(x, y, z) != Some(BlockType::Lever) {
+            return None;
+        }
+        let data = self.redstone_data.get_mut(&(x, y, z))?;
+        data.powered = !data.powered;
+        let now_on = data.powered;
+        self.redstone_dirty = true;
+        self.mark_neighbors_dirty(x, y, z);
+        Some(now_on)
+    }
+
+    /// Press a stone button. It releases itself after a short pulse.
+    /// Returns false if there is no button here or it is already pressed.
+    pub fn press_button(&mut self, x: i32, y: i32, z: i32) -> bool {
+        if self.get_block(x, y, z) != Some(BlockType::StoneButton) {
+            return false;
+        }
+        match self.redstone_data.get_mut(&(x, y, z)) {
+            Some(data) if !data.powered => data.powered = true,
+            _ => return false,
+        }
+        self.schedule_redstone_tick((x, y, z), BUTTON_PRESS_TICKS);
+        self.redstone_dirty = true;
+        self.mark_neighbors_dirty(x, y, z);
+        true
+    }
+
+    /// Cycle a repeater's delay (1 -> 2 -> 3 -> 4 -> 1). Returns the new delay.
+    pub fn cycle_repeater_delay(&mut self, x: i32, y: i32, z: i32) -> Option<u8> {
+        if self.get_block(x, y, z) != Some(BlockType::Repeater) {
+            return None;
+        }
+        let data = self.redstone_data.get_mut(&(x, y, z))?;
+        data.delay = data.delay % 4 + 1;
+        let delay = data.delay;
+        self.mark_neighbors_dirty(x, y, z);
+        Some(delay)
+    }
+
+    /// Press or release pressure plates based on entity feet positions
+    pub fn update_pressure_plates(&mut self, feet_positions: &[Point3<f32>]) {
+        let plates: Vec<(i32, i32, i32)> = self.redstone_data.keys()
+            .filter(|&&(x, y, z)| self.get_block(x, y, z) == Some(BlockType::PressurePlate))
+            .copied()
+            .collect();
+
+        for pos in plates {
+            let pressed = feet_positions.iter().any(|p| {
+                p.x.floor() as i32 == pos.0
+                    && (p.y + 0.01).floor() as i32 == pos.1
+                    && p.z.floor() as i32 == pos.2
+            });
+            let changed = match self.redstone_data.get_mut(&pos) {
+                Some(data) if data.powered != pressed => {
+                    data.powered = pressed;
+                    true
+                }
+                _ => false,
+            };
+            if changed {
+                self.redstone_dirty = true;
+                self.mark_neighbors_dirty(pos.0, pos.1, pos.2);
+            }
+        }
+    }
+
+    /// Signal strength of redstone wire at a position (0 if not wire)
+    pub fn get_redstone_power(&self, x: i32, y: i32, z: i32) -> u8 {
+        if self.get_block(x, y, z) != Some(BlockType::RedstoneWire) {
+            return 0;
+        }
+        self.redstone_data.get(&(x, y, z)).map_or(0, |d| d.power)
+    }
+
+    /// Advance circuits by real time, running one redstone tick per REDSTONE_TICK seconds
+    pub fn update_redstone(&mut self, dt: f32) {
+        self.redstone_tick_timer += dt;
+        while self.redstone_tick_timer >= REDSTONE_TICK {
+            self.redstone_tick_timer -= REDSTONE_TICK;
+            self.tick_redstone();
+        }
+        // Don't try to catch up after a long stall
+        self.redstone_tick_timer = self.redstone_tick_timer.min(REDSTONE_TICK);
+    }
+
+    /// Run a single redstone tick: fire scheduled updates that are due, then re-propagate power
+    pub fn tick_redstone(&mut self) {
+        self.redstone_tick += 1;
+        let now = self.redstone_tick;
+
+        let (due, pending): (Vec<_>, Vec<_>) = self.redstone_scheduled
+            .drain(..)
+            .partition(|(tick, _)| *tick <= now);
+        self.redstone_scheduled = pending;
+
+        for (_, pos) in due {
+            self.fire_scheduled_redstone(pos);
+        }
+
+        if self.redstone_dirty {
+            self.redstone_dirty = false;
+            self.propagate_redstone();
+        }
+    }
+
+    fn schedule_redstone_tick(&mut self, pos: (i32, i32, i32), delay: u64) {
+        if !self.redstone_scheduled.iter().any(|(_, p)| *p == pos) {
+            self.redstone_scheduled.push((self.redstone_tick + delay, pos));
+        }
+    }
+
+    fn fire_scheduled_redstone(&mut self, pos: (i32, i32, i32)) {
+        let (x, y, z) = pos;
+        let new_state = match self.get_block(x, y, z) {
+            Some(BlockType::StoneButton) => false,
+            Some(BlockType::RedstoneTorch) => !self.is_redstone_torch_input_powered(x, y, z),
+            Some(BlockType::Repeater) => self.is_repeater_input_powered(x, y, z),
+            _ => return,
+        };
+
+        let changed = match self.redstone_data.get_mut(&pos) {
+            Some(data) if data.powered != new_state => {
+                data.powered = new_state;
+                true
+            }
+            _ => false,
+        };
+        if changed {
+            self.redstone_dirty = true;
+            self.mark_neighbors_dirty(x, y, z);
+        }
+    }
+
+    /// Recompute wire strength, drive lamps/doors/trapdoors/gates, and schedule
+    /// torches and repeaters whose input no longer matches their output
+    fn propagate_redstone(&mut self) {
+        // 1. Wire: flood fill outward from directly powered wire, losing 1 per block
+        let wires: Vec<(i32, i32, i32)> = self.redstone_data.keys()
+            .filter(|&&(x, y, z)| self.get_block(x, y, z) == Some(BlockType::RedstoneWire))
+            .copied()
+            .collect();
+
+        let mut strength: HashMap<(i32, i32, i32), u8> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for &pos in &wires {
+            if self.wire_receives_direct_power(pos.0, pos.1, pos.2) {
+                strength.insert(pos, MAX_REDSTONE_POWER);
+                queue.push_back(pos);
+            }
+        }
+        while let Some(pos) = queue.pop_front() {
+            let level = strength[&pos];
+            if level <= 1 {
+                continue;
+            }
+            for next in self.wire_neighbors(pos.0, pos.1, pos.2) {
+                if strength.get(&next).copied().unwrap_or(0) < level - 1 {
+                    strength.insert(next, level - 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut changed_wires = Vec::new();
+        for pos in wires {
+            let level = strength.get(&pos).copied().unwrap_or(0);
+            if let Some(data) = self.redstone_data.get_mut(&pos) {
+                if data.power != level {
+                    data.power = level;
+                    data.powered = level > 0;
+                    changed_wires.push(pos);
+                }
+            }
+        }
+        for (x, y, z) in changed_wires {
+            self.mark_neighbors_dirty(x, y, z);
+        }
+
+        // 2. Lamps follow their input directly
+        let lamps: Vec<(i32, i32, i32)> = self.redstone_data.keys()
+            .filter(|&&(x, y, z)| self.get_block(x, y, z).is_some_and(|b| b.is_redstone_lamp()))
+            .copied()
+            .collect();
+        for (x, y, z) in lamps {
+            let powered = self.is_redstone_powered(x, y, z);
+            let lit = self.get_block(x, y, z) == Some(BlockType::RedstoneLampLit);
+            if powered != lit {
+                let new_type = if powered { BlockType::RedstoneLampLit } else { BlockType::RedstoneLamp };
+                self.set_block(x, y, z, new_type);
+            }
+        }
+
+        // 3. Doors, trapdoors and gates only react to power edges so players can still use them by hand
+        let doors: Vec<(i32, i32, i32)> = self.door_states.keys().copied().collect();
+        for (x, y, z) in doors {
+            let powered = self.is_redstone_powered(x, y, z) || self.is_redstone_powered(x, y + 1, z);
+            if self.redstone_consumer_changed((x, y, z), powered) {
+                if let Some(state) = self.door_states.get_mut(&(x, y, z)) {
+                    state.open = powered;
+                }
+                self.mark_neighbors_dirty(x, y, z);
+            }
+        }
+
+        let trapdoors: Vec<(i32, i32, i32)> = self.trapdoor_data.keys().copied().collect();
+        for (x, y, z) in trapdoors {
+            let powered = self.is_redstone_powered(x, y, z);
+            if self.redstone_consumer_changed((x, y, z), powered) {
+                if let Some(data) = self.trapdoor_data.get_mut(&(x, y, z)) {
+                    data.open = powered;
+                }
+                self.mark_neighbors_dirty(x, y, z);
+            }
+        }
+
+        let gates: Vec<(i32, i32, i32)> = self.fence_gate_data.keys().copied().collect();
+        for (x, y, z) in gates {
+            let powered = self.is_redstone_powered(x, y, z);
+            if self.redstone_consumer_changed((x, y, z), powered) {
+                if let Some(data) = self.fence_gate_data.get_mut(&(x, y, z)) {
+                    data.open = powered;
+                }
+                self.mark_neighbors_dirty(x, y, z);
+            }
+        }
+
+        // 4. Torches and repeaters change state after their delay
+        let delayed: Vec<((i32, i32, i32), BlockType, RedstoneData)> = self.redstone_data.iter()
+            .filter_map(|(&pos, data)| {
+                match self.get_block(pos.0, pos.1, pos.2) {
+                    Some(bt @ (BlockType::RedstoneTorch | BlockType::Repeater)) => Some((pos, bt, *data)),
+                    _ => None,
+                }
+            })
+            .collect();
+        for ((x, y, z), block_type, data) in delayed {
+            if block_type == BlockType::RedstoneTorch {
+                let should_be_lit = !self.is_redstone_torch_input_powered(x, y, z);
+                if should_be_lit != data.powered {
+                    self.schedule_redstone_tick((x, y, z), REDSTONE_TORCH_DELAY);
+                }
+            } else {
+                let input = self.is_repeater_input_powered(x, y, z);
+                if input != data.powered {
+                    self.schedule_redstone_tick((x, y, z), data.delay as u64);
+                }
+            }
+        }
+    }
+
+    /// Record a consumer's powered state; returns true if it changed since last propagation
+    fn redstone_consumer_changed(&mut self, pos: (i32, i32, i32), powered: bool) -> bool {
+        if powered {
+            self.redstone_powered_consumers.insert(pos)
+        } else {
+            self.redstone_powered_consumers.remove(&pos)
+        }
+    }
+
+    /// Whether a lever, button, plate or redstone torch here is currently outputting power
+    fn is_active_redstone_source(&self, x: i32, y: i32, z: i32) -> bool {
+        match self.get_block(x, y, z) {
+            Some(BlockType::Lever) | Some(BlockType::StoneButton)
+            | Some(BlockType::PressurePlate) | Some(BlockType::RedstoneTorch) => {
+                self.redstone_data.get(&(x, y, z)).is_some_and(|d| d.powered)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a powered repeater at `from` outputs into `to`
+    fn repeater_powers(&self, from: (i32, i32, i32), to: (i32, i32, i32)) -> bool {
+        if self.get_block(from.0, from.1, from.2) != Some(BlockType::Repeater) {
+            return false;
+        }
+        match self.redstone_data.get(&from) {
+            Some(data) if data.powered => {
+                let (dx, dz) = data.facing.offset();
+                (from.0 + dx, from.1, from.2 + dz) == to
+            }
+            _ => false,
+        }
+    }
+
+    /// Strong power: a solid block driven by an attached lever/button, a lit torch underneath,
+    /// a pressed plate on top, or a repeater facing into it. Strongly powered blocks drive wire.
+    fn is_block_strongly_powered(&self, x: i32, y: i32, z: i32) -> bool {
+        if !self.get_block(x, y, z).is_some_and(|b| b.is_redstone_conductor()) {
+            return false;
+        }
+
+        for (dx, dy, dz) in Self::NEIGHBOR_OFFSETS {
+            let (nx, ny, nz) = (x + dx, y + dy, z + dz);
+            let powers_us = match self.get_block(nx, ny, nz) {
+                Some(BlockType::Lever) | Some(BlockType::StoneButton) => {
+                    let face = self.get_torch_face(nx, ny, nz).unwrap_or(TorchFace::Top);
+                    self.is_active_redstone_source(nx, ny, nz)
+                        && Self::redstone_attachment(nx, ny, nz, face) == (x, y, z)
+                }
+                Some(BlockType::RedstoneTorch) => dy == -1 && self.is_active_redstone_source(nx, ny, nz),
+                Some(BlockType::PressurePlate) => dy == 1 && self.is_active_redstone_source(nx, ny, nz),
+                Some(BlockType::Repeater) => self.repeater_powers((nx, ny, nz), (x, y, z)),
+                _ => false,
+            };
+            if powers_us {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Weak power: strongly powered, or powered wire lying on top of / pointing into the block.
+    /// Weakly powered blocks drive torches, repeaters and mechanisms but not wire.
+    fn is_block_powered(&self, x: i32, y: i32, z: i32) -> bool {
+        if !self.get_block(x, y, z).is_some_and(|b| b.is_redstone_conductor()) {
+            return false;
+        }
+        if self.is_block_strongly_powered(x, y, z) || self.get_redstone_power(x, y + 1, z) > 0 {
+            return true;
+        }
+        Self::HORIZONTAL_FACINGS.iter().any(|facing| {
+            let (dx, dz) = facing.offset();
+            let (wx, wz) = (x + dx, z + dz);
+            self.get_redstone_power(wx, y, wz) > 0 && self.wire_points_toward(wx, y, wz, facing.opposite())
+        })
+    }
+
+    /// Whether a mechanism (lamp, door, trapdoor, gate) at this position receives power
+    pub fn is_redstone_powered(&self, x: i32, y: i32, z: i32) -> bool {
+        for (dx, dy, dz) in Self::NEIGHBOR_OFFSETS {
+            let (nx, ny, nz) = (x + dx, y + dy, z + dz);
+            let neighbor = match self.get_block(nx, ny, nz) {
+                Some(b) => b,
+                None => continue,
+            };
+
+            let powers_us = match neighbor {
+                BlockType::RedstoneTorch => {
+                    // A torch never powers the block it hangs on
+                    let face = self.get_torch_face(nx, ny, nz).unwrap_or(TorchFace::Top);
+                    self.is_active_redstone_source(nx, ny, nz)
+                        && Self::redstone_attachment(nx, ny, nz, face) != (x, y, z)
+                }
+                BlockType::Lever | BlockType::StoneButton | BlockType::PressurePlate => {
+                    self.is_active_redstone_source(nx, ny, nz)
+                }
+                BlockType::Repeater => self.repeater_powers((nx, ny, nz), (x, y, z)),
+                BlockType::RedstoneWire => {
+                    let power = self.get_redstone_power(nx, ny, nz);
+                    if dy == 1 {
+                        power > 0
+                    } else if dy == 0 {
+                        let toward = match (dx, dz) {
+                            (1, _) => BlockFacing::West,
+                            (-1, _) => BlockFacing::East,
+                            (_, 1) => BlockFacing::North,
+                            _ => BlockFacing::South,
+                        };
+                        power > 0 && self.wire_points_toward(nx, ny, nz, toward)
+                    } else {
+                        false
+                    }
+                }
+                _ => self.is_block_powered(nx, ny, nz),
+            };
+            if powers_us {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// A redstone torch turns off when the block it is attached to is powered
+    fn is_redstone_torch_input_powered(&self, x: i32, y: i32, z: i32) -> bool {
+        let face = self.get_torch_face(x, y, z).unwrap_or(TorchFace::Top);
+        let (ax, ay, az) = Self::redstone_attachment(x, y, z, face);
+        self.is_block_powered(ax, ay, az)
+    }
+
+    /// A repeater reads power from the block behind it
+    fn is_repeater_input_powered(&self, x: i32, y: i32, z: i32) -> bool {
+        let facing = match self.redstone_data.get(&(x, y, z)) {
+            Some(data) => data.facing,
+            None => return false,
+        };
+        let (dx, dz) = facing.offset();
+        let back = (x - dx, y, z - dz);
+        self.get_redstone_power(back.0, back.1, back.2) > 0
+            || self.is_active_redstone_source(back.0, back.1, back.2)
+            || self.repeater_powers(back, (x, y, z))
+            || self.is_block_powered(back.0, back.1, back.2)
+    }
+
+    /// Wire picks up full strength from adjacent active components and strongly powered blocks
+    fn wire_receives_direct_power(&self, x: i32, y: i32, z: i32) -> bool {
+        Self::NEIGHBOR_OFFSETS.iter().any(|&(dx, dy, dz)| {
+            let (nx, ny, nz) = (x + dx, y + dy, z + dz);
+            self.is_active_redstone_source(nx, ny, nz)
+                || self.repeater_powers((nx, ny, nz), (x, y, z))
+                || self.is_block_strongly_powered(nx, ny, nz)
+        })
+    }
+
+    /// Wire positions this wire passes signal to: same level, or one block up/down a step
+    fn wire_neighbors(&self, x: i32, y: i32, z: i32) -> Vec<(i32, i32, i32)> {
+        let is_wire = |bx: i32, by: i32, bz: i32| self.get_block(bx, by, bz) == Some(BlockType::RedstoneWire);
+        let is_conductor = |bx: i32, by: i32, bz: i32| self.get_block(bx, by, bz).is_some_and(|b| b.is_redstone_conductor());
+
+        let mut neighbors = Vec::new();
+        for facing in Self::HORIZONTAL_FACINGS {
+            let (dx, dz) = facing.offset();
+            let (nx, nz) = (x + dx, z + dz);
+            if is_wire(nx, y, nz) {
+                neighbors.push((nx, y, nz));
+            } else if is_conductor(nx, y, nz) {
+                // Climb up the side of a block unless something solid is above us
+                if is_wire(nx, y + 1, nz) && !is_conductor(x, y + 1, z) {
+                    neighbors.push((nx, y + 1, nz));
+                }
+            } else if is_wire(nx, y - 1, nz) {
+                neighbors.push((nx, y - 1, nz));
+            }
+        }
+        neighbors
+    }
+
+    /// Wire connections for rendering and signal direction - returns (north, south, east, west)
+    pub fn wire_connections(&self, x: i32, y: i32, z: i32) -> (bool, bool, bool, bool) {
+        let connects = |facing: BlockFacing| -> bool {
+            let (dx, dz) = facing.offset();
+            let (nx, nz) = (x + dx, z + dz);
+            if self.wire_neighbors(x, y, z).iter().any(|&(wx, _, wz)| wx == nx && wz == nz) {
+                return true;
+            }
+            match self.get_block(nx, y, nz) {
+                Some(BlockType::Repeater) => self.redstone_data.get(&(nx, y, nz))
+                    .is_some_and(|d| d.facing == facing || d.facing == facing.opposite()),
+                Some(b) => b.is_redstone_component() || b.is_redstone_lamp(),
+                None => false,
+            }
+        };
+
+        (
+            connects(BlockFacing::North),
+            connects(BlockFacing::South),
+            connects(BlockFacing::East),
+            connects(BlockFacing::West),
+        )
+    }
+
+    /// Work out which way a rail runs from the rails beside it. Rails join neighbours
+    /// on the same level or one below; a neighbour one block up turns a straight into a slope.
+    pub fn rail_shape(&self, x: i32, y: i32, z: i32) -> RailShape {
+        let is_rail = |nx: i32, ny: i32, nz: i32| self.get_block(nx, ny, nz) == Some(BlockType::Rail);
+        let rises = |facing: BlockFacing| {
+            let (dx, dz) = facing.offset();
+            is_rail(x + dx, y + 1, z + dz)
+        };
+        let connects = |facing: BlockFacing| {
+            let (dx, dz) = facing.offset();
+            rises(facing) || is_rail(x + dx, y, z + dz) || is_rail(x + dx, y - 1, z + dz)
+        };
+
+        let north = connects(BlockFacing::North);
+        let south = connects(BlockFacing::South);
+        let east = connects(BlockFacing::East);
+        let west = connects(BlockFacing::West);
+
+        let straight = |a: BlockFacing, b: BlockFacing, flat: RailShape| {
+            if rises(a) {
+                RailShape::Ascending(a)
+            } else if rises(b) {
+                RailShape::Ascending(b)
+            } else {
+                flat
+            }
+        };
+        let north_south = straight(BlockFacing::North, BlockFacing::South, RailShape::NorthSouth);
+        let east_west = straight(BlockFacing::East, BlockFacing::West, RailShape::EastWest);
+
+        match (north || south, east || west) {
+            // Junctions keep the straight through line
+            _ if north && south => north_south,
+            _ if east && west => east_west,
+            (true, true) => RailShape::Curve(
+                if north { BlockFacing::North } else { BlockFacing::South },
+                if east { BlockFacing::East } else { BlockFacing::West },
+            ),
+            (false, true) => east_west,
+            _ => north_south,
+        }
+    }
+
+    /// Wire "points" in a direction when it has no connections to either side of that axis.
+    /// A lone dot points everywhere.
+    fn wire_points_toward(&self, x: i32, y: i32, z: i32, facing: BlockFacing) -> bool {
+        let (north, south, east, west) = self.wire_connections(x, y, z);
+        match facing {
+            BlockFacing::North | BlockFacing::South => !east && !west,
+            BlockFacing::East | BlockFacing::West => !north && !south,
+        }
+    }
+
+    // ========== Farming System ==========
+
+    /// Till grass or dirt into farmland with a hoe. Needs air above.
+    pub fn till_block(&mut self, x: i32, y: i32, z: i32) -> bool {
+        match self.get_block(x, y, z) {
+            Some(BlockType::Grass) | Some(BlockType::Dirt) => {}
+            _ => return false,
+        }
+        if self.get_block(x, y + 1, z) != Some(BlockType::Air) {
+            return false;
+        }
+
+        let farmland = if self.is_farmland_hydrated(x, y, z) { BlockType::FarmlandWet } else { BlockType::Farmland };
+        self.set_block(x, y, z, farmland);
+        true
+    }
+
+    /// Farmland is hydrated when water is within FARMLAND_WATER_RANGE blocks horizontally,
+    /// on the same level or one above
+    pub fn is_farmland_hydrated(&self, x: i32, y: i32, z: i32) -> bool {
+        for dy in 0..=1 {
+            for dx in -FARMLAND_WATER_RANGE..=FARMLAND_WATER_RANGE {
+                for dz in -FARMLAND_WATER_RANGE..=FARMLAND_WATER_RANGE {
+                    if self.get_block(x + dx, y + dy, z + dz) == Some(BlockType::Water) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Plant a crop from a seed item on top of the farmland at (x, y, z)
+    pub fn plant_crop(&mut self, x: i32, y: i32, z: i32, item: BlockType) -> bool {
+        let crop = match item.planted_crop() {
+            Some(crop) => crop,
+            None => return false,
+        };
+        if !self.get_block(x, y, z).is_some_and(|b| b.is_farmland()) {
+            return false;
+        }
+        if !self.can_place_block_at(x, y + 1, z) {
+            return false;
+        }
+
+        self.set_block(x, y + 1, z, crop);
+        self.crop_stages.insert((x, y + 1, z), 0);
+        self.mark_neighbors_dirty(x, y + 1, z);
+        true
+    }
+
+    /// Growth stage of the crop at a position (0 if not a crop)
+    pub fn get_crop_stage(&self, x: i32, y: i32, z: i32) -> u8 {
+        if !self.get_block(x, y, z).is_some_and(|b| b.is_crop()) {
+            return 0;
+        }
+        self.crop_stages.get(&(x, y, z)).copied().unwrap_or(CROP_MAX_STAGE)
+    }
+
+    pub fn is_crop_mature(&self, x: i32, y: i32, z: i32) -> bool {
+        self.get_block(x, y, z).is_some_and(|b| b.is_crop()) && self.get_crop_stage(x, y, z) >= CROP_MAX_STAGE
+    }
+
+    /// Refresh the soil under a crop and maybe advance it one stage
+    fn random_tick_crop<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        if !self.get_block(x, y, z).is_some_and(|b| b.is_crop()) {
+            self.crop_stages.remove(&(x, y, z));
+            return;
+        }
+
+        // Crops pop off if their farmland was removed
+        let soil = match self.get_block(x, y - 1, z) {
+            Some(b) if b.is_farmland() => b,
+            _ => {
+                self.set_block(x, y, z, BlockType::Air);
+                return;
+            }
+        };
+
+        let hydrated = self.is_farmland_hydrated(x, y - 1, z);
+        let wanted = if hydrated { BlockType::FarmlandWet } else { BlockType::Farmland };
+        if soil != wanted {
+            self.set_block(x, y - 1, z, wanted);
+        }
+
+        let chance = if hydrated { CROP_GROWTH_CHANCE_WET } else { CROP_GROWTH_CHANCE_DRY };
+        let stage = self.crop_stages.get(&(x, y, z)).copied().unwrap_or(CROP_MAX_STAGE);
+        if stage < CROP_MAX_STAGE && rng.gen_bool(chance) {
+            self.crop_stages.insert((x, y, z), stage + 1);
+            self.mark_neighbors_dirty(x, y, z);
+        }
+    }
+
+    // ========== Random Tick System ==========
+
+    /// Accumulate frame time and run random ticks at RANDOM_TICK_INTERVAL (call each frame)
+    pub fn update_random_ticks(&mut self, dt: f32, precipitating: bool) {
+        self.random_tick_timer += dt;
+        while self.random_tick_timer >= RANDOM_TICK_INTERVAL {
+            self.random_tick_timer -= RANDOM_TICK_INTERVAL;
+            self.tick_random_blocks(precipitating);
+        }
+        // Don't try to catch up after a long stall
+        self.random_tick_timer = self.random_tick_timer.min(RANDOM_TICK_INTERVAL);
+    }
+
+    /// Run one random tick: pick RANDOM_TICKS_PER_SECTION blocks in every section of
+    /// every chunk near the player and run that block's handler
+    pub fn tick_random_blocks(&mut self, precipitating: bool) {
+        let (player_cx, player_cz) = self.player_chunk_pos;
+        // Sorted so a seeded world always visits chunks in the same order
+        let mut chunk_keys: Vec<(i32, i32)> = self.chunks.keys()
+            .filter(|(cx, cz)| {
+                (cx - player_cx).abs() <= RANDOM_TICK_CHUNK_RADIUS
+                    && (cz - player_cz).abs() <= RANDOM_TICK_CHUNK_RADIUS
+            })
+            .copied()
+            .collect();
+        chunk_keys.sort_unstable();
+
+        // Take the rng out so handlers can borrow self mutably
+        let mut rng = std::mem::replace(&mut self.random_tick_rng, StdRng::seed_from_u64(0));
+
+        for (chunk_x, chunk_z) in chunk_keys {
+            let base_x = chunk_x * Self::CHUNK_SIZE as i32;
+            let base_z = chunk_z * Self::CHUNK_SIZE as i32;
+
+            for section in 0..(Self::CHUNK_HEIGHT / RANDOM_TICK_SECTION_HEIGHT) {
+                for _ in 0..RANDOM_TICKS_PER_SECTION {
+                    let x = base_x + rng.gen_range(0..Self::CHUNK_SIZE as i32);
+                    let y = (section * RANDOM_TICK_SECTION_HEIGHT) as i32 + rng.gen_range(0..RANDOM_TICK_SECTION_HEIGHT as i32);
+                    let z = base_z + rng.gen_range(0..Self::CHUNK_SIZE as i32);
+                    self.random_tick_block(x, y, z, &mut rng);
+                }
+            }
+
+            if precipitating && rng.gen_bool(SNOW_ACCUMULATION_CHANCE) {
+                let x = base_x + rng.gen_range(0..Self::CHUNK_SIZE as i32);
+                let z = base_z + rng.gen_range(0..Self::CHUNK_SIZE as i32);
+                self.accumulate_snow(x, z);
+            }
+        }
+
+        self.random_tick_rng = rng;
+    }
+
+    /// Dispatch a random tick to the handler for whatever block is at this position
+    fn random_tick_block<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        match self.get_block(x, y, z) {
+            Some(BlockType::Grass) => self.random_tick_grass(x, y, z, rng),
+            Some(BlockType::Leaves) => self.random_tick_leaves(x, y, z, rng),
+            Some(BlockType::Sapling) => self.random_tick_sapling(x, y, z, rng),
+            Some(BlockType::Ice) => self.random_tick_ice(x, y, z),
+            Some(BlockType::Lava) => self.random_tick_lava(x, y, z, rng),
+            Some(BlockType::Farmland) | Some(BlockType::FarmlandWet) => self.random_tick_farmland(x, y, z, rng),
+            Some(b) if b.is_crop() => self.random_tick_crop(x, y, z, rng),
+            _ => {}
+        }
+    }
+
+    /// Whether a block lets enough light through for grass to live under it
+    fn lets_light_through(block: Option<BlockType>) -> bool {
+        match block {
+            None => true,
+            Some(b) => b.collision_shape() != CollisionShape::FullBlock
+                || matches!(b, BlockType::Glass | BlockType::Leaves | BlockType::Ice),
+        }
+    }
+
+    /// Grass dies under solid blocks, otherwise spreads to nearby lit dirt
+    fn random_tick_grass<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        if !Self::lets_light_through(self.get_block(x, y + 1, z)) || self.get_block(x, y + 1, z) == Some(BlockType::Water) {
+            self.set_block(x, y, z, BlockType::Dirt);
+            return;
+        }
+
+        let tx = x + rng.gen_range(-1..=1);
+        let ty = y + rng.gen_range(-3..=1);
+        let tz = z + rng.gen_range(-1..=1);
+        if self.get_block(tx, ty, tz) == Some(BlockType::Dirt)
+            && self.get_block(tx, ty + 1, tz) == Some(BlockType::Air)
+        {
+            self.set_block(tx, ty, tz, BlockType::Grass);
+        }
+    }
+
+    /// Leaves with no log reachable within LEAF_DECAY_RANGE steps (through other leaves) decay
+    fn random_tick_leaves<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        if self.is_leaf_disconnected(x, y, z) {
+            self.decay_leaves(x, y, z, rng);
+        }
+    }
+
+    /// Whether natural leaves here have lost their connection to any log
+    fn is_leaf_disconnected(&self, x: i32, y: i32, z: i32) -> bool {
+        self.get_block(x, y, z) == Some(BlockType::Leaves)
+            && !self.persistent_leaves.contains(&(x, y, z))
+            && !self.is_cactus_leaves(x, y, z)
+            && self.leaf_log_distance(x, y, z).is_none()
+    }
+
+    /// Deserts build cacti out of leaf columns standing on sand - those never decay
+    fn is_cactus_leaves(&self, x: i32, y: i32, z: i32) -> bool {
+        let mut check_y = y - 1;
+        while self.get_block(x, check_y, z) == Some(BlockType::Leaves) {
+            check_y -= 1;
+        }
+        self.get_block(x, check_y, z) == Some(BlockType::Sand)
+    }
+
+    /// Steps from these leaves to the nearest log, searching only through leaves
+    fn leaf_log_distance(&self, x: i32, y: i32, z: i32) -> Option<u32> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert((x, y, z));
+        queue.push_back(((x, y, z), 0u32));
+
+        while let Some(((cx, cy, cz), dist)) = queue.pop_front() {
+            if dist >= LEAF_DECAY_RANGE {
+                continue;
+            }
+            for (dx, dy, dz) in Self::NEIGHBOR_OFFSETS {
+                let next = (cx + dx, cy + dy, cz + dz);
+                if !visited.insert(next) {
+                    continue;
+                }
+                match self.get_block(next.0, next.1, next.2) {
+                    Some(BlockType::Wood) => return Some(dist + 1),
+                    Some(BlockType::Leaves) => queue.push_back((next, dist + 1)),
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Ice near a torch or lava melts into a water source
+    fn random_tick_ice(&mut self, x: i32, y: i32, z: i32) {
+        let r = ICE_MELT_RANGE;
+        let near_heat = (-r..=r).any(|dx| (-r..=r).any(|dy| (-r..=r).any(|dz| {
+            matches!(self.get_block(x + dx, y + dy, z + dz), Some(BlockType::Torch) | Some(BlockType::Lava))
+        })));
+        if near_heat {
+            self.set_water(x, y, z, 8);
+            self.queue_water_update(x, y, z);
+        }
+    }
+
+    /// Lava sets fire to flammable blocks just above and around it
+    fn random_tick_lava<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        for _ in 0..LAVA_IGNITE_ATTEMPTS {
+            let tx = x + rng.gen_range(-1..=1);
+            let ty = y + rng.gen_range(1..=2);
+            let tz = z + rng.gen_range(-1..=1);
+            if self.touches_flammable(tx, ty, tz) && self.ignite(tx, ty, tz) {
+                return;
+            }
+        }
+    }
+
+    /// Refresh farmland hydration; dry, empty farmland slowly turns back into dirt
+    fn random_tick_farmland<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        let hydrated = self.is_farmland_hydrated(x, y, z);
+        let has_crop = self.get_block(x, y + 1, z).is_some_and(|b| b.is_crop());
+
+        if !hydrated && !has_crop && rng.gen_bool(FARMLAND_REVERT_CHANCE) {
+            self.set_block(x, y, z, BlockType::Dirt);
+            return;
+        }
+
+        let wanted = if hydrated { BlockType::FarmlandWet } else { BlockType::Farmland };
+        if self.get_block(x, y, z) != Some(wanted) {
+            self.set_block(x, y, z, wanted);
+        }
+    }
+
+    /// Settle a layer of snow on top of the highest block in a Tundra column
+    fn accumulate_snow(&mut self, x: i32, z: i32) {
+        if self.get_biome(x as f64, z as f64) != Biome::Tundra {
+            return;
+        }
+
+        let top_y = match self.highest_block_y(x, z) {
+            Some(y) if y + 1 < Self::CHUNK_HEIGHT as i32 => y,
+            _ => return,
+        };
+
+        // Only one layer: snow doesn't pile on snow, and never settles on water, ice or non-full blocks
+        let top = self.get_block(x, top_y, z).unwrap_or(BlockType::Air);
+        if top == BlockType::Snow || top == BlockType::Ice || top == BlockType::Water
+            || top.collision_shape() != CollisionShape::FullBlock
+        {
+            return;
+        }
+        self.set_block(x, top_y + 1, z, BlockType::Snow);
+    }
+
+    /// Height of the highest non-air block in a column
+    pub fn highest_block_y(&self, x: i32, z: i32) -> Option<i32> {
+        (0..Self::CHUNK_HEIGHT as i32).rev()
+            .find(|&y| self.get_block(x, y, z).is_some_and(|b| b != BlockType::Air))
+    }
+
+    /// Below sea level with solid blocks overhead - caves and mines rather than open pits
+    pub fn is_underground(&self, x: i32, y: i32, z: i32) -> bool {
+        y < Self::SEA_LEVEL as i32 && self.is_sheltered(x, y, z)
+    }
+
+    /// Whether any solid block sits somewhere above this position (indoors, under trees, in caves)
+    pub fn is_sheltered(&self, x: i32, y: i32, z: i32) -> bool {
+        (y + 1..Self::CHUNK_HEIGHT as i32).any(|above| {
+            self.get_block(x, above, z).is_some_and(|b| b.collision_shape() == CollisionShape::FullBlock)
+        })
+    }
+
+    // ========== Leaf Decay & Saplings ==========
+
+    /// Items the world produced on its own since the last call (e.g. saplings from decayed leaves)
+    pub fn take_pending_drops(&mut self) -> Vec<(Point3<f32>, BlockType)> {
+        std::mem::take(&mut self.pending_drops)
+    }
+
+    /// Check leaves around removed logs and make disconnected ones fall apart over a few seconds (call each frame)
+    pub fn update_leaf_decay(&mut self, dt: f32) {
+        let mut rng = rand::thread_rng();
+
+        // One connectivity check per frame keeps felling a whole tree cheap
+        if let Some((cx, cy, cz)) = self.leaf_decay_checks.pop_front() {
+            let distances = self.leaf_distance_map(cx, cy, cz);
+            let r = LEAF_DECAY_RANGE as i32;
+            for x in (cx - r)..=(cx + r) {
+                for y in (cy - r)..=(cy + r) {
+                    for z in (cz - r)..=(cz + r) {
+                        let pos = (x, y, z);
+                        if self.get_block(x, y, z) != Some(BlockType::Leaves)
+                            || distances.contains_key(&pos)
+                            || self.persistent_leaves.contains(&pos)
+                            || self.decaying_leaves.iter().any(|(_, p)| *p == pos)
+                        {
+                            continue;
+                        }
+                        let delay = rng.gen_range(LEAF_DECAY_DELAY.0..LEAF_DECAY_DELAY.1);
+                        self.decaying_leaves.push((delay, pos));
+                    }
+                }
+            }
+        }
+
+        let mut due = Vec::new();
+        self.decaying_leaves.retain_mut(|(timer, pos)| {
+            *timer -= dt;
+            if *timer <= 0.0 {
+                due.push(*pos);
+                false
+            } else {
+                true
+            }
+        });
+
+        for (x, y, z) in due {
+            // The player may have placed a new log nearby in the meantime
+            if self.is_leaf_disconnected(x, y, z) {
+                self.decay_leaves(x, y, z, &mut rng);
+            }
+        }
+    }
+
+    /// Distance from every leaf within LEAF_DECAY_RANGE of (cx, cy, cz) to its nearest log,
+    /// found by searching outward from all nearby logs through leaves
+    fn leaf_distance_map(&self, cx: i32, cy: i32, cz: i32) -> HashMap<(i32, i32, i32), u32> {
+        let search = LEAF_DECAY_RANGE as i32 * 2;
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        for x in (cx - search)..=(cx + search) {
+            for y in (cy - search)..=(cy + search) {
+                for z in (cz - search)..=(cz + search) {
+                    if self.get_block(x, y, z) == Some(BlockType::Wood) {
+                        queue.push_back(((x, y, z), 0u32));
+                    }
+                }
+            }
+        }
+
+        while let Some(((x, y, z), dist)) = queue.pop_front() {
+            if dist >= LEAF_DECAY_RANGE {
+                continue;
+            }
+            for (dx, dy, dz) in Self::NEIGHBOR_OFFSETS {
+                let next = (x + dx, y + dy, z + dz);
+                if distances.contains_key(&next) || self.get_block(next.0, next.1, next.2) != Some(BlockType::Leaves) {
+                    continue;
+                }
+                distances.insert(next, dist + 1);
+                queue.push_back((next, dist + 1));
+            }
+        }
+        distances
+    }
+
+    /// Remove leaves and drop the occasional sapling or apple
+    fn decay_leaves<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        self.set_block(x, y, z, BlockType::Air);
+        let center = Point3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5);
+        for drop in BlockType::leaf_bonus_drops(rng) {
+            self.pending_drops.push((center, drop));
+        }
+    }
+
+    /// Plant a sapling on grass or dirt
+    pub fn plant_sapling(&mut self, x: i32, y: i32, z: i32) -> bool {
+        if !matches!(self.get_block(x, y - 1, z), Some(BlockType::Grass) | Some(BlockType::Dirt)) {
+            return false;
+        }
+        if !self.can_place_block_at(x, y, z) {
+            return false;
+        }
+        self.set_block(x, y, z, BlockType::Sapling);
+        self.mark_neighbors_dirty(x, y, z);
+        true
+    }
+
+    fn random_tick_sapling<R: Rng>(&mut self, x: i32, y: i32, z: i32, rng: &mut R) {
+        // Saplings pop off when their soil is gone
+        if !matches!(self.get_block(x, y - 1, z), Some(BlockType::Grass) | Some(BlockType::Dirt)) {
+            self.set_block(x, y, z, BlockType::Air);
+            self.pending_drops.push((Point3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5), BlockType::Sapling));
+            return;
+        }
+        if rng.gen_bool(SAPLING_GROW_CHANCE) {
+            self.grow_sapling(x, y, z);
+        }
+    }
+
+    /// Grow the sapling at (x, y, z) into a full tree, using the same shapes as world generation
+    /// (pine in mountains and tundra, oak elsewhere). Returns false if there is no room for the trunk.
+    pub fn grow_sapling(&mut self, x: i32, y: i32, z: i32) -> bool {
+        if self.get_block(x, y, z) != Some(BlockType::Sapling) {
+            return false;
+        }
+
+        let ground_y = y - 1;
+        let shape = match self.get_biome(x as f64, z as f64) {
+            Biome::Mountains | Biome::Tundra => self.pine_tree_shape(x, z),
+            _ => self.oak_tree_shape(x, ground_y, z),
+        };
+
+        // The trunk needs a clear column
+        let trunk_clear = shape.iter()
+            .filter(|(_, _, _, block)| *block == BlockType::Wood)
+            .all(|&(dx, dy, dz, _)| {
+                matches!(self.get_block(x + dx, ground_y + dy, z + dz),
+                    Some(BlockType::Air) | Some(BlockType::Leaves) | Some(BlockType::Sapling))
+            });
+        if !trunk_clear {
+            return false;
+        }
+
+        for (dx, dy, dz, block) in shape {
+            let (bx, by, bz) = (x + dx, ground_y + dy, z + dz);
+            let existing = self.get_block(bx, by, bz);
+            if block == BlockType::Wood || existing == Some(BlockType::Air) {
+                self.set_block(bx, by, bz, block);
+            }
+        }
+        self.mark_neighbors_dirty(x, y, z);
+        true
+    }
+
+    // ========== Fire ==========
+
+    /// Whether fire can burn at this spot: empty, and resting on solid ground or next to something flammable
+    pub fn can_ignite(&self, x: i32, y: i32, z: i32) -> bool {
+        self.get_block(x, y, z) == Some(BlockType::Air)
+            && (self.get_block(x, y - 1, z).is_some_and(|b| b.collision_shape() == CollisionShape::FullBlock)
+                || self.touches_flammable(x, y, z))
+    }
+
+    /// Light a fire at this spot (flint and steel, lava, lightning); returns false if it can't burn here
+    pub fn ignite(&mut self, x: i32, y: i32, z: i32) -> bool {
+        if !self.can_ignite(x, y, z) {
+            return false;
+        }
+        self.set_block(x, y, z, BlockType::Fire);
+        self.mark_neighbors_dirty(x, y, z);
+        true
+    }
+
+    /// Whether any of the six neighbours of this spot can burn
+    fn touches_flammable(&self, x: i32, y: i32, z: i32) -> bool {
+        Self::NEIGHBOR_OFFSETS.iter().any(|(dx, dy, dz)| {
+            self.get_block(x + dx, y + dy, z + dz).is_some_and(|b| b.flammability().is_some())
+        })
+    }
+
+    /// Best chance of catching fire among this spot's neighbours
+    fn neighbor_catch_chance(&self, x: i32, y: i32, z: i32) -> Option<u32> {
+        Self::NEIGHBOR_OFFSETS.iter()
+            .filter_map(|(dx, dy, dz)| self.get_block(x + dx, y + dy, z + dz).and_then(|b| b.flammability()))
+            .map(|(catch, _)| catch)
+            .max()
+    }
+
+    /// Replace a block with air (or fire) and let water and neighbouring chunks react
+    fn burn_block(&mut self, x: i32, y: i32, z: i32, replacement: BlockType) {
+        self.set_block(x, y, z, replacement);
+        self.mark_neighbors_dirty(x, y, z);
+        self.trigger_water_updates_around(x, y, z);
+    }
+
+    /// Step every fire once per FIRE_TICK_INTERVAL: burn flammable neighbours, spread to nearby
+    /// spots and go out in the rain or once there is nothing left to burn (call each frame)
+    pub fn update_fire(&mut self, dt: f32, raining: bool) {
+        if self.fire_ages.is_empty() {
+            self.fire_tick_timer = 0.0;
+            return;
+        }
+        self.fire_tick_timer += dt;
+        if self.fire_tick_timer < FIRE_TICK_INTERVAL {
+            return;
+        }
+        self.fire_tick_timer = 0.0;
+
+        // Sorted so a seeded world always burns in the same order
+        let mut fires: Vec<((i32, i32, i32), u8)> = self.fire_ages.iter().map(|(pos, age)| (*pos, *age)).collect();
+        fires.sort_unstable();
+
+        // Fire draws from the random tick rng so it spreads the same way under a seed
+        let mut rng = std::mem::replace(&mut self.random_tick_rng, StdRng::seed_from_u64(0));
+
+        for ((x, y, z), age) in fires {
+            // Already burnt out, washed away or unloaded with its chunk
+            if self.get_block(x, y, z) != Some(BlockType::Fire) {
+                self.fire_ages.remove(&(x, y, z));
+                continue;
+            }
+
+            // Rain puts out fires open to the sky
+            if raining && !self.is_sheltered(x, y, z) && rng.gen_bool(FIRE_RAIN_CHANCE) {
+                self.burn_block(x, y, z, BlockType::Air);
+                continue;
+            }
+
+            let age = (age + 1).min(FIRE_MAX_AGE);
+            self.fire_ages.insert((x, y, z), age);
+
+            // Fire on bare ground flickers out after a few ticks
+            if !self.touches_flammable(x, y, z) {
+                let grounded = self.get_block(x, y - 1, z).is_some_and(|b| b.collision_shape() == CollisionShape::FullBlock);
+                if !grounded || age > FIRE_BARE_GROUND_AGE {
+                    self.burn_block(x, y, z, BlockType::Air);
+                }
+                continue;
+            }
+
+            // Old fires over something that doesn't burn die down
+            let fuel_below = self.get_block(x, y - 1, z).is_some_and(|b| b.flammability().is_some());
+            if age >= FIRE_MAX_AGE && !fuel_below && rng.gen_bool(FIRE_BURN_OUT_CHANCE) {
+                self.burn_block(x, y, z, BlockType::Air);
+                continue;
+            }
+
+            // Burn neighbours away, often leaving fire in their place
+            for (dx, dy, dz) in Self::NEIGHBOR_OFFSETS {
+                let (nx, ny, nz) = (x + dx, y + dy, z + dz);
+                let Some((_, burn)) = self.get_block(nx, ny, nz).and_then(|b| b.flammability()) else {
+                    continue;
+                };
+                if rng.gen_range(0..100) < burn {
+                    let replacement = if rng.gen_bool(FIRE_REPLACE_CHANCE) { BlockType::Fire } else { BlockType::Air };
+                    self.burn_block(nx, ny, nz, replacement);
+                }
+            }
+
+            // Spread to empty spots next to something flammable, reaching further upwards
+            for _ in 0..FIRE_SPREAD_ATTEMPTS {
+                let tx = x + rng.gen_range(-1..=1);
+                let ty = y + rng.gen_range(-1..=2);
+                let tz = z + rng.gen_range(-1..=1);
+                if self.get_block(tx, ty, tz) != Some(BlockType::Air) {
+                    continue;
+                }
+                if let Some(catch) = self.neighbor_catch_chance(tx, ty, tz) {
+                    if rng.gen_range(0..100) < catch {
+                        self.burn_block(tx, ty, tz, BlockType::Fire);
+                    }
+                }
+            }
+        }
+
+        self.random_tick_rng = rng;
+    }
+
+    // ========== Lightning ==========
+
+    /// Block a bolt falling near (x, z) hits: the highest lightning rod within LIGHTNING_TARGET_RADIUS,
+    /// otherwise the tallest exposed block (ties picked at random). None if nothing there is loaded.
+    pub fn lightning_target(&self, x: i32, z: i32, rng: &mut impl Rng) -> Option<(i32, i32, i32)> {
+        let mut tallest: Vec<(i32, i32, i32)> = Vec::new();
+        let mut rod: Option<(i32, i32, i32)> = None;
+        for tx in x - LIGHTNING_TARGET_RADIUS..=x + LIGHTNING_TARGET_RADIUS {
+            for tz in z - LIGHTNING_TARGET_RADIUS..=z + LIGHTNING_TARGET_RADIUS {
+                let Some(top_y) = self.highest_block_y(tx, tz) else {
+                    continue;
+                };
+                if self.get_block(tx, top_y, tz) == Some(BlockType::LightningRod) && rod.is_none_or(|(_, y, _)| top_y > y) {
+                    rod = Some((tx, top_y, tz));
+                }
+                match tallest.first() {
+                    Some(&(_, y, _)) if top_y < y => {}
+                    Some(&(_, y, _)) if top_y == y => tallest.push((tx, top_y, tz)),
+                    _ => tallest = vec![(tx, top_y, tz)],
+                }
+            }
+        }
+        if rod.is_some() || tallest.is_empty() {
+            return rod;
+        }
+        Some(tallest[rng.gen_range(0..tallest.len())])
+    }
+
+    // ========== Ambient Sound Sources ==========
+
+    /// Distance from a point to the closest block of this type within `radius` blocks
+    pub fn nearest_block_distance(&self, pos: Point3<f32>, radius: i32, block_type: BlockType) -> Option<f32> {
+        let (cx, cy, cz) = (pos.x.floor() as i32, pos.y.floor() as i32, pos.z.floor() as i32);
+        let mut nearest: Option<f32> = None;
+        for x in cx - radius..=cx + radius {
+            for y in cy - radius..=cy + radius {
+                for z in cz - radius..=cz + radius {
+                    if self.get_block(x, y, z) != Some(block_type) {
+                        continue;
+                    }
+                    let distance = Self::distance_to_block(pos, (x, y, z));
+                    if nearest.is_none_or(|d| distance < d) {
+                        nearest = Some(distance);
+                    }
+                }
+            }
+        }
+        nearest.filter(|&d| d <= radius as f32)
+    }
+
+    /// Distance to the closest flowing (non-source) water within `radius` blocks
+    pub fn nearest_flowing_water_distance(&self, pos: Point3<f32>, radius: f32) -> Option<f32> {
+        self.water_levels.keys()
+            .map(|&block| Self::distance_to_block(pos, block))
+            .filter(|&d| d <= radius)
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    fn distance_to_block(pos: Point3<f32>, (x, y, z): (i32, i32, i32)) -> f32 {
+        let dx = x as f32 + 0.5 - pos.x;
+        let dy = y as f32 + 0.5 - pos.y;
+        let dz = z as f32 + 0.5 - pos.z;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    // ========== Villages ==========
+
+    /// Workstations, house doors and meeting places in a chunk, found by scanning its blocks
+    pub fn find_village_sites(&self, chunk_x: i32, chunk_z: i32) -> VillageSites {
+        let mut sites = VillageSites::default();
+        let Some(chunk) = self.chunks.get(&(chunk_x, chunk_z)) else {
+            return sites;
+        };
+        for (lx, column) in chunk.blocks.iter().enumerate() {
+            for (y, row) in column.iter().enumerate() {
+                for (lz, &block) in row.iter().enumerate() {
+                    let pos = (
+                        chunk_x * Self::CHUNK_SIZE as i32 + lx as i32,
+                        y as i32,
+                        chunk_z * Self::CHUNK_SIZE as i32 + lz as i32,
+                    );
+                    if block.is_workstation() {
+                        sites.workstations.push((pos, block));
+                    }
+                    if block == BlockType::DoorBottom {
+                        sites.doors.push(pos);
+                    }
+                    // Villagers meet at the church lectern or beside a well
+                    if block == BlockType::Lectern || (block == BlockType::Water && self.is_well_center(pos)) {
+                        sites.meeting_points.push(pos);
+                    }
+                }
+            }
+        }
+        sites
+    }
+
+    /// A well is water with fence posts rising from the four diagonal corners of its rim
+    fn is_well_center(&self, (x, y, z): (i32, i32, i32)) -> bool {
+        [(-1, -1), (-1, 1), (1, -1), (1, 1)].iter()
+            .all(|&(dx, dz)| self.get_block(x + dx, y + 1, z + dz) == Some(BlockType::Fence))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const FLOOR_Y: i32 = 64;
+
+    /// A seeded world cut down to its spawn chunk, flattened to stone up to FLOOR_Y and air above
+    pub(crate) fn flat_world(seed: u32) -> World {
+        let mut world = World::with_seed(seed);
+        world.chunks.retain(|&key, _| key == (0, 0));
+        let chunk = world.chunks.get_mut(&(0, 0)).expect("spawn chunk is loaded");
+        for column in chunk.blocks.iter_mut() {
+            for (y, row) in column.iter_mut().enumerate() {
+                let block = if y as i32 <= FLOOR_Y { BlockType::Stone } else { BlockType::Air };
+                row.fill(block);
+            }
+        }
+        world
+    }
+
+    fn run_redstone(world: &mut World, ticks: u32) {
+        for _ in 0..ticks {
+            world.update_redstone(REDSTONE_TICK);
+        }
+    }
+
+    #[test]
+    fn redstone_torch_inverts_lever() {
+        let mut world = flat_world(1);
+        let y = FLOOR_Y + 1;
+        // Lever -> stone block -> torch on its far side -> lamp
+        world.set_block(4, y, 4, BlockType::Stone);
+        assert!(world.place_redstone_component(3, y, 4, BlockType::Lever, TorchFace::West, BlockFacing::North));
+        assert!(world.place_redstone_component(5, y, 4, BlockType::RedstoneTorch, TorchFace::East, BlockFacing::North));
+        assert!(world.place_redstone_component(6, y, 4, BlockType::RedstoneLamp, TorchFace::Top, BlockFacing::North));
+
+        run_redstone(&mut world, 5);
+        assert_eq!(world.get_block(6, y, 4), Some(BlockType::RedstoneLampLit), "lever off: torch lights the lamp");
+
+        assert_eq!(world.toggle_lever(3, y, 4), Some(true));
+        run_redstone(&mut world, 5);
+        assert_eq!(world.get_block(6, y, 4), Some(BlockType::RedstoneLamp), "lever on: torch goes out");
+
+        assert_eq!(world.toggle_lever(3, y, 4), Some(false));
+        run_redstone(&mut world, 5);
+        assert_eq!(world.get_block(6, y, 4), Some(BlockType::RedstoneLampLit), "lever off again: lamp relights");
+    }
+
+    #[test]
+    fn redstone_torch_and_repeater_clock() {
+        let mut world = flat_world(1);
+        let y = FLOOR_Y + 1;
+        // Torch on the east side of a block feeds wire round into a repeater that
+        // powers the block back, turning the torch off, which cuts the repeater, and so on
+        world.set_block(4, y, 8, BlockType::Stone);
+        assert!(world.place_redstone_component(5, y, 8, BlockType::RedstoneTorch, TorchFace::East, BlockFacing::North));
+        for (x, z) in [(5, 9), (5, 10), (4, 10)] {
+            assert!(world.place_redstone_component(x, y, z, BlockType::RedstoneWire, TorchFace::Top, BlockFacing::North));
+        }
+        assert!(world.place_redstone_component(4, y, 9, BlockType::Repeater, TorchFace::Top, BlockFacing::North));
+
+        let torch_lit = |world: &World| world.get_redstone_data(5, y, 8).is_some_and(|d| d.powered);
+        let mut toggles = 0;
+        let mut was_lit = torch_lit(&world);
+        for _ in 0..60 {
+            run_redstone(&mut world, 1);
+            let lit = torch_lit(&world);
+            if lit != was_lit {
+                toggles += 1;
+            }
+            was_lit = lit;
+        }
+        assert!(toggles >= 6, "clock should keep toggling, saw {toggles} toggles");
+    }
+
+    #[test]
+    fn rail_shapes_join_their_neighbours() {
+        let mut world = flat_world(1);
+        let y = FLOOR_Y + 1;
+
+        world.set_block(2, y, 2, BlockType::Rail);
+        assert_eq!(world.rail_shape(2, y, 2), RailShape::NorthSouth, "a lone rail runs north-south");
+        world.set_block(3, y, 2, BlockType::Rail);
+        assert_eq!(world.rail_shape(2, y, 2), RailShape::EastWest);
+
+        // L bend: one leg north, one leg east
+        for (x, z) in [(8, 8), (8, 7), (9, 8)] {
+            world.set_block(x, y, z, BlockType::Rail);
+        }
+        assert_eq!(world.rail_shape(8, y, 8), RailShape::Curve(BlockFacing::North, BlockFacing::East));
+        world.set_block(8, y, 9, BlockType::Rail);
+        assert_eq!(world.rail_shape(8, y, 8), RailShape::NorthSouth, "a junction keeps the straight line");
+
+        // A rail one block up to the west turns the lower end into a slope
+        world.set_block(11, y, 12, BlockType::Stone);
+        world.set_block(11, y + 1, 12, BlockType::Rail);
+        world.set_block(12, y, 12, BlockType::Rail);
+        assert_eq!(world.rail_shape(12, y, 12), RailShape::Ascending(BlockFacing::West));
+        assert_eq!(world.rail_shape(11, y + 1, 12), RailShape::EastWest, "the top of a slope stays flat");
+    }
+
+    #[test]
+    fn sloped_rail_height_rises_toward_its_high_side() {
+        let north = RailShape::Ascending(BlockFacing::North);
+        assert_eq!(north.height_at(0.5, 0.5), 0.5);
+        assert_eq!(north.height_at(0.5, 0.0), 1.0);
+        assert_eq!(north.height_at(0.5, 1.0), 0.0);
+        assert_eq!(north.height_at(0.0, 0.75), north.height_at(1.0, 0.75), "sideways position doesn't matter");
+
+        let east = RailShape::Ascending(BlockFacing::East);
+        assert_eq!(east.height_at(0.75, 0.5), 0.75);
+        assert_eq!(RailShape::EastWest.height_at(0.75, 0.5), 0.0);
+        assert_eq!(RailShape::Curve(BlockFacing::South, BlockFacing::West).height_at(0.2, 0.9), 0.0);
+    }
+
+    /// Every block in the spawn chunk, to compare runs of the same scenario
+    fn chunk_blocks(world: &World) -> Vec<Vec<Vec<BlockType>>> {
+        world.chunks[&(0, 0)].blocks.clone()
+    }
+
+    /// Spawn chunk topped with a checkerboard of grass and dirt, after `ticks` random ticks
+    fn grass_after_random_ticks(seed: u32, ticks: u32) -> World {
+        let mut world = flat_world(seed);
+        for x in 0..World::CHUNK_SIZE as i32 {
+            for z in 0..World::CHUNK_SIZE as i32 {
+                let top = if (x + z) % 2 == 0 { BlockType::Grass } else { BlockType::Dirt };
+                world.set_block(x, FLOOR_Y, z, top);
+            }
+        }
+        for _ in 0..ticks {
+            world.tick_random_blocks(false);
+        }
+        world
+    }
+
+    #[test]
+    fn random_ticks_are_deterministic_under_a_seed() {
+        let a = chunk_blocks(&grass_after_random_ticks(42, 200));
+        assert!(a != chunk_blocks(&grass_after_random_ticks(42, 0)), "random ticks should spread some grass");
+        assert!(a == chunk_blocks(&grass_after_random_ticks(42, 200)), "same seed should give the same world");
+        assert!(a != chunk_blocks(&grass_after_random_ticks(43, 200)), "a different seed should tick differently");
+    }
+
+    fn run_fire(world: &mut World, ticks: u32, raining: bool) {
+        for _ in 0..ticks {
+            world.update_fire(FIRE_TICK_INTERVAL, raining);
+        }
+    }
+
+    /// A fire on the stone floor with a log on each side of it
+    fn fire_among_logs(seed: u32) -> World {
+        let mut world = flat_world(seed);
+        let y = FLOOR_Y + 1;
+        for (x, z) in [(7, 8), (9, 8), (8, 7), (8, 9)] {
+            world.set_block(x, y, z, BlockType::Wood);
+        }
+        assert!(world.ignite(8, y, 8));
+        world
+    }
+
+    #[test]
+    fn rain_puts_out_exposed_fire() {
+        let ticks = FIRE_MAX_AGE as u32 - 1;
+        let mut dry = fire_among_logs(1);
+        run_fire(&mut dry, ticks, false);
+        assert_eq!(dry.get_block(8, FLOOR_Y + 1, 8), Some(BlockType::Fire), "with fuel beside it the fire keeps going");
+
+        let mut wet = fire_among_logs(1);
+        run_fire(&mut wet, ticks, true);
+        assert_eq!(wet.get_block(8, FLOOR_Y + 1, 8), Some(BlockType::Air), "rain should put out a fire open to the sky");
+        assert!(!wet.fire_ages.contains_key(&(8, FLOOR_Y + 1, 8)));
+    }
+
+    #[test]
+    fn fire_on_bare_stone_burns_out() {
+        let mut world = flat_world(1);
+        assert!(world.ignite(8, FLOOR_Y + 1, 8));
+        run_fire(&mut world, FIRE_BARE_GROUND_AGE as u32, false);
+        assert_eq!(world.get_block(8, FLOOR_Y + 1, 8), Some(BlockType::Fire));
+
+        run_fire(&mut world, 1, false);
+        assert_eq!(world.get_block(8, FLOOR_Y + 1, 8), Some(BlockType::Air), "nothing to burn, so the fire goes out");
+        assert!(world.fire_ages.is_empty());
+        assert_eq!(world.get_block(8, FLOOR_Y, 8), Some(BlockType::Stone));
+    }
+
+    /// An 8x8 platform of planks set alight in one corner, after `ticks` fire ticks
+    fn burning_planks(seed: u32, ticks: u32) -> World {
+        let mut world = flat_world(seed);
+        for x in 0..8 {
+            for z in 0..8 {
+                world.set_block(x, FLOOR_Y + 1, z, BlockType::Planks);
+            }
+        }
+        assert!(world.ignite(0, FLOOR_Y + 2, 0));
+        run_fire(&mut world, ticks, false);
+        world
+    }
+
+    #[test]
+    fn planks_next_to_fire_burn() {
+        let world = burning_planks(42, 60);
+        assert_ne!(world.get_block(0, FLOOR_Y + 1, 0), Some(BlockType::Planks), "the plank under the fire should burn away");
+        assert!(chunk_blocks(&world) == chunk_blocks(&burning_planks(42, 60)), "same seed should burn the same blocks");
+    }
+
+    #[test]
+    fn fire_only_consumes_flammable_blocks() {
+        let mut world = flat_world(7);
+        let y = FLOOR_Y + 1;
+        // Planks and stone in a checkerboard, with a glass and a dirt block in the mix
+        for x in 2..10 {
+            for z in 2..10 {
+                let block = if (x + z) % 2 == 0 { BlockType::Planks } else { BlockType::Stone };
+                world.set_block(x, y, z, block);
+            }
+        }
+        world.set_block(5, y, 4, BlockType::Glass);
+        world.set_block(5, y, 6, BlockType::Dirt);
+        assert!(world.ignite(2, y + 1, 2));
+        run_fire(&mut world, 200, false);
+
+        let mut burnt = 0;
+        for x in 2..10 {
+            for z in 2..10 {
+                let block = world.get_block(x, y, z);
+                if (x + z) % 2 == 0 {
+                    burnt += (block != Some(BlockType::Planks)) as u32;
+                } else if (x, z) == (5, 4) {
+                    assert_eq!(block, Some(BlockType::Glass));
+                } else if (x, z) == (5, 6) {
+                    assert_eq!(block, Some(BlockType::Dirt));
+                } else {
+                    assert_eq!(block, Some(BlockType::Stone), "stone at ({x}, {z}) was burnt");
+                }
+                assert_eq!(world.get_block(x, FLOOR_Y, z), Some(BlockType::Stone), "floor at ({x}, {z}) was burnt");
+            }
+        }
+        assert!(burnt > 0, "some planks should have burnt");
+    }
+}